@@ -9,16 +9,70 @@ fi
 PLUGIN_NAME=$1
 RIG_NAME=$1
 EXAMPLE_NAME=$1
+PLUGIN_CRATE=solagent_plugin_${PLUGIN_NAME//-/_}
+
+LICENSE_HEADER='// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.'
 
 # Create solagent-plugins
 cargo new --lib solagent-plugins/$PLUGIN_NAME
 # Rename package name in Cargo.toml
 sed -i "s/name = \"$PLUGIN_NAME\"/name = \"solagent-plugin-$PLUGIN_NAME\"/" solagent-plugins/$PLUGIN_NAME/Cargo.toml
+cat >> solagent-plugins/$PLUGIN_NAME/Cargo.toml <<TOML
+solagent-core = { path = "../../solagent-core" }
+solagent-parameters = { path = "../../solagent-parameters" }
+solagent-rig-macros = { path = "../../solagent-adapters/rig/macros" }
+serde = { version = "1.0", features = ["derive"] }
+serde_json = "1.0"
+TOML
+# One annotated function is both the plugin function and its tool, `Example`
+cat > solagent-plugins/$PLUGIN_NAME/src/lib.rs <<RUST
+$LICENSE_HEADER
+
+use solagent_core::{SolagentError, SolanaAgentKit};
+use solagent_rig_macros::solagent_tool;
+
+/// Get the address of one of the agent's wallets.
+///
+/// - \`wallet\`: Label of the wallet; the default wallet when left out.
+#[solagent_tool(name = "example", category = "read_only")]
+pub fn example(agent: &SolanaAgentKit, wallet: Option<&str>) -> Result<String, SolagentError> {
+    Ok(agent.wallet(wallet)?.pubkey().to_string())
+}
+RUST
 
 # Create rig-based tool
 cargo new --lib solagent-adapters/rig/$RIG_NAME
 # Rename package name in Cargo.toml
 sed -i "s/name = \"$RIG_NAME\"/name = \"solagent-rig-$RIG_NAME\"/" solagent-adapters/rig/$RIG_NAME/Cargo.toml
+cat >> solagent-adapters/rig/$RIG_NAME/Cargo.toml <<TOML
+solagent-core = { path = "../../../solagent-core" }
+solagent-plugin-$PLUGIN_NAME = { path = "../../../solagent-plugins/$PLUGIN_NAME" }
+TOML
+cat > solagent-adapters/rig/$RIG_NAME/src/lib.rs <<RUST
+$LICENSE_HEADER
+
+use solagent_core::{SolagentTool, SolanaAgentKit};
+use std::sync::Arc;
+
+pub use $PLUGIN_CRATE::{Example, ExampleArgs};
+
+/// Every tool of this crate, acting for \`agent\`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![Arc::new(Example::new(agent.clone()))]
+}
+RUST
 
 # Create examples
-cargo new examples/$EXAMPLE_NAME
\ No newline at end of file
+cargo new examples/$EXAMPLE_NAME
@@ -0,0 +1,12 @@
+#!/bin/bash
+
+# Dumps the mainnet programs the solagent-testkit SVM loads besides those LiteSVM ships.
+# Requires the solana CLI.
+
+set -e
+
+PROGRAMS_DIR=$(dirname "$0")/../solagent-testkit/programs
+mkdir -p "$PROGRAMS_DIR"
+
+# Token Metadata
+solana program dump -u m metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s "$PROGRAMS_DIR/mpl_token_metadata.so"
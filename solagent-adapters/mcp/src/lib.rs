@@ -0,0 +1,34 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [Model Context Protocol](https://modelcontextprotocol.io) server for solagent.
+//!
+//! [`McpServer`] serves a [`ToolRegistry`] as MCP tools, with the JSON Schema of their arguments, so
+//! any MCP client can drive a [`SolanaAgentKit`]. [`McpServer::for_agent`] serves the tools of the
+//! plugins compiled in, one cargo feature per plugin as in `solana-agent`. Calls go through the tools themselves: arguments are
+//! validated and journaled as with rig.
+//!
+//! The `solagent-mcp` binary serves a kit over stdio, or over HTTP with Server-Sent Events.
+//!
+//! [`SolanaAgentKit`]: solagent_core::SolanaAgentKit
+//! [`ToolRegistry`]: solagent_core::ToolRegistry
+
+mod protocol;
+mod server;
+mod sse;
+mod stdio;
+
+pub use server::McpServer;
+pub use sse::serve_sse;
+pub use stdio::serve_stdio;
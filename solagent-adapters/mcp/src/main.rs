@@ -0,0 +1,105 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use solagent_core::{Config, JsonlJournal, SolanaAgentKit, ToolCategory};
+use solagent_mcp::{serve_sse, serve_stdio, McpServer};
+use std::{error::Error, net::SocketAddr, sync::Arc};
+
+const USAGE: &str = "Usage: solagent-mcp [OPTIONS]
+
+Serves the solagent tools over the Model Context Protocol, on stdio unless --http is given.
+
+Options:
+  --config <FILE>     Read the config from a TOML file instead of the environment
+  --profile <NAME>    Apply [profiles.<NAME>] of the config file
+  --journal <FILE>    Journal every tool call to a JSON Lines file
+  --http <ADDRESS>    Serve HTTP with Server-Sent Events on ADDRESS, e.g. 127.0.0.1:8080
+  --read-only         Serve only the tools that never sign
+  -h, --help          Print this help";
+
+#[derive(Default)]
+struct Options {
+    config: Option<String>,
+    profile: Option<String>,
+    journal: Option<String>,
+    http: Option<SocketAddr>,
+    read_only: bool,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+            match arg.as_str() {
+                "--config" => options.config = Some(value()?),
+                "--profile" => options.profile = Some(value()?),
+                "--journal" => options.journal = Some(value()?),
+                "--http" => {
+                    let address = value()?;
+                    options.http = Some(address.parse().map_err(|_| format!("invalid address `{}`", address))?);
+                }
+                "--read-only" => options.read_only = true,
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ => return Err(format!("unexpected argument `{}`\n\n{}", arg, USAGE)),
+            }
+        }
+        if options.profile.is_some() && options.config.is_none() {
+            return Err("--profile needs --config".to_string());
+        }
+        Ok(options)
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(e) = run().await {
+        eprintln!("solagent-mcp: {}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), Box<dyn Error>> {
+    let options = Options::parse(std::env::args().skip(1))?;
+    let config = match (&options.config, &options.profile) {
+        (Some(path), Some(profile)) => Config::from_file_profile(path, profile)?,
+        (Some(path), None) => Config::from_file(path)?,
+        (None, _) => Config::from_env()?,
+    };
+
+    let mut agent = SolanaAgentKit::from_config(config)?;
+    if let Some(path) = &options.journal {
+        agent = agent.with_journal(JsonlJournal::open(path)?);
+    }
+    let mut tools = solana_agent::tools(&Arc::new(agent));
+    if options.read_only {
+        tools = tools.only(&[ToolCategory::ReadOnly]);
+    }
+    let server = Arc::new(McpServer::new(tools));
+
+    match options.http {
+        Some(address) => {
+            if !address.ip().is_loopback() {
+                eprintln!("solagent-mcp: serving {} without authentication, beyond this machine", address);
+            }
+            eprintln!("solagent-mcp: listening on http://{}/sse", address);
+            serve_sse(server, address).await?;
+        }
+        None => serve_stdio(server).await?,
+    }
+    Ok(())
+}
@@ -0,0 +1,250 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::protocol::{
+    Request, Response, RpcError, INVALID_PARAMS, INVALID_REQUEST, METHOD_NOT_FOUND, PARSE_ERROR, PROTOCOL_VERSIONS,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solagent_core::{SolanaAgentKit, ToolCategory, ToolRegistry};
+use std::sync::Arc;
+
+/// Answers MCP messages with the tools of a registry; the transports only move the messages.
+pub struct McpServer {
+    tools: ToolRegistry,
+}
+
+#[derive(Deserialize)]
+struct CallParams {
+    name: String,
+    #[serde(default)]
+    arguments: Option<Value>,
+}
+
+impl McpServer {
+    pub fn new(tools: ToolRegistry) -> Self {
+        Self { tools }
+    }
+
+    /// Serves the tools of every plugin compiled in, see [`solana_agent::tools`].
+    pub fn for_agent(agent: Arc<SolanaAgentKit>) -> Self {
+        Self::new(solana_agent::tools(&agent))
+    }
+
+    /// Answers one message, a request or a batch of them; `None` when nothing is to be sent back, as
+    /// for notifications.
+    pub async fn handle_message(&self, message: &str) -> Option<String> {
+        let response = match serde_json::from_str(message) {
+            Err(e) => Some(json!(Response::error(Value::Null, RpcError::new(PARSE_ERROR, e.to_string())))),
+            Ok(Value::Array(batch)) => {
+                let mut responses = Vec::new();
+                for message in batch {
+                    responses.extend(self.handle(message).await);
+                }
+                (!responses.is_empty()).then(|| json!(responses))
+            }
+            Ok(message) => self.handle(message).await.map(|response| json!(response)),
+        };
+        response.map(|response| response.to_string())
+    }
+
+    async fn handle(&self, message: Value) -> Option<Response> {
+        // The client answering a request of ours; this server sends none.
+        if message.get("method").is_none() && (message.get("result").is_some() || message.get("error").is_some()) {
+            return None;
+        }
+        let request = match serde_json::from_value::<Request>(message) {
+            Ok(request) if request.jsonrpc == "2.0" => request,
+            _ => {
+                let error = RpcError::new(INVALID_REQUEST, "expected a JSON-RPC 2.0 request");
+                return Some(Response::error(Value::Null, error));
+            }
+        };
+        // Notifications, such as `notifications/initialized`, need no answer.
+        let id = request.id?;
+
+        let result = match request.method.as_str() {
+            "initialize" => Ok(self.initialize(&request.params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(self.list_tools()),
+            "tools/call" => self.call_tool(request.params).await,
+            method => Err(RpcError::new(METHOD_NOT_FOUND, format!("unknown method `{}`", method))),
+        };
+        Some(match result {
+            Ok(result) => Response::result(id, result),
+            Err(error) => Response::error(id, error),
+        })
+    }
+
+    fn initialize(&self, params: &Value) -> Value {
+        let requested = params["protocolVersion"].as_str();
+        let version = requested.filter(|version| PROTOCOL_VERSIONS.contains(version)).unwrap_or(PROTOCOL_VERSIONS[0]);
+        json!({
+            "protocolVersion": version,
+            "capabilities": { "tools": { "listChanged": false } },
+            "serverInfo": { "name": "solagent-mcp", "version": env!("CARGO_PKG_VERSION") },
+        })
+    }
+
+    fn list_tools(&self) -> Value {
+        let tools: Vec<Value> = self
+            .tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "name": tool.name(),
+                    "description": tool.description().trim(),
+                    "inputSchema": tool.parameters(),
+                    "annotations": { "readOnlyHint": tool.category() == ToolCategory::ReadOnly },
+                })
+            })
+            .collect();
+        json!({ "tools": tools })
+    }
+
+    /// Runs a tool. Its failures, invalid arguments included, are results flagged with `isError` so
+    /// that the model reads them; only an unknown tool is a protocol error.
+    async fn call_tool(&self, params: Value) -> Result<Value, RpcError> {
+        let params: CallParams =
+            serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))?;
+        let tool = self
+            .tools
+            .get(&params.name)
+            .ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("unknown tool `{}`", params.name)))?;
+
+        let arguments = params.arguments.unwrap_or_else(|| json!({}));
+        let (text, is_error) = match tool.invoke(arguments).await {
+            Ok(output) => (output.to_string(), false),
+            Err(e) => (e.to_string(), true),
+        };
+        Ok(json!({ "content": [{ "type": "text", "text": text }], "isError": is_error }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solagent_core::{SolagentError, SolagentTool, ToolFuture};
+    use solagent_parameters::ToolParameters;
+
+    #[derive(Deserialize, ToolParameters)]
+    struct DivideArgs {
+        dividend: i64,
+        divisor: i64,
+    }
+
+    struct Divide;
+
+    impl SolagentTool for Divide {
+        fn name(&self) -> &str {
+            "divide"
+        }
+
+        fn description(&self) -> &str {
+            "\n    Divide two integers.\n  "
+        }
+
+        fn parameters(&self) -> Value {
+            DivideArgs::schema()
+        }
+
+        fn category(&self) -> ToolCategory {
+            ToolCategory::ReadOnly
+        }
+
+        fn invoke(&self, args: Value) -> ToolFuture<'_> {
+            Box::pin(async move {
+                let args: DivideArgs = serde_json::from_value(args)
+                    .map_err(|e| SolagentError::InvalidInput(format!("invalid arguments: {}", e)))?;
+                let quotient = args.dividend.checked_div(args.divisor);
+                quotient
+                    .map(Value::from)
+                    .ok_or_else(|| SolagentError::InvalidInput("cannot divide by zero".to_string()))
+            })
+        }
+    }
+
+    async fn handle(message: Value) -> Value {
+        let server = McpServer::new(ToolRegistry::new().with(Divide));
+        let response = server.handle_message(&message.to_string()).await.unwrap();
+        serde_json::from_str(&response).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_initialize() {
+        let params = json!({ "protocolVersion": "2024-11-05", "capabilities": {}, "clientInfo": { "name": "test" } });
+        let response = handle(json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": params })).await;
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"]["protocolVersion"], "2024-11-05");
+        assert_eq!(response["result"]["serverInfo"]["name"], "solagent-mcp");
+
+        let params = json!({ "protocolVersion": "1999-01-01" });
+        let response = handle(json!({ "jsonrpc": "2.0", "id": 2, "method": "initialize", "params": params })).await;
+        assert_eq!(response["result"]["protocolVersion"], PROTOCOL_VERSIONS[0]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tools() {
+        let response = handle(json!({ "jsonrpc": "2.0", "id": "a", "method": "tools/list" })).await;
+        let tool = &response["result"]["tools"][0];
+        assert_eq!(tool["name"], "divide");
+        assert_eq!(tool["description"], "Divide two integers.");
+        assert_eq!(tool["inputSchema"], DivideArgs::schema());
+        assert_eq!(tool["annotations"]["readOnlyHint"], true);
+    }
+
+    #[tokio::test]
+    async fn test_call_tool() {
+        let call = |arguments: Value| json!({ "jsonrpc": "2.0", "id": 3, "method": "tools/call", "params": { "name": "divide", "arguments": arguments } });
+
+        let response = handle(call(json!({ "dividend": 7, "divisor": 2 }))).await;
+        assert_eq!(response["result"], json!({ "content": [{ "type": "text", "text": "3" }], "isError": false }));
+
+        let response = handle(call(json!({ "dividend": 7, "divisor": 0 }))).await;
+        assert_eq!(response["result"]["isError"], true);
+        assert_eq!(response["result"]["content"][0]["text"], "Invalid input: cannot divide by zero");
+
+        let response = handle(call(json!({ "dividend": "seven" }))).await;
+        assert_eq!(response["result"]["isError"], true);
+        assert!(response["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .starts_with("Invalid input: invalid arguments"));
+
+        let params = json!({ "name": "multiply", "arguments": {} });
+        let response = handle(json!({ "jsonrpc": "2.0", "id": 4, "method": "tools/call", "params": params })).await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_protocol_errors() {
+        let server = McpServer::new(ToolRegistry::new());
+        let notification = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        assert_eq!(server.handle_message(&notification.to_string()).await, None);
+        assert_eq!(server.handle_message(&json!([notification]).to_string()).await, None);
+
+        let response = handle(json!({ "jsonrpc": "2.0", "id": 5, "method": "resources/list" })).await;
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+
+        let response: Value = serde_json::from_str(&server.handle_message("{").await.unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+        assert_eq!(response["id"], Value::Null);
+
+        let batch =
+            json!([{ "jsonrpc": "2.0", "id": 6, "method": "ping" }, { "jsonrpc": "1.0", "id": 7, "method": "ping" }]);
+        let response = handle(batch).await;
+        assert_eq!(response[0], json!({ "jsonrpc": "2.0", "id": 6, "result": {} }));
+        assert_eq!(response[1]["error"]["code"], INVALID_REQUEST);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::McpServer;
+use axum::{
+    extract::{Query, State},
+    http::{header::ORIGIN, HeaderMap, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
+    routing::{get, post},
+    Router,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{io, net::TcpListener, sync::mpsc};
+use tokio_stream::{wrappers::UnboundedReceiverStream, Stream, StreamExt};
+
+#[derive(Clone)]
+struct Sessions {
+    server: Arc<McpServer>,
+    senders: Arc<Mutex<HashMap<u64, mpsc::UnboundedSender<String>>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+#[derive(Deserialize)]
+struct SessionQuery {
+    #[serde(rename = "sessionId")]
+    session_id: u64,
+}
+
+/// Serves MCP over HTTP with Server-Sent Events on `address`.
+///
+/// A client opens `GET /sse`, whose first `endpoint` event names the URL to `POST` its messages to;
+/// the answers come back as `message` events on the stream.
+///
+/// There is no authentication: anyone who can reach `address` can sign with the kit's wallets. Bind it
+/// to a loopback address; requests from web pages of other origins are refused.
+pub async fn serve_sse(server: Arc<McpServer>, address: SocketAddr) -> io::Result<()> {
+    let sessions = Sessions { server, senders: Default::default(), next_id: Default::default() };
+    let app = Router::new().route("/sse", get(connect)).route("/message", post(message)).with_state(sessions);
+    let listener = TcpListener::bind(address).await?;
+    axum::serve(listener, app).await
+}
+
+async fn connect(
+    State(sessions): State<Sessions>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, StatusCode> {
+    check_origin(&headers)?;
+    let id = sessions.next_id.fetch_add(1, Ordering::Relaxed);
+    let (sender, receiver) = mpsc::unbounded_channel();
+    sessions.senders.lock().unwrap().insert(id, sender);
+
+    let endpoint = Event::default().event("endpoint").data(format!("/message?sessionId={}", id));
+    let messages =
+        UnboundedReceiverStream::new(receiver).map(|message| Ok(Event::default().event("message").data(message)));
+    Ok(Sse::new(tokio_stream::once(Ok(endpoint)).chain(messages)).keep_alive(KeepAlive::default()))
+}
+
+async fn message(
+    State(sessions): State<Sessions>,
+    Query(query): Query<SessionQuery>,
+    headers: HeaderMap,
+    body: String,
+) -> StatusCode {
+    if let Err(status) = check_origin(&headers) {
+        return status;
+    }
+    let Some(sender) = sessions.senders.lock().unwrap().get(&query.session_id).cloned() else {
+        return StatusCode::NOT_FOUND;
+    };
+
+    tokio::spawn(async move {
+        if let Some(response) = sessions.server.handle_message(&body).await {
+            // The stream was closed: the session is over.
+            if sender.send(response).is_err() {
+                sessions.senders.lock().unwrap().remove(&query.session_id);
+            }
+        }
+    });
+    StatusCode::ACCEPTED
+}
+
+/// Refuses browsers on other sites, which could otherwise reach a local server (DNS rebinding).
+fn check_origin(headers: &HeaderMap) -> Result<(), StatusCode> {
+    let Some(origin) = headers.get(ORIGIN) else { return Ok(()) };
+    let host = origin.to_str().unwrap_or_default().split("://").nth(1).unwrap_or_default();
+    let host = match host.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next(),
+        None => host.split(':').next(),
+    };
+    if matches!(host, Some("localhost" | "127.0.0.1" | "::1")) {
+        Ok(())
+    } else {
+        Err(StatusCode::FORBIDDEN)
+    }
+}
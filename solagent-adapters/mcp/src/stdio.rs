@@ -0,0 +1,54 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::McpServer;
+use std::sync::Arc;
+use tokio::{
+    io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader},
+    sync::mpsc,
+};
+
+/// Serves MCP over stdin and stdout, one JSON-RPC message per line, until stdin closes.
+///
+/// Requests run concurrently, so a client can still ping while a swap confirms. Nothing else may
+/// write to stdout; log to stderr.
+pub async fn serve_stdio(server: Arc<McpServer>) -> io::Result<()> {
+    let (sender, mut receiver) = mpsc::unbounded_channel::<String>();
+    let writer = tokio::spawn(async move {
+        let mut stdout = io::stdout();
+        while let Some(response) = receiver.recv().await {
+            stdout.write_all(response.as_bytes()).await?;
+            stdout.write_all(b"\n").await?;
+            stdout.flush().await?;
+        }
+        Ok::<_, io::Error>(())
+    });
+
+    let mut lines = BufReader::new(io::stdin()).lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (server, sender) = (server.clone(), sender.clone());
+        tokio::spawn(async move {
+            if let Some(response) = server.handle_message(&line).await {
+                let _ = sender.send(response);
+            }
+        });
+    }
+
+    // The writer ends once the requests still running have answered.
+    drop(sender);
+    writer.await?
+}
@@ -1,9 +1,7 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_birdeye::{
     get_market_data, get_token_overview, get_wallet_portfolio, TokenMarketDataResponse, TokenOverviewResponse,
     WalletPortfolioResponse,
@@ -15,8 +13,9 @@ use std::sync::Arc;
 /// Market Data Tool
 ///
 /// ///////////////////////////////////////////////////////////////////////////////////////////////////
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct MarketDataArgs {
+    #[parameters(address)]
     address: String,
 }
 
@@ -25,10 +24,6 @@ pub struct MarketDataOutput {
     pub data: TokenMarketDataResponse,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("MarketData error")]
-pub struct MarketDataError;
-
 pub struct MarketData {
     agent: Arc<SolanaAgentKit>,
 }
@@ -37,29 +32,41 @@ impl MarketData {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         MarketData { agent }
     }
+
+    async fn run(&self, args: MarketDataArgs) -> Result<MarketDataOutput, SolagentError> {
+        let data = get_market_data(&self.agent, &args.address).await?;
+
+        Ok(MarketDataOutput { data })
+    }
 }
 
-impl Tool for MarketData {
-    const NAME: &'static str = "get_market_data";
+impl SolagentTool for MarketData {
+    fn name(&self) -> &str {
+        "get_market_data"
+    }
 
-    type Error = MarketDataError;
-    type Args = MarketDataArgs;
-    type Output = MarketDataOutput;
+    fn description(&self) -> &str {
+        "Get market data of single token by birdeye api"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_market_data".to_string(),
-            description: "Get market data of single token by birdeye api".to_string(),
-            parameters: parameters!(
-                address: String,
-            ),
-        }
+    fn parameters(&self) -> Value {
+        MarketDataArgs::schema()
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_market_data(&self.agent, &args.address).await.expect("get_market_data");
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
 
-        Ok(MarketDataOutput { data })
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Get market data of single token by birdeye api".into(),
+            "Show the price, liquidity and market cap of a token.".into(),
+            "How is this token trading on Birdeye?".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
 
@@ -68,8 +75,9 @@ impl Tool for MarketData {
 /// Token Overview Tool
 ///
 /// ///////////////////////////////////////////////////////////////////////////////////////////////////
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct TokenOverviewArgs {
+    #[parameters(address)]
     address: String,
 }
 
@@ -78,10 +86,6 @@ pub struct TokenOverviewOutput {
     pub data: TokenOverviewResponse,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("MarketData error")]
-pub struct TokenOverviewError;
-
 pub struct TokenOverview {
     agent: Arc<SolanaAgentKit>,
 }
@@ -90,29 +94,41 @@ impl TokenOverview {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         TokenOverview { agent }
     }
+
+    async fn run(&self, args: TokenOverviewArgs) -> Result<TokenOverviewOutput, SolagentError> {
+        let data = get_token_overview(&self.agent, &args.address).await?;
+
+        Ok(TokenOverviewOutput { data })
+    }
 }
 
-impl Tool for TokenOverview {
-    const NAME: &'static str = "get_token_overview";
+impl SolagentTool for TokenOverview {
+    fn name(&self) -> &str {
+        "get_token_overview"
+    }
 
-    type Error = TokenOverviewError;
-    type Args = TokenOverviewArgs;
-    type Output = TokenOverviewOutput;
+    fn description(&self) -> &str {
+        "Get overview of a token by birdeye api"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_token_overview".to_string(),
-            description: "Get overview of a token by birdeye api".to_string(),
-            parameters: parameters!(
-                address: String,
-            ),
-        }
+    fn parameters(&self) -> Value {
+        TokenOverviewArgs::schema()
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_token_overview(&self.agent, &args.address).await.expect("get_token_overview");
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
 
-        Ok(TokenOverviewOutput { data })
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Get overview of a token by birdeye api".into(),
+            "Summarize a token: supply, holders, volume and price changes.".into(),
+            "Tell me about this token from Birdeye.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
 
@@ -121,8 +137,9 @@ impl Tool for TokenOverview {
 /// Wallet portfolio Tool
 ///
 /// ///////////////////////////////////////////////////////////////////////////////////////////////////
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct WalletPortfoioArgs {
+    #[parameters(address)]
     address: String,
 }
 
@@ -131,10 +148,6 @@ pub struct WalletPortfoioOutput {
     pub data: WalletPortfolioResponse,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("WalletPortfoio error")]
-pub struct WalletPortfoioError;
-
 pub struct WalletPortfoio {
     agent: Arc<SolanaAgentKit>,
 }
@@ -143,30 +156,48 @@ impl WalletPortfoio {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         WalletPortfoio { agent }
     }
+
+    async fn run(&self, args: WalletPortfoioArgs) -> Result<WalletPortfoioOutput, SolagentError> {
+        let data = get_wallet_portfolio(&self.agent, &args.address).await?;
+        Ok(WalletPortfoioOutput { data })
+    }
 }
 
-impl Tool for WalletPortfoio {
-    const NAME: &'static str = "get_wallet_portfolio";
+impl SolagentTool for WalletPortfoio {
+    fn name(&self) -> &str {
+        "get_wallet_portfolio"
+    }
+
+    fn description(&self) -> &str {
+        "Get wallet portfoio by birdeye api"
+    }
 
-    type Error = WalletPortfoioError;
-    type Args = WalletPortfoioArgs;
-    type Output = WalletPortfoioOutput;
+    fn parameters(&self) -> Value {
+        WalletPortfoioArgs::schema()
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_wallet_portfolio".to_string(),
-            description: "Get wallet portfoio by birdeye api".to_string(),
-            parameters: parameters!(
-                address: String,
-            ),
-        }
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        if let Ok(data) = get_wallet_portfolio(&self.agent, &args.address).await {
-            return Ok(WalletPortfoioOutput { data });
-        }
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Get wallet portfoio by birdeye api".into(),
+            "List the tokens and USD values held by a wallet.".into(),
+            "What is this wallet's portfolio worth?".into(),
+        ]
+    }
 
-        Err(WalletPortfoioError)
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![
+        Arc::new(MarketData::new(agent.clone())),
+        Arc::new(TokenOverview::new(agent.clone())),
+        Arc::new(WalletPortfoio::new(agent.clone())),
+    ]
+}
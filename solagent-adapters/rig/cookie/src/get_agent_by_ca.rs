@@ -13,15 +13,13 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-    IWallet, SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_cookie::get_agent_by_ca;
 use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct GetAgentByCaArgs {
     contract_address: String,
     interval: Option<u32>,
@@ -29,47 +27,53 @@ pub struct GetAgentByCaArgs {
 
 #[derive(Deserialize, Serialize)]
 pub struct GetAgentByCaOutput {
-    pub data: serde_json::Value,
+    pub data: Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetAgentByCa error")]
-pub struct GetAgentByCaError;
-
-pub struct GetAgentByCa<W: IWallet> {
-    agent: Arc<SolanaAgentKit<W>>,
+pub struct GetAgentByCa {
+    agent: Arc<SolanaAgentKit>,
 }
 
-impl<W: IWallet> GetAgentByCa<W> {
-    pub fn new(agent: Arc<SolanaAgentKit<W>>) -> Self {
+impl GetAgentByCa {
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         GetAgentByCa { agent }
     }
-}
 
-impl<W: IWallet + std::marker::Send + std::marker::Sync> Tool for GetAgentByCa<W> {
-    const NAME: &'static str = "get_agent_by_ca";
+    async fn run(&self, args: GetAgentByCaArgs) -> Result<GetAgentByCaOutput, SolagentError> {
+        let data = get_agent_by_ca(&self.agent, &args.contract_address, args.interval).await?;
 
-    type Error = GetAgentByCaError;
-    type Args = GetAgentByCaArgs;
-    type Output = GetAgentByCaOutput;
+        Ok(GetAgentByCaOutput { data })
+    }
+}
+
+impl SolagentTool for GetAgentByCa {
+    fn name(&self) -> &str {
+        "get_agent_by_ca"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_agent_by_ca".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Retrieve agent details in specified interval by one of its tokens contract address.
               "#
-            .to_string(),
-            parameters: parameters!(
-                contract_address: String,
-                interval: Option<u32>,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_agent_by_ca(&self.agent, &args.contract_address, args.interval).await.expect("get_agent_by_ca");
+    fn parameters(&self) -> Value {
+        GetAgentByCaArgs::schema()
+    }
 
-        Ok(GetAgentByCaOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Retrieve agent details in specified interval by one of its tokens contract address.".into(),
+            "Which AI agent is behind this token, and how is it doing?".into(),
+            "Show the mindshare and Twitter stats of the agent of a token.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
@@ -13,15 +13,13 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-    IWallet, SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_cookie::get_agent_by_name;
 use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct GetAgentByTwitterNameArgs {
     twitter_name: String,
     interval: Option<u32>,
@@ -29,47 +27,53 @@ pub struct GetAgentByTwitterNameArgs {
 
 #[derive(Deserialize, Serialize)]
 pub struct GetAgentByTwitterNameOutput {
-    pub data: serde_json::Value,
+    pub data: Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetAgentByTwitterName error")]
-pub struct GetAgentByTwitterNameError;
-
-pub struct GetAgentByTwitterName<W: IWallet> {
-    agent: Arc<SolanaAgentKit<W>>,
+pub struct GetAgentByTwitterName {
+    agent: Arc<SolanaAgentKit>,
 }
 
-impl<W: IWallet> GetAgentByTwitterName<W> {
-    pub fn new(agent: Arc<SolanaAgentKit<W>>) -> Self {
+impl GetAgentByTwitterName {
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         GetAgentByTwitterName { agent }
     }
-}
 
-impl<W: IWallet + std::marker::Send + std::marker::Sync> Tool for GetAgentByTwitterName<W> {
-    const NAME: &'static str = "get_agent_by_name";
+    async fn run(&self, args: GetAgentByTwitterNameArgs) -> Result<GetAgentByTwitterNameOutput, SolagentError> {
+        let data = get_agent_by_name(&self.agent, &args.twitter_name, args.interval).await?;
 
-    type Error = GetAgentByTwitterNameError;
-    type Args = GetAgentByTwitterNameArgs;
-    type Output = GetAgentByTwitterNameOutput;
+        Ok(GetAgentByTwitterNameOutput { data })
+    }
+}
+
+impl SolagentTool for GetAgentByTwitterName {
+    fn name(&self) -> &str {
+        "get_agent_by_name"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_agent_by_name".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Retrieve agent details in specified interval by twitter username.
               "#
-            .to_string(),
-            parameters: parameters!(
-                twitter_name: String,
-                interval: Option<u32>,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_agent_by_name(&self.agent, &args.twitter_name, args.interval).await.expect("get_agent_by_name");
+    fn parameters(&self) -> Value {
+        GetAgentByTwitterNameArgs::schema()
+    }
 
-        Ok(GetAgentByTwitterNameOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Retrieve agent details in specified interval by twitter username.".into(),
+            "How is this AI agent doing on Twitter?".into(),
+            "Show the mindshare and market cap of an agent by its Twitter handle.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
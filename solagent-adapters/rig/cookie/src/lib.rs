@@ -20,3 +20,15 @@ pub use get_agent_by_name::GetAgentByTwitterName;
 
 mod search_tweets;
 pub use search_tweets::SearchTweets;
+
+use solagent_core::{SolagentTool, SolanaAgentKit};
+use std::sync::Arc;
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![
+        Arc::new(GetAgentByCa::new(agent.clone())),
+        Arc::new(GetAgentByTwitterName::new(agent.clone())),
+        Arc::new(SearchTweets::new(agent.clone())),
+    ]
+}
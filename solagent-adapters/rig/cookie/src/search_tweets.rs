@@ -13,15 +13,13 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-    IWallet, SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_cookie::search_tweets;
 use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct SearchTweetsArgs {
     tweets: String,
     from: String,
@@ -30,47 +28,54 @@ pub struct SearchTweetsArgs {
 
 #[derive(Deserialize, Serialize)]
 pub struct SearchTweetsOutput {
-    pub data: serde_json::Value,
+    pub data: Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("SearchTweets error")]
-pub struct SearchTweetsError;
-
-pub struct SearchTweets<W: IWallet> {
-    agent: Arc<SolanaAgentKit<W>>,
+pub struct SearchTweets {
+    agent: Arc<SolanaAgentKit>,
 }
 
-impl<W: IWallet> SearchTweets<W> {
-    pub fn new(agent: Arc<SolanaAgentKit<W>>) -> Self {
+impl SearchTweets {
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         SearchTweets { agent }
     }
-}
 
-impl<W: IWallet + std::marker::Send + std::marker::Sync> Tool for SearchTweets<W> {
-    const NAME: &'static str = "search_tweets";
+    async fn run(&self, args: SearchTweetsArgs) -> Result<SearchTweetsOutput, SolagentError> {
+        let data = search_tweets(&self.agent, &args.tweets, &args.from, &args.to).await?;
 
-    type Error = SearchTweetsError;
-    type Args = SearchTweetsArgs;
-    type Output = SearchTweetsOutput;
+        Ok(SearchTweetsOutput { data })
+    }
+}
+
+impl SolagentTool for SearchTweets {
+    fn name(&self) -> &str {
+        "search_tweets"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "search_tweets".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Retrieve popular content matching search query, created in time range {from} - {to} (YYYY-MM-DD dates).
               "#
-            .to_string(),
-            parameters: parameters!(
-                tweets: String,
-                from: String,
-                to: String,            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = search_tweets(&self.agent, &args.tweets, &args.from, &args.to).await.expect("search_tweets");
+    fn parameters(&self) -> Value {
+        SearchTweetsArgs::schema()
+    }
 
-        Ok(SearchTweetsOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Retrieve popular content matching search query, created in time range {from} - {to} (YYYY-MM-DD dates)."
+                .into(),
+            "What are people tweeting about this topic?".into(),
+            "Find popular tweets about a token between two dates.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
@@ -1,11 +1,11 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_dexscreener::{get_token_data_by_ticker, DexTokenData};
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct GetTokenDataByTickerArgs {
     ticker: String,
 }
@@ -15,39 +15,53 @@ pub struct GetTokenDataByTickerOutput {
     pub data: DexTokenData,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetTokenDataByTicker error")]
-pub struct GetTokenDataByTickerError;
-
-#[derive(Default)]
-pub struct GetTokenDataByTicker {}
+pub struct GetTokenDataByTicker {
+    agent: Arc<SolanaAgentKit>,
+}
 
 impl GetTokenDataByTicker {
-    pub fn new() -> Self {
-        GetTokenDataByTicker {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        GetTokenDataByTicker { agent }
+    }
+
+    async fn run(&self, args: GetTokenDataByTickerArgs) -> Result<GetTokenDataByTickerOutput, SolagentError> {
+        let data = get_token_data_by_ticker(&self.agent, &args.ticker).await?;
+
+        Ok(GetTokenDataByTickerOutput { data })
     }
 }
 
-impl Tool for GetTokenDataByTicker {
-    const NAME: &'static str = "get_token_data_by_ticker";
+impl SolagentTool for GetTokenDataByTicker {
+    fn name(&self) -> &str {
+        "get_token_data_by_ticker"
+    }
+
+    fn description(&self) -> &str {
+        "Get the token data for a given token ticker on Dexscreener"
+    }
 
-    type Error = GetTokenDataByTickerError;
-    type Args = GetTokenDataByTickerArgs;
-    type Output = GetTokenDataByTickerOutput;
+    fn parameters(&self) -> Value {
+        GetTokenDataByTickerArgs::schema()
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_token_data_by_ticker".to_string(),
-            description: "Get the token data for a given token ticker on Dexscreener".to_string(),
-            parameters: parameters!(
-                ticker: String
-            ),
-        }
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_token_data_by_ticker(&args.ticker).await.expect("get_token_data_by_ticker");
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Get the token data for a given token ticker on Dexscreener".into(),
+            "Find the mint address of a token from its symbol.".into(),
+            "Look up trading pairs and liquidity of a ticker on Dexscreener.".into(),
+        ]
+    }
 
-        Ok(GetTokenDataByTickerOutput { data })
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![Arc::new(GetTokenDataByTicker::new(agent.clone()))]
+}
@@ -1,20 +1,22 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-    solana_sdk::pubkey::Pubkey,
-    SolanaAgentKit,
+    solana_sdk::pubkey::Pubkey, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory,
+    ToolFuture,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_gibwork::{create_gibwork_task, GibworkCreateTaskResponse};
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct CreateGibworkTaskArgs {
     title: String,
     content: String,
     requirements: String,
     tags: Vec<String>,
+    #[parameters(address)]
     token_mint_address: String,
+    #[parameters(exclusive_minimum = 0)]
     token_amount: u64,
     payer: Option<Pubkey>,
 }
@@ -24,10 +26,6 @@ pub struct CreateGibworkTaskOutput {
     pub data: GibworkCreateTaskResponse,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("CreateGibworkTask error")]
-pub struct CreateGibworkTaskError;
-
 pub struct CreateGibworkTask {
     agent: Arc<SolanaAgentKit>,
 }
@@ -36,19 +34,34 @@ impl CreateGibworkTask {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         CreateGibworkTask { agent }
     }
-}
 
-impl Tool for CreateGibworkTask {
-    const NAME: &'static str = "create_gibwork_task";
+    async fn run(
+        &self,
+        args: CreateGibworkTaskArgs,
+    ) -> Result<CreateGibworkTaskOutput, SolagentError> {
+        let data = create_gibwork_task(
+            &self.agent,
+            &args.title,
+            &args.content,
+            &args.requirements,
+            args.tags,
+            &args.token_mint_address,
+            args.token_amount,
+            args.payer,
+        )
+        .await?;
+
+        Ok(CreateGibworkTaskOutput { data })
+    }
+}
 
-    type Error = CreateGibworkTaskError;
-    type Args = CreateGibworkTaskArgs;
-    type Output = CreateGibworkTaskOutput;
+impl SolagentTool for CreateGibworkTask {
+    fn name(&self) -> &str {
+        "create_gibwork_task"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "create_gibwork_task".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Create a new task on the Gibwork platform with payment in SPL tokens.
             
             examples: [
@@ -73,33 +86,33 @@ impl Tool for CreateGibworkTask {
                 ],
             ]
             "#
-            .to_string(),
-            parameters: parameters!(
-                title: String,
-                content: String,
-                requirements: String,
-                tags: Vec<String>,
-                token_mint_address: String,
-                token_amount: u64,
-                payer: Option<Pubkey>,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = create_gibwork_task(
-            &self.agent,
-            &args.title,
-            &args.content,
-            &args.requirements,
-            args.tags,
-            &args.token_mint_address,
-            args.token_amount,
-            args.payer,
-        )
-        .await
-        .expect("create_gibwork_task");
+    fn parameters(&self) -> Value {
+        CreateGibworkTaskArgs::schema()
+    }
 
-        Ok(CreateGibworkTaskOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
     }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Create a new task on the Gibwork platform with payment in SPL tokens.".into(),
+            "Post a bounty for work, paid in tokens.".into(),
+            "Hire someone on Gibwork for a job.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
+}
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![Arc::new(CreateGibworkTask::new(agent.clone()))]
 }
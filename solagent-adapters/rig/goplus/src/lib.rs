@@ -7,3 +7,16 @@ pub use solana_token_security_info::SolanaTokenSecurityInfo;
 pub use token_malicious_info::TokenMaliciousInfo;
 pub use token_phishing_site_info::PhishingSiteInfo;
 pub use token_security_info::TokenSecurityInfo;
+
+use solagent_core::{SolagentTool, SolanaAgentKit};
+use std::sync::Arc;
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![
+        Arc::new(SolanaTokenSecurityInfo::new(agent.clone())),
+        Arc::new(TokenMaliciousInfo::new(agent.clone())),
+        Arc::new(PhishingSiteInfo::new(agent.clone())),
+        Arc::new(TokenSecurityInfo::new(agent.clone())),
+    ]
+}
@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{completion::ToolDefinition, tool::Tool};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_goplus::get_solana_token_security_info;
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct SolanaTokenSecurityInfoArgs {
+    #[parameters(address)]
     contract_address: String,
 }
 
@@ -13,29 +16,29 @@ pub struct SolanaTokenSecurityInfoOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("SolanaTokenSecurityInfo error")]
-pub struct SolanaTokenSecurityInfoError;
+pub struct SolanaTokenSecurityInfo {
+    agent: Arc<SolanaAgentKit>,
+}
 
-#[derive(Default)]
-pub struct SolanaTokenSecurityInfo;
 impl SolanaTokenSecurityInfo {
-    pub fn new() -> Self {
-        SolanaTokenSecurityInfo {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        SolanaTokenSecurityInfo { agent }
     }
-}
 
-impl Tool for SolanaTokenSecurityInfo {
-    const NAME: &'static str = "get_solana_token_security_info";
+    async fn run(&self, args: SolanaTokenSecurityInfoArgs) -> Result<SolanaTokenSecurityInfoOutput, SolagentError> {
+        let data = get_solana_token_security_info(&self.agent, &args.contract_address).await?;
 
-    type Error = SolanaTokenSecurityInfoError;
-    type Args = SolanaTokenSecurityInfoArgs;
-    type Output = SolanaTokenSecurityInfoOutput;
+        Ok(SolanaTokenSecurityInfoOutput { data })
+    }
+}
+
+impl SolagentTool for SolanaTokenSecurityInfo {
+    fn name(&self) -> &str {
+        "get_solana_token_security_info"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_solana_token_security_info".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Token Security API for Solana (Beta).
 
@@ -49,18 +52,25 @@ impl Tool for SolanaTokenSecurityInfo {
                 ],
             ]
               "#
-            .to_string(),
-            parameters: parameters!(
-                chain_id: String,
-                contract_address: String
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data =
-            get_solana_token_security_info(&args.contract_address).await.expect("get_solana_token_security_info");
+    fn parameters(&self) -> Value {
+        SolanaTokenSecurityInfoArgs::schema()
+    }
 
-        Ok(SolanaTokenSecurityInfoOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Token Security API for Solana (Beta).".into(),
+            "Check a Solana token for mint, freeze and transfer fee risks with GoPlus.".into(),
+            "Is this Solana token safe? Audit its authorities and metadata.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
-use serde_json::json;
-use solagent_core::rig::{completion::ToolDefinition, tool::Tool};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_goplus::get_token_malicious_info;
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct TokenMaliciousInfoArgs {
     chain_id: String,
     address: String,
@@ -15,29 +16,29 @@ pub struct TokenMaliciousInfoOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("TokenMaliciousInfo error")]
-pub struct TokenMaliciousInfoError;
+pub struct TokenMaliciousInfo {
+    agent: Arc<SolanaAgentKit>,
+}
 
-#[derive(Default)]
-pub struct TokenMaliciousInfo;
 impl TokenMaliciousInfo {
-    pub fn new() -> Self {
-        TokenMaliciousInfo {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        TokenMaliciousInfo { agent }
     }
-}
 
-impl Tool for TokenMaliciousInfo {
-    const NAME: &'static str = "get_token_malicious_info";
+    async fn run(&self, args: TokenMaliciousInfoArgs) -> Result<TokenMaliciousInfoOutput, SolagentError> {
+        let data = get_token_malicious_info(&self.agent, &args.chain_id, &args.address).await?;
 
-    type Error = TokenMaliciousInfoError;
-    type Args = TokenMaliciousInfoArgs;
-    type Output = TokenMaliciousInfoOutput;
+        Ok(TokenMaliciousInfoOutput { data })
+    }
+}
+
+impl SolagentTool for TokenMaliciousInfo {
+    fn name(&self) -> &str {
+        "get_token_malicious_info"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_token_malicious_info".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Check if the address is malicious
 
             examples: [
@@ -51,17 +52,25 @@ impl Tool for TokenMaliciousInfo {
                 ],
             ]
               "#
-            .to_string(),
-            parameters: parameters!(
-                chain_id: String,
-                contract_address: String
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_token_malicious_info(&args.chain_id, &args.address).await.expect("get_token_malicious_info");
+    fn parameters(&self) -> Value {
+        TokenMaliciousInfoArgs::schema()
+    }
 
-        Ok(TokenMaliciousInfoOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Check if the address is malicious".into(),
+            "Is this address flagged for scams, theft or phishing by GoPlus?".into(),
+            "Screen a counterparty address before sending funds.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
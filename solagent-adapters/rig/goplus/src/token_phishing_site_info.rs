@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{completion::ToolDefinition, tool::Tool};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_goplus::get_token_phishing_site_info;
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct PhishingSiteInfoArgs {
     url: String,
 }
@@ -13,29 +15,29 @@ pub struct PhishingSiteInfoOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("PhishingSiteInfo error")]
-pub struct PhishingSiteInfoError;
+pub struct PhishingSiteInfo {
+    agent: Arc<SolanaAgentKit>,
+}
 
-#[derive(Default)]
-pub struct PhishingSiteInfo;
 impl PhishingSiteInfo {
-    pub fn new() -> Self {
-        PhishingSiteInfo {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        PhishingSiteInfo { agent }
     }
-}
 
-impl Tool for PhishingSiteInfo {
-    const NAME: &'static str = "get_token_phishing_site_info";
+    async fn run(&self, args: PhishingSiteInfoArgs) -> Result<PhishingSiteInfoOutput, SolagentError> {
+        let data = get_token_phishing_site_info(&self.agent, &args.url).await?;
 
-    type Error = PhishingSiteInfoError;
-    type Args = PhishingSiteInfoArgs;
-    type Output = PhishingSiteInfoOutput;
+        Ok(PhishingSiteInfoOutput { data })
+    }
+}
+
+impl SolagentTool for PhishingSiteInfo {
+    fn name(&self) -> &str {
+        "get_token_phishing_site_info"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_token_phishing_site_info".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Check if the URL is a phishing site.
 
             examples: [
@@ -48,17 +50,25 @@ impl Tool for PhishingSiteInfo {
                 ],
             ]
               "#
-            .to_string(),
-            parameters: parameters!(
-                chain_id: String,
-                contract_address: String
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_token_phishing_site_info(&args.url).await.expect("get_token_phishing_site_info");
+    fn parameters(&self) -> Value {
+        PhishingSiteInfoArgs::schema()
+    }
 
-        Ok(PhishingSiteInfoOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Check if the URL is a phishing site.".into(),
+            "Is this website safe to connect a wallet to?".into(),
+            "Screen a link for scams with GoPlus.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{completion::ToolDefinition, tool::Tool};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_goplus::get_token_security_info;
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct TokenSecurityInfoArgs {
     chain_id: String,
     contract_address: String,
@@ -14,29 +16,29 @@ pub struct TokenSecurityInfoOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("TokenSecurityInfo error")]
-pub struct TokenSecurityInfoError;
+pub struct TokenSecurityInfo {
+    agent: Arc<SolanaAgentKit>,
+}
 
-#[derive(Default)]
-pub struct TokenSecurityInfo;
 impl TokenSecurityInfo {
-    pub fn new() -> Self {
-        TokenSecurityInfo {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        TokenSecurityInfo { agent }
     }
-}
 
-impl Tool for TokenSecurityInfo {
-    const NAME: &'static str = "get_token_security_info";
+    async fn run(&self, args: TokenSecurityInfoArgs) -> Result<TokenSecurityInfoOutput, SolagentError> {
+        let data = get_token_security_info(&self.agent, &args.chain_id, &args.contract_address).await?;
 
-    type Error = TokenSecurityInfoError;
-    type Args = TokenSecurityInfoArgs;
-    type Output = TokenSecurityInfoOutput;
+        Ok(TokenSecurityInfoOutput { data })
+    }
+}
+
+impl SolagentTool for TokenSecurityInfo {
+    fn name(&self) -> &str {
+        "get_token_security_info"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_token_security_info".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Get token security information
 
             examples: [
@@ -50,18 +52,25 @@ impl Tool for TokenSecurityInfo {
                 ],
             ]
               "#
-            .to_string(),
-            parameters: parameters!(
-                chain_id: String,
-                contract_address: String
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data =
-            get_token_security_info(&args.chain_id, &args.contract_address).await.expect("get_token_security_info");
+    fn parameters(&self) -> Value {
+        TokenSecurityInfoArgs::schema()
+    }
 
-        Ok(TokenSecurityInfoOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Get token security information".into(),
+            "Detect honeypots, taxes and owner privileges of an EVM token with GoPlus.".into(),
+            "Audit the contract of a token on another chain.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
@@ -1,17 +1,13 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::Tool,
-    },
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_helius::{create_webhook, HeliusWebhookResponse};
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct CreateWebHookArgs {
+    #[parameters(address)]
     account_addresses: Vec<String>,
     webhook_url: String,
 }
@@ -21,10 +17,6 @@ pub struct CreateWebHookOutput {
     pub data: HeliusWebhookResponse,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("CreateWebHook error")]
-pub struct CreateWebHookError;
-
 pub struct CreateWebHook {
     agent: Arc<SolanaAgentKit>,
 }
@@ -33,19 +25,23 @@ impl CreateWebHook {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         CreateWebHook { agent }
     }
-}
 
-impl Tool for CreateWebHook {
-    const NAME: &'static str = "create_webhook";
+    async fn run(&self, args: CreateWebHookArgs) -> Result<CreateWebHookOutput, SolagentError> {
+        let account_addresses = args.account_addresses;
+        let webhook_url = args.webhook_url;
+        let data = create_webhook(&self.agent, account_addresses, webhook_url).await?;
 
-    type Error = CreateWebHookError;
-    type Args = CreateWebHookArgs;
-    type Output = CreateWebHookOutput;
+        Ok(CreateWebHookOutput { data })
+    }
+}
+
+impl SolagentTool for CreateWebHook {
+    fn name(&self) -> &str {
+        "create_webhook"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "create_webhook".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Creates a new webhook in the Helius system to monitor transactions for specified account addresses
 
@@ -58,19 +54,25 @@ impl Tool for CreateWebHook {
             },
            
             "#
-            .to_string(),
-            parameters: parameters!(
-                account_addresses: Vec<String>,
-                webhook_url: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let account_addresses = args.account_addresses;
-        let webhook_url = args.webhook_url;
-        let data = create_webhook(&self.agent, account_addresses, webhook_url).await.expect("create_webhook");
+    fn parameters(&self) -> Value {
+        CreateWebHookArgs::schema()
+    }
 
-        Ok(CreateWebHookOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Deployment
     }
-}
\ No newline at end of file
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Creates a new webhook in the Helius system to monitor transactions for specified account addresses".into(),
+            "Get notified at a URL when these accounts transact.".into(),
+            "Watch wallet activity with a Helius webhook.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
+    }
+}
@@ -1,16 +1,11 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::Tool,
-    },
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_helius::delete_webhook;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct DeleteWebHookArgs {
     webhook_id: String,
 }
@@ -20,10 +15,6 @@ pub struct DeleteWebHookOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("DeleteWebHook error")]
-pub struct DeleteWebHookError;
-
 pub struct DeleteWebHook {
     agent: Arc<SolanaAgentKit>,
 }
@@ -32,19 +23,21 @@ impl DeleteWebHook {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         DeleteWebHook { agent }
     }
-}
 
-impl Tool for DeleteWebHook {
-    const NAME: &'static str = "delete_webhook";
+    async fn run(&self, args: DeleteWebHookArgs) -> Result<DeleteWebHookOutput, SolagentError> {
+        let data = delete_webhook(&self.agent, &args.webhook_id).await?;
 
-    type Error = DeleteWebHookError;
-    type Args = DeleteWebHookArgs;
-    type Output = DeleteWebHookOutput;
+        Ok(DeleteWebHookOutput { data })
+    }
+}
+
+impl SolagentTool for DeleteWebHook {
+    fn name(&self) -> &str {
+        "delete_webhook"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "delete_webhook".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Deletes a Helius webhook by its unique ID
 
@@ -53,16 +46,25 @@ impl Tool for DeleteWebHook {
             },
            
             "#
-            .to_string(),
-            parameters: parameters!(
-                webhook_id: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = delete_webhook(&self.agent, &args.webhook_id).await.expect("delete_webhook");
+    fn parameters(&self) -> Value {
+        DeleteWebHookArgs::schema()
+    }
 
-        Ok(DeleteWebHookOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Deployment
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Deletes a Helius webhook by its unique ID".into(),
+            "Stop monitoring accounts and remove a webhook.".into(),
+            "Unsubscribe from Helius transaction notifications.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
 }
@@ -1,18 +1,15 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::Tool,
-    },
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_helius::get_assets_by_owner;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct GetAssetsByOwnerArgs {
+    #[parameters(address)]
     owner_public_key: String,
+    #[parameters(minimum = 1, maximum = 1000)]
     limit: u32,
 }
 
@@ -21,10 +18,6 @@ pub struct GetAssetsByOwnerOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetAssetsByOwner error")]
-pub struct GetAssetsByOwnerError;
-
 pub struct GetAssetsByOwner {
     agent: Arc<SolanaAgentKit>,
 }
@@ -33,19 +26,21 @@ impl GetAssetsByOwner {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         GetAssetsByOwner { agent }
     }
-}
 
-impl Tool for GetAssetsByOwner {
-    const NAME: &'static str = "get_assets_by_owner";
+    async fn run(&self, args: GetAssetsByOwnerArgs) -> Result<GetAssetsByOwnerOutput, SolagentError> {
+        let data = get_assets_by_owner(&self.agent, &args.owner_public_key, args.limit).await?;
 
-    type Error = GetAssetsByOwnerError;
-    type Args = GetAssetsByOwnerArgs;
-    type Output = GetAssetsByOwnerOutput;
+        Ok(GetAssetsByOwnerOutput { data })
+    }
+}
+
+impl SolagentTool for GetAssetsByOwner {
+    fn name(&self) -> &str {
+        "get_assets_by_owner"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_assets_by_owner".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Fetch assets owned by a specific Solana wallet address using the Helius Digital Asset Standard API
 
@@ -55,18 +50,25 @@ impl Tool for GetAssetsByOwner {
             },
            
             "#
-            .to_string(),
-            parameters: parameters!(
-                owner_pubkey: String,
-                limit: u32,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data =
-            get_assets_by_owner(&self.agent, &args.owner_public_key, args.limit).await.expect("get_assets_by_owner");
+    fn parameters(&self) -> Value {
+        GetAssetsByOwnerArgs::schema()
+    }
 
-        Ok(GetAssetsByOwnerOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Fetch assets owned by a specific Solana wallet address using the Helius Digital Asset Standard API".into(),
+            "List the NFTs and tokens held by a wallet.".into(),
+            "Show the digital assets in an address.".into(),
+        ]
     }
-}
\ No newline at end of file
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
+    }
+}
@@ -1,16 +1,11 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::Tool,
-    },
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_helius::{get_webhook, HeliusWebhookIdResponse};
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct GetWebHookArgs {
     webhook_id: String,
 }
@@ -20,10 +15,6 @@ pub struct GetWebHookOutput {
     pub data: HeliusWebhookIdResponse,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetWebHook error")]
-pub struct GetWebHookError;
-
 pub struct GetWebHook {
     agent: Arc<SolanaAgentKit>,
 }
@@ -32,19 +23,21 @@ impl GetWebHook {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         GetWebHook { agent }
     }
-}
 
-impl Tool for GetWebHook {
-    const NAME: &'static str = "get_webhook";
+    async fn run(&self, args: GetWebHookArgs) -> Result<GetWebHookOutput, SolagentError> {
+        let data = get_webhook(&self.agent, &args.webhook_id).await?;
 
-    type Error = GetWebHookError;
-    type Args = GetWebHookArgs;
-    type Output = GetWebHookOutput;
+        Ok(GetWebHookOutput { data })
+    }
+}
+
+impl SolagentTool for GetWebHook {
+    fn name(&self) -> &str {
+        "get_webhook"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_webhook".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Retrieves details of a Helius webhook by its unique ID
 
@@ -53,16 +46,25 @@ impl Tool for GetWebHook {
             },
            
             "#
-            .to_string(),
-            parameters: parameters!(
-                webhook_id: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_webhook(&self.agent, &args.webhook_id).await.expect("get_webhook");
+    fn parameters(&self) -> Value {
+        GetWebHookArgs::schema()
+    }
 
-        Ok(GetWebHookOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Retrieves details of a Helius webhook by its unique ID".into(),
+            "Show which accounts and URL a webhook watches.".into(),
+            "Look up the configuration of a Helius webhook.".into(),
+        ]
     }
-}
\ No newline at end of file
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
+    }
+}
@@ -3,3 +3,17 @@ pub mod delete_webhook;
 pub mod get_assets_by_owner;
 pub mod get_webhook;
 pub mod transaction_parsing;
+
+use solagent_core::{SolagentTool, SolanaAgentKit};
+use std::sync::Arc;
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![
+        Arc::new(create_webhook::CreateWebHook::new(agent.clone())),
+        Arc::new(delete_webhook::DeleteWebHook::new(agent.clone())),
+        Arc::new(get_assets_by_owner::GetAssetsByOwner::new(agent.clone())),
+        Arc::new(get_webhook::GetWebHook::new(agent.clone())),
+        Arc::new(transaction_parsing::TransactionParse::new(agent.clone())),
+    ]
+}
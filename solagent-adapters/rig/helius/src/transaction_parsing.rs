@@ -1,16 +1,11 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::Tool,
-    },
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_helius::transaction_parse;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct TransactionParseArgs {
     transaction_id: String,
 }
@@ -20,10 +15,6 @@ pub struct TransactionParseOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("TransactionParse error")]
-pub struct TransactionParseError;
-
 pub struct TransactionParse {
     agent: Arc<SolanaAgentKit>,
 }
@@ -32,19 +23,21 @@ impl TransactionParse {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         TransactionParse { agent }
     }
-}
 
-impl Tool for TransactionParse {
-    const NAME: &'static str = "transaction_parse";
+    async fn run(&self, args: TransactionParseArgs) -> Result<TransactionParseOutput, SolagentError> {
+        let data = transaction_parse(&self.agent, &args.transaction_id).await?;
 
-    type Error = TransactionParseError;
-    type Args = TransactionParseArgs;
-    type Output = TransactionParseOutput;
+        Ok(TransactionParseOutput { data })
+    }
+}
+
+impl SolagentTool for TransactionParse {
+    fn name(&self) -> &str {
+        "transaction_parse"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "transaction_parse".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Parse a Solana transaction to retrieve detailed information using the Helius Enhanced Transactions API
 
@@ -53,16 +46,26 @@ impl Tool for TransactionParse {
             },
            
             "#
-            .to_string(),
-            parameters: parameters!(
-                transaction_id: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = transaction_parse(&self.agent, &args.transaction_id).await.expect("transaction_parse");
+    fn parameters(&self) -> Value {
+        TransactionParseArgs::schema()
+    }
 
-        Ok(TransactionParseOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Parse a Solana transaction to retrieve detailed information using the Helius Enhanced Transactions API"
+                .into(),
+            "Explain what happened in a transaction signature.".into(),
+            "Decode the transfers, swaps and fees of a transaction.".into(),
+        ]
     }
-}
\ No newline at end of file
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
+    }
+}
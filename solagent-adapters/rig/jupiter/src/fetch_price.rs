@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{completion::ToolDefinition, tool::Tool};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_jupiter::fetch_price;
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct FetchPriceArgs {
+    #[parameters(address)]
     token_address: String,
 }
 
@@ -13,29 +16,29 @@ pub struct FetchPriceOutput {
     pub price: String,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("FetchPrice error")]
-pub struct FetchPriceError;
+pub struct FetchPrice {
+    agent: Arc<SolanaAgentKit>,
+}
 
-#[derive(Default)]
-pub struct FetchPrice;
 impl FetchPrice {
-    pub fn new() -> Self {
-        FetchPrice {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        FetchPrice { agent }
     }
-}
 
-impl Tool for FetchPrice {
-    const NAME: &'static str = "fetch_price";
+    async fn run(&self, args: FetchPriceArgs) -> Result<FetchPriceOutput, SolagentError> {
+        let price = fetch_price(&self.agent, &args.token_address).await?;
 
-    type Error = FetchPriceError;
-    type Args = FetchPriceArgs;
-    type Output = FetchPriceOutput;
+        Ok(FetchPriceOutput { price })
+    }
+}
+
+impl SolagentTool for FetchPrice {
+    fn name(&self) -> &str {
+        "fetch_price"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "fetch_price".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Fetch the current price of a Solana token in USDC using Jupiter API.
 
             input: {
@@ -43,16 +46,28 @@ impl Tool for FetchPrice {
             },
 
             "#
-            .to_string(),
-            parameters: parameters!(
-                token_address: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let price = fetch_price(&args.token_address).await.expect("fetch_price");
+    fn parameters(&self) -> Value {
+        FetchPriceArgs::schema()
+    }
 
-        Ok(FetchPriceOutput { price })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Fetch the current price of a Solana token in USDC using Jupiter API.".into(),
+            "What is the price of this token in dollars?".into(),
+            "Get the USD value of a token mint.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
     }
 }
@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{completion::ToolDefinition, tool::Tool};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_jupiter::get_token_data_by_address;
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct GetTokenDataArgs {
+    #[parameters(address)]
     mint: String,
 }
 
@@ -13,29 +16,29 @@ pub struct GetTokenDataOutput {
     pub data: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetTokenData error")]
-pub struct GetTokenDataError;
+pub struct GetTokenData {
+    agent: Arc<SolanaAgentKit>,
+}
 
-#[derive(Default)]
-pub struct GetTokenData;
 impl GetTokenData {
-    pub fn new() -> Self {
-        GetTokenData {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        GetTokenData { agent }
     }
-}
 
-impl Tool for GetTokenData {
-    const NAME: &'static str = "get_token_data_by_address";
+    async fn run(&self, args: GetTokenDataArgs) -> Result<GetTokenDataOutput, SolagentError> {
+        let data = get_token_data_by_address(&self.agent, &args.mint).await?;
 
-    type Error = GetTokenDataError;
-    type Args = GetTokenDataArgs;
-    type Output = GetTokenDataOutput;
+        Ok(GetTokenDataOutput { data })
+    }
+}
+
+impl SolagentTool for GetTokenData {
+    fn name(&self) -> &str {
+        "get_token_data_by_address"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_token_data_by_address".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Get the token data for a given token mint address.
 
             examples: [
@@ -49,18 +52,28 @@ impl Tool for GetTokenData {
                 ],
             ]
               "#
-            .to_string(),
-            parameters: parameters!(
-                mint: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = get_token_data_by_address(&args.mint)
-            .await
-            .expect("get_token_data_by_address");
+    fn parameters(&self) -> Value {
+        GetTokenDataArgs::schema()
+    }
 
-        Ok(GetTokenDataOutput { data })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Get the token data for a given token mint address.".into(),
+            "Look up the name, symbol and decimals of a mint on Jupiter.".into(),
+            "What token is this mint address?".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
     }
 }
@@ -2,3 +2,16 @@ pub mod fetch_price;
 pub mod get_token_data_by_address;
 pub mod stake_with_jup;
 pub mod trade;
+
+use solagent_core::{SolagentTool, SolanaAgentKit};
+use std::sync::Arc;
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![
+        Arc::new(fetch_price::FetchPrice::new(agent.clone())),
+        Arc::new(get_token_data_by_address::GetTokenData::new(agent.clone())),
+        Arc::new(stake_with_jup::StakeWithJup::new(agent.clone())),
+        Arc::new(trade::Trade::new(agent.clone())),
+    ]
+}
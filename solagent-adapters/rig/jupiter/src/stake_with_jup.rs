@@ -1,26 +1,26 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-    SolanaAgentKit,
+    Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture,
+    TransactionOutcome,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_jupiter::stake_with_jup;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct StakeWithJupArgs {
+    #[parameters(exclusive_minimum = 0, decimals = 9)]
     amount: f64,
+    wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct StakeWithJupOutput {
-    pub signature: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("StakeWithJup error")]
-pub struct StakeWithJupError;
-
 pub struct StakeWithJup {
     agent: Arc<SolanaAgentKit>,
 }
@@ -29,19 +29,24 @@ impl StakeWithJup {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         StakeWithJup { agent }
     }
-}
 
-impl Tool for StakeWithJup {
-    const NAME: &'static str = "stake_with_jup";
+    async fn run(&self, args: StakeWithJupArgs) -> Result<StakeWithJupOutput, SolagentError> {
+        let transaction = stake_with_jup(&self.agent, args.amount, args.wallet.as_deref()).await?;
+
+        Ok(StakeWithJupOutput {
+            transaction,
+            network: self.agent.network.clone(),
+        })
+    }
+}
 
-    type Error = StakeWithJupError;
-    type Args = StakeWithJupArgs;
-    type Output = StakeWithJupOutput;
+impl SolagentTool for StakeWithJup {
+    fn name(&self) -> &str {
+        "stake_with_jup"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "stake_with_jup".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Stake SOL tokens with Jupiter's liquid staking protocol to receive jupSOL
                
             examples: [
@@ -61,18 +66,28 @@ impl Tool for StakeWithJup {
             ],
                 
             "#
-            .to_string(),
-            parameters: parameters!(
-                amount: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let signature = stake_with_jup(&self.agent, args.amount)
-            .await
-            .expect("stake_with_jup");
+    fn parameters(&self) -> Value {
+        StakeWithJupArgs::schema()
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Stake SOL tokens with Jupiter's liquid staking protocol to receive jupSOL".into(),
+            "Earn staking yield on SOL with Jupiter.".into(),
+            "Convert SOL to the jupSOL liquid staking token.".into(),
+        ]
+    }
 
-        Ok(StakeWithJupOutput { signature })
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
     }
 }
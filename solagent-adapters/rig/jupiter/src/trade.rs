@@ -1,29 +1,37 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{completion::ToolDefinition, tool::Tool},
-    SolanaAgentKit,
+    Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture,
+    TransactionOutcome,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_jupiter::trade;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct TradeArgs {
+    /// Mint of the token to buy.
+    #[parameters(address)]
     output_mint: String,
+    /// Amount of the input token to sell, in UI units (e.g. 1.5 SOL).
+    #[parameters(exclusive_minimum = 0, decimals = 9)]
     input_amount: f64,
+    /// Mint of the token to sell; SOL when left out.
+    #[parameters(address)]
     input_mint: Option<String>,
+    /// Slippage tolerance in basis points; 300 (3%) when left out.
+    #[parameters(maximum = 10_000)]
     slippage_bps: Option<u32>,
+    /// Label of the agent wallet to swap from; the default wallet when left out.
+    wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct TradeOutput {
-    pub signature: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("Trade error")]
-pub struct TradeError;
-
 pub struct Trade {
     agent: Arc<SolanaAgentKit>,
 }
@@ -32,19 +40,32 @@ impl Trade {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         Trade { agent }
     }
-}
 
-impl Tool for Trade {
-    const NAME: &'static str = "trade";
+    async fn run(&self, args: TradeArgs) -> Result<TradeOutput, SolagentError> {
+        let transaction = trade(
+            &self.agent,
+            &args.output_mint,
+            args.input_amount,
+            args.input_mint,
+            args.slippage_bps,
+            args.wallet.as_deref(),
+        )
+        .await?;
+
+        Ok(TradeOutput {
+            transaction,
+            network: self.agent.network.clone(),
+        })
+    }
+}
 
-    type Error = TradeError;
-    type Args = TradeArgs;
-    type Output = TradeOutput;
+impl SolagentTool for Trade {
+    fn name(&self) -> &str {
+        "trade"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "trade".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             This tool can be used to swap tokens to another token (It uses Jupiter Exchange).
 
             {
@@ -55,27 +76,29 @@ impl Tool for Trade {
             }
             
             "#
-            .to_string(),
-            parameters: parameters!(
-                output_mint: String,
-                input_amount: f64,
-                input_mint: Option<String>,
-                slippage_bps: Option<u32>,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let signature = trade(
-            &self.agent,
-            &args.output_mint,
-            args.input_amount,
-            args.input_mint,
-            args.slippage_bps,
-        )
-        .await
-        .expect("trade");
+    fn parameters(&self) -> Value {
+        TradeArgs::schema()
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
+    }
 
-        Ok(TradeOutput { signature })
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "This tool can be used to swap tokens to another token (It uses Jupiter Exchange)."
+                .into(),
+            "Buy or sell a token, e.g. swap SOL for USDC.".into(),
+            "Exchange tokens at the best Jupiter route with slippage control.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
     }
 }
@@ -0,0 +1,620 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `#[solagent_tool]`: a rig tool from a plugin function.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    meta::ParseNestedMeta, parse::Parser, Attribute, FnArg, GenericArgument, Ident, ItemFn, LitStr,
+    Pat, PathArguments, ReturnType, Type,
+};
+
+/// Turns a plugin function into a `SolagentTool`.
+///
+/// ```ignore
+/// /// Transfer SOL or SPL tokens to a recipient.
+/// ///
+/// /// - `to`: Recipient address, or the label of one of the agent's wallets.
+/// /// - `amount`: Lamports for SOL, whole tokens when `mint` is set.
+/// #[solagent_tool(name = "transfer", description = "Transfer SOL or SPL tokens", category = "trading")]
+/// pub async fn transfer(
+///     agent: &SolanaAgentKit,
+///     to: &str,
+///     amount: u64,
+///     mint: Option<String>,
+///     wallet: Option<&str>,
+/// ) -> Result<TransactionOutcome, SolagentError> { ... }
+/// ```
+///
+/// Next to the function, this emits:
+/// - `TransferArgs`, one field per argument but the agent, with `&str` read as `String` and `&T`
+///   as `T`; its schema comes from `#[derive(ToolParameters)]` and the descriptions of the
+///   arguments from the ``- `name`: description`` lines of the doc comment;
+/// - `Transfer`, holding an `Arc<SolanaAgentKit>`, which implements `SolagentTool`, validated and
+///   journaled like every other tool; `RigTool` makes it a rig tool.
+///
+/// `#[parameters(...)]` on an argument moves to its field, e.g. `#[parameters(address)] to: &str`.
+///
+/// `category` is required: `"read_only"`, `"trading"` or `"deployment"`, see `ToolCategory`. `name`
+/// defaults to the name of the function and `description` to the first paragraph of its doc
+/// comment. `embedding_docs = ["...", ...]` sets the texts the tool is retrieved by, see
+/// `SolagentTool::embedding_docs`. The function returns `Result<T, E>` with `T: Serialize` and
+/// `E: Into<SolagentError>`, and may be async or not. The crate needs `serde`, `serde_json`,
+/// `solagent-core` and `solagent-parameters` as dependencies.
+#[proc_macro_attribute]
+pub fn solagent_tool(attr: TokenStream, item: TokenStream) -> TokenStream {
+    expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct Options {
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+    category: Option<Ident>,
+    embedding_docs: Option<Vec<LitStr>>,
+}
+
+impl Options {
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("name") {
+            self.name = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("description") {
+            self.description = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("category") {
+            let category: LitStr = meta.value()?.parse()?;
+            let variant = match category.value().as_str() {
+                "read_only" => "ReadOnly",
+                "trading" => "Trading",
+                "deployment" => "Deployment",
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        category,
+                        "expected \"read_only\", \"trading\" or \"deployment\"",
+                    ))
+                }
+            };
+            self.category = Some(Ident::new(variant, category.span()));
+        } else if meta.path.is_ident("embedding_docs") {
+            let docs: syn::ExprArray = meta.value()?.parse()?;
+            let docs = docs
+                .elems
+                .into_iter()
+                .map(|doc| match doc {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }) => Ok(doc),
+                    doc => Err(syn::Error::new_spanned(doc, "expected a string")),
+                })
+                .collect::<syn::Result<_>>()?;
+            self.embedding_docs = Some(docs);
+        } else {
+            return Err(
+                meta.error("expected `name`, `description`, `category` or `embedding_docs`")
+            );
+        }
+        Ok(())
+    }
+}
+
+/// An argument of the plugin function, and how the tool passes it from its args.
+struct Argument {
+    ident: Ident,
+    ty: Type,
+    call: TokenStream2,
+}
+
+fn expand(attr: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let mut options = Options::default();
+    syn::meta::parser(|meta| options.parse(meta)).parse2(attr)?;
+    let mut function: ItemFn = syn::parse2(item)?;
+    // `#[parameters(...)]` on an argument constrains its field, and is not valid on the function.
+    let constraints: Vec<Vec<Attribute>> = function
+        .sig
+        .inputs
+        .iter_mut()
+        .map(|input| match input {
+            FnArg::Typed(input) => {
+                let (constraints, attrs) = input
+                    .attrs
+                    .drain(..)
+                    .partition(|attr| attr.path().is_ident("parameters"));
+                input.attrs = attrs;
+                constraints
+            }
+            FnArg::Receiver(_) => Vec::new(),
+        })
+        .collect();
+    let signature = &function.sig;
+    if !signature.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &signature.generics,
+            "tool functions cannot be generic",
+        ));
+    }
+
+    let fn_ident = &signature.ident;
+    let docs = docs(&function.attrs);
+    let name = options
+        .name
+        .unwrap_or_else(|| LitStr::new(&fn_ident.to_string(), fn_ident.span()));
+    let description = match options.description {
+        Some(description) => description.value(),
+        None => summary(&docs).ok_or_else(|| {
+            syn::Error::new_spanned(fn_ident, "add a doc comment or a `description`")
+        })?,
+    };
+    let category = options.category.ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            "add a `category`: \"read_only\", \"trading\" or \"deployment\"",
+        )
+    })?;
+    let embedding_docs = options.embedding_docs.map(|docs| {
+        quote! {
+            fn embedding_docs(&self) -> ::std::vec::Vec<::std::string::String> {
+                ::std::vec![#(::std::string::String::from(#docs)),*]
+            }
+        }
+    });
+    let tool = format_ident!("{}", pascal_case(&fn_ident.to_string()));
+    let args = format_ident!("{}Args", tool);
+    returns_result(&signature.output)?;
+
+    let mut call = Vec::new();
+    let mut fields = Vec::new();
+    for (input, constraints) in signature.inputs.iter().zip(&constraints) {
+        let FnArg::Typed(input) = input else {
+            return Err(syn::Error::new_spanned(
+                input,
+                "tool functions cannot take `self`",
+            ));
+        };
+        if is_agent(&input.ty) {
+            call.push(quote!(&self.agent));
+            continue;
+        }
+        let Pat::Ident(pat) = &*input.pat else {
+            return Err(syn::Error::new_spanned(
+                &input.pat,
+                "expected a named argument",
+            ));
+        };
+        let argument = argument(pat.ident.clone(), &input.ty);
+        let Argument { ident, ty, .. } = &argument;
+        let doc = argument_doc(&docs, &ident.to_string()).map(|doc| quote!(#[doc = #doc]));
+        fields.push(quote! {
+            #doc
+            #(#constraints)*
+            pub #ident: #ty,
+        });
+        call.push(argument.call);
+    }
+    let awaited = signature.asyncness.map(|_| quote!(.await));
+    let args_doc = format!("Arguments of the `{}` tool.", name.value());
+    let tool_doc = format!("The `{}` tool, calling [`{}`].", name.value(), fn_ident);
+
+    Ok(quote! {
+        #function
+
+        #[doc = #args_doc]
+        #[derive(::serde::Deserialize, ::serde::Serialize, ::solagent_parameters::ToolParameters)]
+        pub struct #args {
+            #(#fields)*
+        }
+
+        #[doc = #tool_doc]
+        pub struct #tool {
+            agent: ::std::sync::Arc<::solagent_core::SolanaAgentKit>,
+        }
+
+        impl #tool {
+            pub fn new(agent: ::std::sync::Arc<::solagent_core::SolanaAgentKit>) -> Self {
+                #tool { agent }
+            }
+        }
+
+        impl ::solagent_core::SolagentTool for #tool {
+            fn name(&self) -> &str {
+                #name
+            }
+
+            fn description(&self) -> &str {
+                #description
+            }
+
+            fn parameters(&self) -> ::serde_json::Value {
+                <#args as ::solagent_parameters::ToolParameters>::schema()
+            }
+
+            fn category(&self) -> ::solagent_core::ToolCategory {
+                ::solagent_core::ToolCategory::#category
+            }
+
+            #embedding_docs
+
+            fn invoke(&self, args: ::serde_json::Value) -> ::solagent_core::ToolFuture<'_> {
+                ::std::boxed::Box::pin(self.agent.invoke_tool(#name, args, move |args: #args| async move {
+                    #fn_ident(#(#call),*)#awaited.map_err(::std::convert::Into::into)
+                }))
+            }
+        }
+    })
+}
+
+/// The field an argument of type `ty` is read into, and how it is passed back to the function.
+fn argument(ident: Ident, ty: &Type) -> Argument {
+    if let Type::Reference(reference) = ty {
+        let ty = owned(&reference.elem);
+        return Argument {
+            call: quote!(&args.#ident),
+            ident,
+            ty,
+        };
+    }
+    if let Some(Type::Reference(reference)) = option_inner(ty) {
+        let inner = owned(&reference.elem);
+        let call = match inner_is_str(&reference.elem) {
+            true => quote!(args.#ident.as_deref()),
+            false => quote!(args.#ident.as_ref()),
+        };
+        return Argument {
+            call,
+            ident,
+            ty: syn::parse_quote!(Option<#inner>),
+        };
+    }
+    Argument {
+        call: quote!(args.#ident),
+        ident,
+        ty: ty.clone(),
+    }
+}
+
+/// The owned type a borrowed argument is deserialized into: `String` for `str`, `Vec<T>` for `[T]`.
+fn owned(ty: &Type) -> Type {
+    match ty {
+        Type::Slice(slice) => {
+            let elem = &slice.elem;
+            syn::parse_quote!(Vec<#elem>)
+        }
+        ty if inner_is_str(ty) => syn::parse_quote!(String),
+        ty => ty.clone(),
+    }
+}
+
+fn inner_is_str(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("str"))
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_arguments(ty, "Option")?.first().copied()
+}
+
+/// The type arguments of `ty` when it is a `name<...>`, e.g. `Option<T>`.
+fn generic_arguments<'a>(ty: &'a Type, name: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    (segment.ident == name).then(|| {
+        arguments
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect()
+    })
+}
+
+/// Whether an argument is the agent, `&SolanaAgentKit`.
+fn is_agent(ty: &Type) -> bool {
+    let Type::Reference(reference) = ty else {
+        return false;
+    };
+    let Type::Path(path) = &*reference.elem else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "SolanaAgentKit")
+}
+
+/// `T` of a function returning `Result<T, E>`.
+fn returns_result(output: &ReturnType) -> syn::Result<()> {
+    let error = || {
+        syn::Error::new(
+            Span::call_site(),
+            "tool functions must return `Result<T, E>`",
+        )
+    };
+    let ReturnType::Type(_, ty) = output else {
+        return Err(error());
+    };
+    generic_arguments(ty, "Result")
+        .map(|_| ())
+        .ok_or_else(error)
+}
+
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The first paragraph of a doc comment.
+fn summary(docs: &[String]) -> Option<String> {
+    let summary = docs
+        .iter()
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!summary.is_empty()).then_some(summary)
+}
+
+/// The description of argument `name` in a doc comment, written as ``- `name`: description``,
+/// ``* `name` - description`` or ``` `name` - description ```.
+fn argument_doc(docs: &[String], name: &str) -> Option<String> {
+    let quoted = format!("`{}`", name);
+    docs.iter().find_map(|line| {
+        let line = line.trim_start_matches(['-', '*']).trim_start();
+        let rest = line.strip_prefix(&quoted)?.trim_start();
+        let rest = rest.strip_prefix([':', '-'])?.trim();
+        (!rest.is_empty()).then(|| rest.to_string())
+    })
+}
+
+fn pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_items(attr: TokenStream2, item: TokenStream2) -> Vec<syn::Item> {
+        let file: syn::File = syn::parse2(expand(attr, item).unwrap()).unwrap();
+        file.items
+    }
+
+    fn item_struct<'a>(items: &'a [syn::Item], name: &str) -> &'a syn::ItemStruct {
+        items
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Struct(item) if item.ident == name => Some(item),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    fn transfer() -> TokenStream2 {
+        quote! {
+            /// Transfer SOL or SPL tokens to a recipient
+            ///
+            /// - `to`: Recipient's public key
+            /// * `amount` - Amount to transfer
+            /// `wallet` - Label of the kit wallet to send from
+            pub async fn transfer(
+                agent: &SolanaAgentKit,
+                #[parameters(address)] to: &str,
+                amount: u64,
+                mint: Option<String>,
+                signers: &[String],
+                wallet: Option<&str>,
+            ) -> Result<TransactionOutcome, SolagentError> {
+                todo!()
+            }
+        }
+    }
+
+    #[test]
+    fn test_args() {
+        let items = expand_items(
+            quote!(name = "transfer_tokens", category = "trading"),
+            transfer(),
+        );
+        let args = item_struct(&items, "TransferArgs");
+        let fields: Vec<String> = args
+            .fields
+            .iter()
+            .map(|field| {
+                let (ident, ty) = (&field.ident, &field.ty);
+                quote!(#ident: #ty).to_string()
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                "to : String",
+                "amount : u64",
+                "mint : Option < String >",
+                "signers : Vec < String >",
+                "wallet : Option < String >"
+            ]
+        );
+
+        let field_docs: Vec<Vec<String>> =
+            args.fields.iter().map(|field| docs(&field.attrs)).collect();
+        assert_eq!(field_docs[0], ["Recipient's public key"]);
+        assert_eq!(field_docs[1], ["Amount to transfer"]);
+        assert!(field_docs[3].is_empty());
+        assert_eq!(field_docs[4], ["Label of the kit wallet to send from"]);
+
+        let constraint = &args.fields.iter().next().unwrap().attrs[1];
+        assert_eq!(quote!(#constraint).to_string(), "# [parameters (address)]");
+        let function = items
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Fn(function) => Some(function),
+                _ => None,
+            })
+            .unwrap();
+        let FnArg::Typed(to) = &function.sig.inputs[1] else {
+            panic!("expected `to`")
+        };
+        assert!(to.attrs.is_empty());
+    }
+
+    #[test]
+    fn test_tool() {
+        let items = expand_items(
+            quote!(name = "transfer_tokens", category = "trading"),
+            transfer(),
+        );
+        item_struct(&items, "Transfer");
+        let code = quote!(#(#items)*).to_string();
+        assert!(code.contains("fn name (& self) -> & str { \"transfer_tokens\" }"));
+        assert!(code.contains(
+            "fn description (& self) -> & str { \"Transfer SOL or SPL tokens to a recipient\" }"
+        ));
+        assert!(code.contains(
+            "fn category (& self) -> :: solagent_core :: ToolCategory { \
+             :: solagent_core :: ToolCategory :: Trading }"
+        ));
+        assert!(
+            code.contains(". invoke_tool (\"transfer_tokens\" , args , move | args : TransferArgs |")
+        );
+        assert!(code.contains(
+            "transfer (& self . agent , & args . to , args . amount , args . mint , \
+             & args . signers , args . wallet . as_deref ()) . await"
+        ));
+    }
+
+    #[test]
+    fn test_embedding_docs() {
+        let attr = quote!(
+            category = "trading",
+            embedding_docs = ["Send SOL", "Pay a friend"]
+        );
+        let items = expand_items(attr, transfer());
+        let code = quote!(#(#items)*).to_string();
+        assert!(code.contains("fn embedding_docs (& self)"));
+        assert!(code.contains(
+            ":: std :: string :: String :: from (\"Send SOL\") , \
+             :: std :: string :: String :: from (\"Pay a friend\")"
+        ));
+
+        // The default of `SolagentTool` otherwise
+        let items = expand_items(quote!(category = "trading"), transfer());
+        assert!(!quote!(#(#items)*).to_string().contains("embedding_docs"));
+
+        let error = expand(
+            quote!(category = "trading", embedding_docs = [1]),
+            transfer(),
+        );
+        assert!(error.unwrap_err().to_string().contains("expected a string"));
+    }
+
+    #[test]
+    fn test_sync_function() {
+        let item = quote! {
+            pub fn verify(message: &str, signer: Option<&Pubkey>) -> Result<bool, SolagentError> {
+                todo!()
+            }
+        };
+        let items = expand_items(
+            quote!(description = "Verify a message", category = "read_only"),
+            item,
+        );
+        let code = quote!(#(#items)*).to_string();
+        assert!(code.contains("fn name (& self) -> & str { \"verify\" }"));
+        assert!(code.contains("verify (& args . message , args . signer . as_ref ()) . map_err"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |attr, item| expand(attr, item).unwrap_err().to_string();
+        assert!(error(
+            quote!(),
+            quote!(
+                fn no_docs() -> Result<(), E> {}
+            )
+        )
+        .contains("doc comment"));
+        assert!(error(
+            quote!(description = "x"),
+            quote!(
+                fn uncategorized() -> Result<(), E> {}
+            )
+        )
+        .contains("category"));
+        assert!(error(
+            quote!(description = "x", category = "admin"),
+            quote!(
+                fn f() -> Result<(), E> {}
+            )
+        )
+        .contains("expected"));
+        assert!(error(
+            quote!(description = "x", category = "read_only"),
+            quote!(
+                fn infallible() -> u64 {
+                    1
+                }
+            )
+        )
+        .contains("must return"));
+        assert!(error(
+            quote!(title = "x"),
+            quote!(
+                fn f() -> Result<(), E> {}
+            )
+        )
+        .contains("expected"));
+        assert!(error(
+            quote!(description = "x", category = "read_only"),
+            quote!(
+                fn g<T>(t: T) -> Result<(), E> {}
+            )
+        )
+        .contains("generic"));
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(pascal_case("get_balance_other"), "GetBalanceOther");
+        assert_eq!(pascal_case("trade"), "Trade");
+    }
+}
@@ -1,23 +1,18 @@
-
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::Tool,
-    },
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_pumpfun::{launch_token_pumpfun, PumpFunTokenOptions, PumpfunTokenResponse};
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct LaunchPumpfunTokenArgs {
     token_name: String,
     token_symbol: String,
     description: String,
     image_url: String,
     options: Option<PumpFunTokenOptions>,
+    wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -25,10 +20,6 @@ pub struct LaunchPumpfunTokenOutput {
     pub res: PumpfunTokenResponse,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("LaunchPumpfunToken error")]
-pub struct LaunchPumpfunTokenError;
-
 pub struct LaunchPumpfunToken {
     agent: Arc<SolanaAgentKit>,
 }
@@ -37,19 +28,30 @@ impl LaunchPumpfunToken {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         LaunchPumpfunToken { agent }
     }
-}
 
-impl Tool for LaunchPumpfunToken {
-    const NAME: &'static str = "launch_token_pumpfun";
+    async fn run(&self, args: LaunchPumpfunTokenArgs) -> Result<LaunchPumpfunTokenOutput, SolagentError> {
+        let res = launch_token_pumpfun(
+            &self.agent,
+            &args.token_name,
+            &args.token_symbol,
+            &args.description,
+            &args.image_url,
+            args.options,
+            args.wallet.as_deref(),
+        )
+        .await?;
+
+        Ok(LaunchPumpfunTokenOutput { res })
+    }
+}
 
-    type Error = LaunchPumpfunTokenError;
-    type Args = LaunchPumpfunTokenArgs;
-    type Output = LaunchPumpfunTokenOutput;
+impl SolagentTool for LaunchPumpfunToken {
+    fn name(&self) -> &str {
+        "launch_token_pumpfun"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "launch_token_pumpfun".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Launch a new token on Pump.fun with customizable metadata and initial liquidity.
             do not use this tool for any other purpose, or for creating SPL tokens.
             If the user asks you to chose the parameters, you should generate valid values.
@@ -85,29 +87,30 @@ impl Tool for LaunchPumpfunToken {
                 ],
             ]
             "#
-            .to_string(),
-            parameters: parameters!(
-                token_name: String,
-                token_symbol: String,
-                description: String,
-                image_url: String,
-                options: Option<PumpFunTokenOptions>,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let res = launch_token_pumpfun(
-            &self.agent,
-            &args.token_name,
-            &args.token_symbol,
-            &args.description,
-            &args.image_url,
-            args.options,
-        )
-        .await
-        .expect("launch_token_pumpfun");
+    fn parameters(&self) -> Value {
+        LaunchPumpfunTokenArgs::schema()
+    }
 
-        Ok(LaunchPumpfunTokenOutput { res })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Deployment
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Launch a new token on Pump.fun with customizable metadata and initial liquidity.".into(),
+            "Create a memecoin on pump.fun with a name, ticker and image.".into(),
+            "Start a Pump.fun bonding curve and buy the first tokens.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
-}
\ No newline at end of file
+}
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![Arc::new(LaunchPumpfunToken::new(agent.clone()))]
+}
@@ -1,12 +1,11 @@
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{
-    completion::ToolDefinition,
-    tool::Tool,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_pyth::{fetch_price_by_pyth, fetch_pyth_price_feed_id};
+use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
 pub struct FetchPricePyThArgs {
     token_symbol: String,
 }
@@ -16,29 +15,30 @@ pub struct FetchPricePyThOutput {
     pub price: f64,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("FetchPricePyTh error")]
-pub struct FetchPricePyThError;
+pub struct FetchPricePyTh {
+    agent: Arc<SolanaAgentKit>,
+}
 
-#[derive(Default)]
-pub struct FetchPricePyTh;
 impl FetchPricePyTh {
-    pub fn new() -> Self {
-        FetchPricePyTh {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        FetchPricePyTh { agent }
     }
-}
 
-impl Tool for FetchPricePyTh {
-    const NAME: &'static str = "fetch_price_by_pyth";
+    async fn run(&self, args: FetchPricePyThArgs) -> Result<FetchPricePyThOutput, SolagentError> {
+        let price_feed_id = fetch_pyth_price_feed_id(&self.agent, &args.token_symbol).await?;
+        let price = fetch_price_by_pyth(&self.agent, &price_feed_id).await?;
+
+        Ok(FetchPricePyThOutput { price })
+    }
+}
 
-    type Error = FetchPricePyThError;
-    type Args = FetchPricePyThArgs;
-    type Output = FetchPricePyThOutput;
+impl SolagentTool for FetchPricePyTh {
+    fn name(&self) -> &str {
+        "fetch_price_by_pyth"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "fetch_price_by_pyth".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Fetch the current price from a Pyth oracle price feed.
             
@@ -59,17 +59,30 @@ impl Tool for FetchPricePyTh {
             ],
 
             "#
-            .to_string(),
-            parameters: parameters!(
-                token_symbol: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let price_feed_id = fetch_pyth_price_feed_id(&args.token_symbol).await.expect("fetch_pyth_price_feed_id");
-        let price = fetch_price_by_pyth(&price_feed_id).await.expect("fetch_price_by_pyth");
+    fn parameters(&self) -> Value {
+        FetchPricePyThArgs::schema()
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
 
-        Ok(FetchPricePyThOutput { price })
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Fetch the current price from a Pyth oracle price feed.".into(),
+            "What is the SOL/USD price according to the Pyth oracle?".into(),
+            "Read an oracle price for a token symbol.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
     }
-}
\ No newline at end of file
+}
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![Arc::new(FetchPricePyTh::new(agent.clone()))]
+}
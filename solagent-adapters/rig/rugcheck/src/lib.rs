@@ -14,3 +14,18 @@
 
 pub mod token_report_detailed;
 pub mod token_report_summary;
+
+use solagent_core::{SolagentTool, SolanaAgentKit};
+use std::sync::Arc;
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![
+        Arc::new(token_report_detailed::FetchTokenReportDetailed::new(
+            agent.clone(),
+        )),
+        Arc::new(token_report_summary::FetchTokenReportSummary::new(
+            agent.clone(),
+        )),
+    ]
+}
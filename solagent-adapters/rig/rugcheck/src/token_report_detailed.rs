@@ -13,15 +13,15 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{
-    completion::ToolDefinition,
-    tool::{Tool, ToolEmbedding},
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_rugcheck::fetch_detailed_report;
+use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct FetchTokenReportDetailedArgs {
+    #[parameters(address)]
     mint: String,
 }
 
@@ -30,64 +30,57 @@ pub struct FetchTokenReportDetailedOutput {
     pub token_check: serde_json::Value,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("FetchTokenReportDetailed error")]
-pub struct FetchTokenReportDetailedError;
-
-#[derive(Default)]
-pub struct FetchTokenReportDetailed {}
+pub struct FetchTokenReportDetailed {
+    agent: Arc<SolanaAgentKit>,
+}
 
 impl FetchTokenReportDetailed {
-    pub fn new() -> Self {
-        FetchTokenReportDetailed {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        FetchTokenReportDetailed { agent }
     }
-}
 
-impl Tool for FetchTokenReportDetailed {
-    const NAME: &'static str = "fetch_detailed_report";
+    async fn run(
+        &self,
+        args: FetchTokenReportDetailedArgs,
+    ) -> Result<FetchTokenReportDetailedOutput, SolagentError> {
+        let token_check = fetch_detailed_report(&self.agent, args.mint).await?;
+        Ok(FetchTokenReportDetailedOutput { token_check })
+    }
+}
 
-    type Error = FetchTokenReportDetailedError;
-    type Args = FetchTokenReportDetailedArgs;
-    type Output = FetchTokenReportDetailedOutput;
+impl SolagentTool for FetchTokenReportDetailed {
+    fn name(&self) -> &str {
+        "fetch_detailed_report"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "fetch_detailed_report".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
                 Fetches a detailed report for a specific token from RugCheck.
                 Inputs:
                 - mint: string, the mint address of the token, e.g., "84VUXykQjNvPDm88oT5FRucXeNcrwdQGottJKjkAoqd1" (required).
-            "#.to_string(),
-            parameters: parameters!(
-                mint: String,
-            ),
-        }
+            "#
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let token_check = fetch_detailed_report(args.mint)
-            .await
-            .expect("fetch_detailed_report");
-        Ok(FetchTokenReportDetailedOutput { token_check })
+    fn parameters(&self) -> Value {
+        FetchTokenReportDetailedArgs::schema()
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for FetchTokenReportDetailed {
-    type InitError = InitError;
-    type Context = ();
-    type State = ();
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(FetchTokenReportDetailed {})
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Fetches a summary report for a specific token from FetchTokenReportDetailed.".into()]
+        vec![
+            "Fetches a detailed report for a specific token from RugCheck.".into(),
+            "Inspect the holders, markets and risks of a token in depth.".into(),
+            "Is this token a rug pull? Show the full RugCheck analysis.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
@@ -13,15 +13,15 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::rig::{
-    completion::ToolDefinition,
-    tool::{Tool, ToolEmbedding},
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_rugcheck::{fetch_summary_report, TokenCheck};
+use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct FetchTokenReportSummaryArgs {
+    #[parameters(address)]
     mint: String,
 }
 
@@ -30,65 +30,58 @@ pub struct FetchTokenReportSummaryOutput {
     pub token_check: TokenCheck,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("FetchTokenReportSummary error")]
-pub struct FetchTokenReportSummaryError;
-
-#[derive(Default)]
-pub struct FetchTokenReportSummary {}
+pub struct FetchTokenReportSummary {
+    agent: Arc<SolanaAgentKit>,
+}
 
 impl FetchTokenReportSummary {
-    pub fn new() -> Self {
-        FetchTokenReportSummary {}
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        FetchTokenReportSummary { agent }
     }
-}
 
-impl Tool for FetchTokenReportSummary {
-    const NAME: &'static str = "fetch_summary_report";
+    async fn run(
+        &self,
+        args: FetchTokenReportSummaryArgs,
+    ) -> Result<FetchTokenReportSummaryOutput, SolagentError> {
+        let token_check = fetch_summary_report(&self.agent, args.mint).await?;
+        Ok(FetchTokenReportSummaryOutput { token_check })
+    }
+}
 
-    type Error = FetchTokenReportSummaryError;
-    type Args = FetchTokenReportSummaryArgs;
-    type Output = FetchTokenReportSummaryOutput;
+impl SolagentTool for FetchTokenReportSummary {
+    fn name(&self) -> &str {
+        "fetch_summary_report"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "fetch_summary_report".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
                 Fetches a summary report for a specific token from RugCheck.
                 
                 Inputs:
                     - mint: string, the mint address of the token, e.g., "84VUXykQjNvPDm88oT5FRucXeNcrwdQGottJKjkAoqd1" (required).
-                "#.to_string(),
-            parameters: parameters!(
-                mint: String,
-            ),
-        }
+                "#
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let token_check = fetch_summary_report(args.mint)
-            .await
-            .expect("fetch_summary_report");
-        Ok(FetchTokenReportSummaryOutput { token_check })
+    fn parameters(&self) -> Value {
+        FetchTokenReportSummaryArgs::schema()
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for FetchTokenReportSummary {
-    type InitError = InitError;
-    type Context = ();
-    type State = ();
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(FetchTokenReportSummary {})
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Fetches a summary report for a specific token from RugCheck.".into()]
+        vec![
+            "Fetches a summary report for a specific token from RugCheck.".into(),
+            "Give the RugCheck risk score of a token.".into(),
+            "Is this token safe to buy? Quick rug pull check.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
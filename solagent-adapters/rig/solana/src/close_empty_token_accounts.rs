@@ -13,28 +13,22 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
-};
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::{close_empty_token_accounts, CloseEmptyTokenAccountsData};
 use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
-pub struct CloseEmptyTokenAccountsArgs {}
+#[derive(Debug, Deserialize, Serialize, ToolParameters)]
+pub struct CloseEmptyTokenAccountsArgs {
+    wallet: Option<String>,
+}
 
 #[derive(Deserialize, Serialize)]
 pub struct CloseEmptyTokenAccountsOutput {
     pub data: CloseEmptyTokenAccountsData,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("CloseEmptyTokenAccounts error")]
-pub struct CloseEmptyTokenAccountsError;
-
 pub struct CloseEmptyTokenAccounts {
     agent: Arc<SolanaAgentKit>,
 }
@@ -43,19 +37,24 @@ impl CloseEmptyTokenAccounts {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         CloseEmptyTokenAccounts { agent }
     }
-}
 
-impl Tool for CloseEmptyTokenAccounts {
-    const NAME: &'static str = "close_empty_token_accounts";
+    async fn run(
+        &self,
+        args: CloseEmptyTokenAccountsArgs,
+    ) -> Result<CloseEmptyTokenAccountsOutput, SolagentError> {
+        let data = close_empty_token_accounts(&self.agent, args.wallet.as_deref()).await?;
+
+        Ok(CloseEmptyTokenAccountsOutput { data })
+    }
+}
 
-    type Error = CloseEmptyTokenAccountsError;
-    type Args = CloseEmptyTokenAccountsArgs;
-    type Output = CloseEmptyTokenAccountsOutput;
+impl SolagentTool for CloseEmptyTokenAccounts {
+    fn name(&self) -> &str {
+        "close_empty_token_accounts"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "close_empty_token_accounts".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Close empty SPL Token accounts associated with your wallet to reclaim rent. 
             This action will close both regular SPL Token accounts and Token-2022 accounts that have zero balance. 
 
@@ -85,36 +84,29 @@ impl Tool for CloseEmptyTokenAccounts {
                 ],
             ]
 
-"#.to_string(),
-            parameters: serde_json::Value::Null,
-        }
+"#
     }
 
-    async fn call(&self, _args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let data = close_empty_token_accounts(&self.agent)
-            .await
-            .expect("close_empty_token_accounts");
-
-        Ok(CloseEmptyTokenAccountsOutput { data })
+    fn parameters(&self) -> Value {
+        CloseEmptyTokenAccountsArgs::schema()
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for CloseEmptyTokenAccounts {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(CloseEmptyTokenAccounts { agent: state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Close empty SPL Token accounts associated with your wallet to reclaim rent.".into()]
+        vec![
+            "Close empty SPL Token accounts associated with your wallet to reclaim rent.".into(),
+            "Clean up token accounts with a zero balance, Token-2022 included.".into(),
+            "Recover the SOL locked as rent in unused token accounts.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
@@ -13,32 +13,28 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
+    Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture,
+    TransactionOutcome,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::{deploy_collection, NFTMetadata};
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct DeployCollectionArgs {
     metadata: NFTMetadata,
+    wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct DeployCollectionOutput {
     pub mint_address: String,
-    pub tx_signature: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("DeployCollection error")]
-pub struct DeployCollectionError;
-
 pub struct DeployCollection {
     agent: Arc<SolanaAgentKit>,
 }
@@ -47,19 +43,28 @@ impl DeployCollection {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         DeployCollection { agent }
     }
-}
 
-impl Tool for DeployCollection {
-    const NAME: &'static str = "deploy_collection";
+    async fn run(
+        &self,
+        args: DeployCollectionArgs,
+    ) -> Result<DeployCollectionOutput, SolagentError> {
+        let res = deploy_collection(&self.agent, &args.metadata, args.wallet.as_deref()).await?;
 
-    type Error = DeployCollectionError;
-    type Args = DeployCollectionArgs;
-    type Output = DeployCollectionOutput;
+        Ok(DeployCollectionOutput {
+            mint_address: res.mint,
+            transaction: res.transaction,
+            network: res.network,
+        })
+    }
+}
+
+impl SolagentTool for DeployCollection {
+    fn name(&self) -> &str {
+        "deploy_collection"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "deploy_collection".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Deploy a new NFT collection on Solana blockchain.:
             examples: [
                 [
@@ -100,41 +105,28 @@ impl Tool for DeployCollection {
             ],
 
             "#
-            .to_string(),
-            parameters: parameters!(
-                metadata: NFTMetadata
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let res = deploy_collection(&self.agent, &args.metadata)
-            .await
-            .expect("deploy_collection");
-
-        Ok(DeployCollectionOutput {
-            mint_address: res.mint,
-            tx_signature: res.signature,
-        })
+    fn parameters(&self) -> Value {
+        DeployCollectionArgs::schema()
     }
-}
-
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
 
-impl ToolEmbedding for DeployCollection {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(DeployCollection { agent: state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Deployment
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Deploy a new NFT collection on Solana blockchain.".into()]
+        vec![
+            "Deploy a new NFT collection on Solana blockchain.".into(),
+            "Create a Metaplex collection with a name, URI and royalties.".into(),
+            "Start an NFT collection that NFTs can be minted into.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
@@ -13,35 +13,32 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
+    Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture,
+    TransactionOutcome,
 };
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::deploy_token;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct DeployTokenArgs {
     pub name: String,
     pub uri: String,
     pub symbol: String,
     pub decimals: u8,
     pub initial_supply: Option<u64>,
+    pub wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct DeployTokenOutput {
     pub mint_address: String,
-    pub tx_signature: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("DeployToken error")]
-pub struct DeployTokenError;
-
 pub struct DeployToken {
     agent: Arc<SolanaAgentKit>,
 }
@@ -50,19 +47,34 @@ impl DeployToken {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         DeployToken { agent }
     }
-}
 
-impl Tool for DeployToken {
-    const NAME: &'static str = "deploy_token";
+    async fn run(&self, args: DeployTokenArgs) -> Result<DeployTokenOutput, SolagentError> {
+        let res = deploy_token(
+            &self.agent,
+            args.name,
+            args.uri,
+            args.symbol,
+            args.decimals,
+            args.initial_supply,
+            args.wallet.as_deref(),
+        )
+        .await?;
+
+        Ok(DeployTokenOutput {
+            mint_address: res.mint,
+            transaction: res.transaction,
+            network: res.network,
+        })
+    }
+}
 
-    type Error = DeployTokenError;
-    type Args = DeployTokenArgs;
-    type Output = DeployTokenOutput;
+impl SolagentTool for DeployToken {
+    fn name(&self) -> &str {
+        "deploy_token"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "deploy_token".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Deploy a new SPL token on the Solana blockchain with specified parameters:
 
             examples: [
@@ -101,46 +113,28 @@ impl Tool for DeployToken {
             ],
             
             "#
-            .to_string(),
-            parameters: serde_json::Value::Null,
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let res = deploy_token(
-            &self.agent,
-            args.name,
-            args.uri,
-            args.symbol,
-            args.decimals,
-            args.initial_supply,
-        )
-        .await
-        .expect("deploy_token");
-
-        Ok(DeployTokenOutput {
-            mint_address: res.mint,
-            tx_signature: res.signature,
-        })
+    fn parameters(&self) -> Value {
+        DeployTokenArgs::schema()
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for DeployToken {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(DeployToken { agent: _state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Deployment
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Deploy a new SPL token on the Solana blockchain with specified parameters.".into()]
+        vec![
+            "Deploy a new SPL token on the Solana blockchain with specified parameters.".into(),
+            "Create a token mint with a name, symbol, decimals and metadata URI.".into(),
+            "Launch a fungible token and mint an initial supply to the agent.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
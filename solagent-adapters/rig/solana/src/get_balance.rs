@@ -13,20 +13,17 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
-};
-use solagent_parameters::parameters;
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::get_balance;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct GetBalanceArgs {
+    #[parameters(address)]
     token_address: Option<String>,
+    wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -34,10 +31,6 @@ pub struct GetBalanceOutput {
     pub balance: f64,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetBalance error")]
-pub struct GetBalanceError;
-
 pub struct GetBalance {
     agent: Arc<SolanaAgentKit>,
 }
@@ -46,19 +39,22 @@ impl GetBalance {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         GetBalance { agent }
     }
-}
 
-impl Tool for GetBalance {
-    const NAME: &'static str = "get_balance";
+    async fn run(&self, args: GetBalanceArgs) -> Result<GetBalanceOutput, SolagentError> {
+        let token_address = args.token_address;
+        let balance = get_balance(&self.agent, token_address, args.wallet.as_deref()).await?;
+
+        Ok(GetBalanceOutput { balance })
+    }
+}
 
-    type Error = GetBalanceError;
-    type Args = GetBalanceArgs;
-    type Output = GetBalanceOutput;
+impl SolagentTool for GetBalance {
+    fn name(&self) -> &str {
+        "get_balance"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_balance".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Get the balance of a Solana wallet or token account.
             If you want to get the balance of your wallet, you don't need to provide the tokenAddress.
             If no tokenAddress is provided, the balance will be in SOL.
@@ -90,39 +86,28 @@ impl Tool for GetBalance {
                 ],
             ]
             "#
-            .to_string(),
-            parameters: parameters!(
-                token_address: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let token_address = args.token_address;
-        let balance = get_balance(&self.agent, token_address)
-            .await
-            .expect("get_balance");
-
-        Ok(GetBalanceOutput { balance })
+    fn parameters(&self) -> Value {
+        GetBalanceArgs::schema()
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for GetBalance {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(GetBalance { agent: state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Get the balance of a Solana wallet or token account.".into()]
+        vec![
+            "Get the balance of a Solana wallet or token account.".into(),
+            "How much SOL do I have?".into(),
+            "Check the agent's balance of USDC or any SPL token.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
@@ -13,19 +13,16 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    solana_sdk::pubkey::Pubkey,
-    SolanaAgentKit,
+    solana_sdk::pubkey::Pubkey, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory,
+    ToolFuture,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::get_balance_other;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct GetBalanceOtherArgs {
     wallet_address: Pubkey,
     token_address: Option<Pubkey>,
@@ -36,10 +33,6 @@ pub struct GetBalanceOtherOutput {
     pub balance: f64,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetBalanceOther error")]
-pub struct GetBalanceOtherError;
-
 pub struct GetBalanceOther {
     agent: Arc<SolanaAgentKit>,
 }
@@ -48,19 +41,23 @@ impl GetBalanceOther {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         GetBalanceOther { agent }
     }
-}
 
-impl Tool for GetBalanceOther {
-    const NAME: &'static str = "get_balance_other";
+    async fn run(&self, args: GetBalanceOtherArgs) -> Result<GetBalanceOtherOutput, SolagentError> {
+        let wallet_address = args.wallet_address;
+        let token_address = args.token_address;
+        let balance = get_balance_other(&self.agent, wallet_address, token_address).await?;
 
-    type Error = GetBalanceOtherError;
-    type Args = GetBalanceOtherArgs;
-    type Output = GetBalanceOtherOutput;
+        Ok(GetBalanceOtherOutput { balance })
+    }
+}
+
+impl SolagentTool for GetBalanceOther {
+    fn name(&self) -> &str {
+        "get_balance_other"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_balance_other".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Get the balance of a Solana wallet or token account which is different from the agent's wallet.
             If no tokenAddress is provided, the SOL balance of the wallet will be returned.
@@ -70,44 +67,28 @@ impl Tool for GetBalanceOther {
                 tokenAddress: string, eg "SENDdRQtYMWaQrBroBrJ2Q53fgVuq95CV9UPGEvpCxa" (optional)  
             }
             "#
-            .to_string(),
-            parameters: parameters!(
-                wallet_address: String,
-                token_address: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let wallet_address = args.wallet_address;
-        let token_address = args.token_address;
-        let balance = get_balance_other(&self.agent, wallet_address, token_address)
-            .await
-            .expect("get_balance_other");
-
-        Ok(GetBalanceOtherOutput { balance })
+    fn parameters(&self) -> Value {
+        GetBalanceOtherArgs::schema()
     }
-}
-
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
 
-impl ToolEmbedding for GetBalanceOther {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(GetBalanceOther { agent: _state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
     }
 
     fn embedding_docs(&self) -> Vec<String> {
         vec![
             "Get the balance of a Solana wallet or token account which is different from the agent's wallet.".into(),
-            "If no tokenAddress is provided, the SOL balance of the wallet will be returned.".into(),
+            "How much SOL does this address hold?".into(),
+            "Check the token balance of another wallet.".into(),
         ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
@@ -13,17 +13,13 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
-};
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::get_tps;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct GetTpsArgs {}
 
 #[derive(Deserialize, Serialize)]
@@ -31,10 +27,6 @@ pub struct GetTpsOutput {
     pub tps: f64,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetTps error")]
-pub struct GetTpsError;
-
 pub struct GetTps {
     agent: Arc<SolanaAgentKit>,
 }
@@ -43,19 +35,21 @@ impl GetTps {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         GetTps { agent }
     }
-}
 
-impl Tool for GetTps {
-    const NAME: &'static str = "get_tps";
+    async fn run(&self) -> Result<GetTpsOutput, SolagentError> {
+        let tps = get_tps(&self.agent).await?;
 
-    type Error = GetTpsError;
-    type Args = GetTpsArgs;
-    type Output = GetTpsOutput;
+        Ok(GetTpsOutput { tps })
+    }
+}
+
+impl SolagentTool for GetTps {
+    fn name(&self) -> &str {
+        "get_tps"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_tps".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             
             Get the current transactions per second (TPS) of the Solana network
             
@@ -74,34 +68,28 @@ impl Tool for GetTps {
             ]
             
             "#
-            .to_string(),
-            parameters: serde_json::Value::Null,
-        }
     }
 
-    async fn call(&self, _args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let tps = get_tps(&self.agent).await.expect("get_tps");
-
-        Ok(GetTpsOutput { tps })
+    fn parameters(&self) -> Value {
+        GetTpsArgs::schema()
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for GetTps {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(GetTps { agent: _state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Get the current transactions per second (TPS) of the Solana network".into()]
+        vec![
+            "Get the current transactions per second (TPS) of the Solana network".into(),
+            "How busy or congested is the Solana network right now?".into(),
+            "Measure the throughput of the cluster from recent performance samples.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |_: GetTpsArgs| self.run()),
+        )
+    }
 }
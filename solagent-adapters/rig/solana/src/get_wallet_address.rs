@@ -13,92 +13,31 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
-};
-use solagent_plugin_solana::get_wallet_address;
-use std::sync::Arc;
-
-#[derive(Deserialize)]
-pub struct GetWalletAddressArgs {}
+use solagent_core::{SolagentError, SolanaAgentKit};
+use solagent_rig_macros::solagent_tool;
 
 #[derive(Deserialize, Serialize)]
 pub struct GetWalletAddressOutput {
     pub address: String,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("GetWalletAddress error")]
-pub struct GetWalletAddressError;
-
-pub struct GetWalletAddress {
-    agent: Arc<SolanaAgentKit>,
-}
-
-impl GetWalletAddress {
-    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
-        GetWalletAddress { agent }
-    }
-}
-
-impl Tool for GetWalletAddress {
-    const NAME: &'static str = "get_wallet_address";
-
-    type Error = GetWalletAddressError;
-    type Args = GetWalletAddressArgs;
-    type Output = GetWalletAddressOutput;
-
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "get_wallet_address".to_string(),
-            description: r#"
-            Get wallet address of the agent".to_string(),
-            
-            examples: [
-                [
-                    {
-                        input: {},
-                        output: {
-                            status: "success",
-                            address: "0x1234567890abcdef",
-                        },
-                        explanation: "The agent's wallet address is 0x1234567890abcdef",
-                    },
-                ],
-            ]
-            "#
-            .to_string(),
-            parameters: serde_json::Value::Null,
-        }
-    }
-
-    async fn call(&self, _args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let address = get_wallet_address(&self.agent);
-
-        Ok(GetWalletAddressOutput { address })
-    }
-}
-
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for GetWalletAddress {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(GetWalletAddress { agent: _state })
-    }
-
-    fn embedding_docs(&self) -> Vec<String> {
-        vec!["Get wallet address of the agent".into()]
-    }
-
-    fn context(&self) -> Self::Context {}
+/// Get wallet address of the agent
+///
+/// - `wallet`: Label of the kit wallet; the default wallet when omitted.
+#[solagent_tool(
+    name = "get_wallet_address",
+    category = "read_only",
+    embedding_docs = [
+        "Get wallet address of the agent",
+        "What is my public key or Solana address?",
+        "Show the address of one of the agent's labelled wallets.",
+    ]
+)]
+pub fn get_wallet_address(
+    agent: &SolanaAgentKit,
+    wallet: Option<&str>,
+) -> Result<GetWalletAddressOutput, SolagentError> {
+    let address = solagent_plugin_solana::get_wallet_address(agent, wallet)?;
+
+    Ok(GetWalletAddressOutput { address })
 }
@@ -21,4 +21,29 @@ pub mod get_tps;
 pub mod get_wallet_address;
 pub mod mint_nft;
 pub mod request_faucet_funds;
+pub mod sign_message;
 pub mod transfer;
+pub mod verify_message;
+
+use solagent_core::{SolagentTool, SolanaAgentKit};
+use std::sync::Arc;
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![
+        Arc::new(close_empty_token_accounts::CloseEmptyTokenAccounts::new(
+            agent.clone(),
+        )),
+        Arc::new(deploy_collection::DeployCollection::new(agent.clone())),
+        Arc::new(deploy_token::DeployToken::new(agent.clone())),
+        Arc::new(get_balance::GetBalance::new(agent.clone())),
+        Arc::new(get_balance_other::GetBalanceOther::new(agent.clone())),
+        Arc::new(get_tps::GetTps::new(agent.clone())),
+        Arc::new(get_wallet_address::GetWalletAddress::new(agent.clone())),
+        Arc::new(mint_nft::MintNFT::new(agent.clone())),
+        Arc::new(request_faucet_funds::RequestFaucetFunds::new(agent.clone())),
+        Arc::new(sign_message::SignMessage::new(agent.clone())),
+        Arc::new(transfer::Transfer::new(agent.clone())),
+        Arc::new(verify_message::VerifyMessage::new(agent.clone())),
+    ]
+}
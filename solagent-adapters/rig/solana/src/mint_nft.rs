@@ -13,34 +13,29 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    solana_sdk::pubkey::Pubkey,
-    SolanaAgentKit,
+    solana_sdk::pubkey::Pubkey, Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory,
+    ToolFuture, TransactionOutcome,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::{mint_nft_to_collection, NFTMetadata};
 use std::sync::Arc;
 
-#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(serde::Serialize, serde::Deserialize, ToolParameters)]
 pub struct MintNFTArgs {
     collection: Pubkey,
     metadata: NFTMetadata,
+    wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct MintNFTOutput {
     pub mint_address: String,
-    pub tx_signature: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("MintNFT error")]
-pub struct MintNFTError;
-
 pub struct MintNFT {
     agent: Arc<SolanaAgentKit>,
 }
@@ -49,19 +44,31 @@ impl MintNFT {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         MintNFT { agent }
     }
-}
 
-impl Tool for MintNFT {
-    const NAME: &'static str = "mint_nft";
+    async fn run(&self, args: MintNFTArgs) -> Result<MintNFTOutput, SolagentError> {
+        let res = mint_nft_to_collection(
+            &self.agent,
+            args.collection,
+            args.metadata,
+            args.wallet.as_deref(),
+        )
+        .await?;
 
-    type Error = MintNFTError;
-    type Args = MintNFTArgs;
-    type Output = MintNFTOutput;
+        Ok(MintNFTOutput {
+            mint_address: res.mint,
+            transaction: res.transaction,
+            network: res.network,
+        })
+    }
+}
+
+impl SolagentTool for MintNFT {
+    fn name(&self) -> &str {
+        "mint_nft"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "mint_nft".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Mint a new NFT in a collection on Solana blockchain.
 
             examples: [
@@ -119,42 +126,28 @@ impl Tool for MintNFT {
             ],
 
             "#
-            .to_string(),
-            parameters: parameters!(
-                collection: Pubkey,
-                metadata: NFTMetadata,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let res = mint_nft_to_collection(&self.agent, args.collection, args.metadata)
-            .await
-            .expect("mint_nft");
-
-        Ok(MintNFTOutput {
-            mint_address: res.mint,
-            tx_signature: res.signature,
-        })
+    fn parameters(&self) -> Value {
+        MintNFTArgs::schema()
     }
-}
-
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
 
-impl ToolEmbedding for MintNFT {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(MintNFT { agent: state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Deployment
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Mint a new NFT in a collection on Solana blockchain.".into()]
+        vec![
+            "Mint a new NFT in a collection on Solana blockchain.".into(),
+            "Create an NFT with a name, URI and royalties inside an existing collection.".into(),
+            "Mint a Metaplex NFT and send it to a recipient.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
@@ -13,28 +13,25 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
+    Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture,
 };
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::request_faucet_funds;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
-pub struct RequestFaucetFundsArgs;
+#[derive(Deserialize, Serialize, ToolParameters)]
+pub struct RequestFaucetFundsArgs {
+    wallet: Option<String>,
+}
 
 #[derive(Deserialize, Serialize)]
 pub struct RequestFaucetFundsOutput {
     pub tx: String,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("RequestFaucetFunds error")]
-pub struct RequestFaucetFundsError;
-
 pub struct RequestFaucetFunds {
     agent: Arc<SolanaAgentKit>,
 }
@@ -42,19 +39,27 @@ impl RequestFaucetFunds {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         RequestFaucetFunds { agent }
     }
-}
 
-impl Tool for RequestFaucetFunds {
-    const NAME: &'static str = "request_faucet_funds";
+    async fn run(
+        &self,
+        args: RequestFaucetFundsArgs,
+    ) -> Result<RequestFaucetFundsOutput, SolagentError> {
+        let tx = request_faucet_funds(&self.agent, args.wallet.as_deref()).await?;
+
+        Ok(RequestFaucetFundsOutput {
+            tx,
+            network: self.agent.network.clone(),
+        })
+    }
+}
 
-    type Error = RequestFaucetFundsError;
-    type Args = RequestFaucetFundsArgs;
-    type Output = RequestFaucetFundsOutput;
+impl SolagentTool for RequestFaucetFunds {
+    fn name(&self) -> &str {
+        "request_faucet_funds"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "request_faucet_funds".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Request SOL from Solana faucet (devnet/testnet only)
             
             examples: [
@@ -71,36 +76,28 @@ impl Tool for RequestFaucetFunds {
                 ],
             ],
             "#
-            .to_string(),
-            parameters: serde_json::Value::Null,
-        }
     }
 
-    async fn call(&self, _args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let tx = request_faucet_funds(&self.agent)
-            .await
-            .expect("request_faucet_funds");
-
-        Ok(RequestFaucetFundsOutput { tx })
+    fn parameters(&self) -> Value {
+        RequestFaucetFundsArgs::schema()
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
-
-impl ToolEmbedding for RequestFaucetFunds {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(RequestFaucetFunds { agent: _state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Request SOL from Solana faucet (devnet/testnet only)".into()]
+        vec![
+            "Request SOL from Solana faucet (devnet/testnet only)".into(),
+            "Airdrop test SOL to the agent's wallet.".into(),
+            "Get free devnet SOL to pay for transactions.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
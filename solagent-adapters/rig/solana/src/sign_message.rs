@@ -0,0 +1,104 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
+use solagent_plugin_solana::{sign_message, MessageFormat, SignedMessage};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize, ToolParameters)]
+pub struct SignMessageArgs {
+    /// The text to sign.
+    message: String,
+    format: Option<MessageFormat>,
+    /// Label of the agent wallet that signs; the default wallet when left out.
+    wallet: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SignMessageOutput {
+    pub signed: SignedMessage,
+}
+
+pub struct SignMessage {
+    agent: Arc<SolanaAgentKit>,
+}
+
+impl SignMessage {
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        SignMessage { agent }
+    }
+
+    async fn run(&self, args: SignMessageArgs) -> Result<SignMessageOutput, SolagentError> {
+        let format = args.format.unwrap_or_default();
+        let signed =
+            sign_message(&self.agent, &args.message, format, args.wallet.as_deref()).await?;
+
+        Ok(SignMessageOutput { signed })
+    }
+}
+
+impl SolagentTool for SignMessage {
+    fn name(&self) -> &str {
+        "sign_message"
+    }
+
+    fn description(&self) -> &str {
+        r#"
+            Sign a text message with the agent's wallet, e.g. to prove ownership of the address.
+            format is "offchain" (default), the Solana off-chain message format, or "raw" to sign the message bytes as they are.
+
+            examples: [
+                [
+                    {
+                        input: {
+                            message: "Sign in to example.com",
+                        },
+                        output: {
+                            status: "success",
+                            signature: "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW",
+                            signer: "8x2dR8Mpzuz2YqyZyZjUbYWKSWesBo5jMx2Q9Y86udVk",
+                        },
+                        explanation: "Sign a login message in the off-chain format",
+                    },
+                ],
+            ]
+            "#
+    }
+
+    fn parameters(&self) -> Value {
+        SignMessageArgs::schema()
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Sign a text message with the agent's wallet.".into(),
+            "Prove ownership of the agent's address by signing a message.".into(),
+            "Produce an off-chain or raw ed25519 signature.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
+}
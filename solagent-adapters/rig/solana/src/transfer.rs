@@ -13,33 +13,35 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::{Tool, ToolEmbedding},
-    },
-    SolanaAgentKit,
+    Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture,
+    TransactionOutcome,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solana::transfer;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct TransferArgs {
+    /// Recipient address, or the label of one of the agent's wallets.
     pub to: String,
+    /// Lamports for SOL, whole tokens when `mint` is set.
+    #[parameters(exclusive_minimum = 0)]
     pub amount: u64,
+    /// Mint of the SPL token to send; SOL when left out.
+    #[parameters(address)]
     pub mint: Option<String>,
+    /// Label of the agent wallet to send from; the default wallet when left out.
+    pub wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct TransferOutput {
-    pub tx: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("Transfer error")]
-pub struct TransferError;
-
 pub struct Transfer {
     agent: Arc<SolanaAgentKit>,
 }
@@ -48,38 +50,57 @@ impl Transfer {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         Transfer { agent }
     }
-}
 
-impl Tool for Transfer {
-    const NAME: &'static str = "transfer";
+    async fn run(&self, args: TransferArgs) -> Result<TransferOutput, SolagentError> {
+        let transaction = transfer(
+            &self.agent,
+            &args.to,
+            args.amount,
+            args.mint,
+            args.wallet.as_deref(),
+        )
+        .await?;
+
+        Ok(TransferOutput {
+            transaction,
+            network: self.agent.network.clone(),
+        })
+    }
+}
 
-    type Error = TransferError;
-    type Args = TransferArgs;
-    type Output = TransferOutput;
+impl SolagentTool for Transfer {
+    fn name(&self) -> &str {
+        "transfer"
+    }
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "transfer".to_string(),
-            description: r#"
+    fn description(&self) -> &str {
+        r#"
             Transfer tokens or SOL to another address (also called as wallet address).
+            `wallet` names the agent wallet to send from, and `to` may also be the name of one of the agent's wallets.
 
             examples: [
                 [
                     {
                         input: {
                             to: "8x2dR8Mpzuz2YqyZyZjUbYWKSWesBo5jMx2Q9Y86udVk",
-                            amount: 1,
+                            amount: 1000000000,
                         },
                         output: {
-                            status: "success",
-                            message: "Transfer completed successfully",
-                            amount: 1,
-                            recipient: "8x2dR8Mpzuz2YqyZyZjUbYWKSWesBo5jMx2Q9Y86udVk",
-                            token: "SOL",
-                            transaction:
-                                "5UfgJ5vVZxUxefDGqzqkVLHzHxVTyYH9StYyHKgvHYmXJgqJKxEqy9k4Rz9LpXrHF9kUZB7",
+                            transaction: {
+                                status: "confirmed",
+                                signature:
+                                    "5UfgJ5vVZxUxefDGqzqkVLHzHxVTyYH9StYyHKgvHYmXJgqJKxEqy9k4Rz9LpXrHF9kUZB7",
+                                slot: 312847561,
+                                fee: 5000,
+                                compute_units: 450,
+                                logs: [
+                                    "Program 11111111111111111111111111111111 invoke [1]",
+                                    "Program 11111111111111111111111111111111 success",
+                                ],
+                            },
+                            network: "mainnet",
                         },
-                        explanation: "Transfer 1 SOL to the recipient address",
+                        explanation: "Transfer 1 SOL (1000000000 lamports) to the recipient address",
                     },
                 ],
                 [
@@ -90,13 +111,19 @@ impl Tool for Transfer {
                             mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
                         },
                         output: {
-                            status: "success",
-                            message: "Transfer completed successfully",
-                            amount: 100,
-                            recipient: "8x2dR8Mpzuz2YqyZyZjUbYWKSWesBo5jMx2Q9Y86udVk",
-                            token: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
-                            transaction:
-                                "4VfgJ5vVZxUxefDGqzqkVLHzHxVTyYH9StYyHKgvHYmXJgqJKxEqy9k4Rz9LpXrHF9kUZB7",
+                            transaction: {
+                                status: "confirmed",
+                                signature:
+                                    "4VfgJ5vVZxUxefDGqzqkVLHzHxVTyYH9StYyHKgvHYmXJgqJKxEqy9k4Rz9LpXrHF9kUZB7",
+                                slot: 312847588,
+                                fee: 5000,
+                                compute_units: 6200,
+                                logs: [
+                                    "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]",
+                                    "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success",
+                                ],
+                            },
+                            network: "mainnet",
                         },
                         explanation: "Transfer 100 USDC tokens to the recipient address",
                     },
@@ -104,40 +131,28 @@ impl Tool for Transfer {
             ],
  
             "#
-            .to_string(),
-            parameters: parameters!(
-                to: String,
-                amount: f64,
-                mint: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let tx = transfer(&self.agent, &args.to, args.amount, args.mint)
-            .await
-            .expect("transfer");
-
-        Ok(TransferOutput { tx })
+    fn parameters(&self) -> Value {
+        TransferArgs::schema()
     }
-}
-
-#[derive(Debug, thiserror::Error)]
-#[error("Init error")]
-pub struct InitError;
 
-impl ToolEmbedding for Transfer {
-    type InitError = InitError;
-    type Context = ();
-    type State = Arc<SolanaAgentKit>;
-
-    fn init(_state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
-        Ok(Transfer { agent: _state })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
     }
 
     fn embedding_docs(&self) -> Vec<String> {
-        vec!["Transfer tokens or SOL to another address (also called as wallet address).".into()]
+        vec![
+            "Transfer tokens or SOL to another address (also called as wallet address).".into(),
+            "Send SOL or SPL tokens such as USDC to a recipient.".into(),
+            "Pay someone, or move funds between the agent's wallets.".into(),
+        ]
     }
 
-    fn context(&self) -> Self::Context {}
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
 }
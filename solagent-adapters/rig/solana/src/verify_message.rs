@@ -0,0 +1,107 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solagent_core::{SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture};
+use solagent_parameters::ToolParameters;
+use solagent_plugin_solana::{verify_message, MessageFormat};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize, ToolParameters)]
+pub struct VerifyMessageArgs {
+    /// The text that was signed.
+    message: String,
+    /// Base58 signature.
+    signature: String,
+    /// Address of the expected signer.
+    #[parameters(address)]
+    signer: String,
+    format: Option<MessageFormat>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct VerifyMessageOutput {
+    pub valid: bool,
+}
+
+pub struct VerifyMessage {
+    agent: Arc<SolanaAgentKit>,
+}
+
+impl VerifyMessage {
+    pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
+        VerifyMessage { agent }
+    }
+
+    async fn run(&self, args: VerifyMessageArgs) -> Result<VerifyMessageOutput, SolagentError> {
+        let format = args.format.unwrap_or_default();
+        let valid = verify_message(&args.message, &args.signature, &args.signer, format)?;
+
+        Ok(VerifyMessageOutput { valid })
+    }
+}
+
+impl SolagentTool for VerifyMessage {
+    fn name(&self) -> &str {
+        "verify_message"
+    }
+
+    fn description(&self) -> &str {
+        r#"
+            Check that a text message was signed by a Solana address.
+            format is "offchain" (default), the Solana off-chain message format, or "raw" for a signature over the message bytes as they are.
+
+            examples: [
+                [
+                    {
+                        input: {
+                            message: "Sign in to example.com",
+                            signature: "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW",
+                            signer: "8x2dR8Mpzuz2YqyZyZjUbYWKSWesBo5jMx2Q9Y86udVk",
+                        },
+                        output: {
+                            status: "success",
+                            valid: true,
+                        },
+                        explanation: "Verify a signature in the off-chain format",
+                    },
+                ],
+            ]
+            "#
+    }
+
+    fn parameters(&self) -> Value {
+        VerifyMessageArgs::schema()
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::ReadOnly
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Check that a text message was signed by a Solana address.".into(),
+            "Verify an ed25519 signature against a public key.".into(),
+            "Confirm who signed an off-chain message.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(
+            self.agent
+                .invoke_tool(self.name(), args, |args| self.run(args)),
+        )
+    }
+}
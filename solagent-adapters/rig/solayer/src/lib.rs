@@ -1,29 +1,25 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use solagent_core::{
-    rig::{
-        completion::ToolDefinition,
-        tool::Tool,
-    },
-    SolanaAgentKit,
+    Network, SolagentError, SolagentTool, SolanaAgentKit, ToolCategory, ToolFuture, TransactionOutcome,
 };
-use solagent_parameters::parameters;
+use solagent_parameters::ToolParameters;
 use solagent_plugin_solayer::stake_with_solayer;
 use std::sync::Arc;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, ToolParameters)]
 pub struct StakeWithSolayerArgs {
+    #[parameters(exclusive_minimum = 0, decimals = 9)]
     amount: f64,
+    wallet: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct StakeWithSolayerOutput {
-    pub signature: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error("StakeWithSolayer error")]
-pub struct StakeWithSolayerError;
-
 pub struct StakeWithSolayer {
     agent: Arc<SolanaAgentKit>,
 }
@@ -32,19 +28,21 @@ impl StakeWithSolayer {
     pub fn new(agent: Arc<SolanaAgentKit>) -> Self {
         StakeWithSolayer { agent }
     }
-}
 
-impl Tool for StakeWithSolayer {
-    const NAME: &'static str = "stake_with_solayer";
+    async fn run(&self, args: StakeWithSolayerArgs) -> Result<StakeWithSolayerOutput, SolagentError> {
+        let transaction = stake_with_solayer(&self.agent, args.amount, args.wallet.as_deref()).await?;
 
-    type Error = StakeWithSolayerError;
-    type Args = StakeWithSolayerArgs;
-    type Output = StakeWithSolayerOutput;
+        Ok(StakeWithSolayerOutput { transaction, network: self.agent.network.clone() })
+    }
+}
 
-    async fn definition(&self, _prompt: String) -> ToolDefinition {
-        ToolDefinition {
-            name: "stake_with_solayer".to_string(),
-            description: r#"
+impl SolagentTool for StakeWithSolayer {
+    fn name(&self) -> &str {
+        "stake_with_solayer"
+    }
+
+    fn description(&self) -> &str {
+        r#"
             
             Stake native SOL with Solayer's restaking protocol to receive Solayer SOL (sSOL)
 
@@ -65,18 +63,30 @@ impl Tool for StakeWithSolayer {
             ]
         
             "#
-            .to_string(),
-            parameters: parameters!(
-                amount: String,
-            ),
-        }
     }
 
-    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        let signature = stake_with_solayer(&self.agent, args.amount)
-            .await
-            .expect("stake_with_solayer");
+    fn parameters(&self) -> Value {
+        StakeWithSolayerArgs::schema()
+    }
 
-        Ok(StakeWithSolayerOutput { signature })
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Trading
     }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        vec![
+            "Stake native SOL with Solayer's restaking protocol to receive Solayer SOL (sSOL)".into(),
+            "Restake SOL with Solayer for extra yield.".into(),
+            "Convert SOL to sSOL.".into(),
+        ]
+    }
+
+    fn invoke(&self, args: Value) -> ToolFuture<'_> {
+        Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
+    }
+}
+
+/// Every tool of this crate, acting for `agent`.
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> Vec<Arc<dyn SolagentTool>> {
+    vec![Arc::new(StakeWithSolayer::new(agent.clone()))]
 }
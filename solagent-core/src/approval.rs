@@ -0,0 +1,341 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{BalanceChange, Outflow};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    compute_budget, message::VersionedMessage, program_utils::limited_deserialize, pubkey, pubkey::Pubkey,
+    system_instruction::SystemInstruction, system_program,
+};
+use std::{collections::HashMap, fmt, io::Write, sync::Arc};
+
+const KNOWN_PROGRAMS: [(Pubkey, &str); 7] = [
+    (system_program::ID, "System Program"),
+    (compute_budget::ID, "Compute Budget Program"),
+    (pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"), "Token Program"),
+    (pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"), "Token-2022 Program"),
+    (pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"), "Associated Token Account Program"),
+    (pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"), "Memo Program"),
+    (pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"), "Token Metadata Program"),
+];
+
+/// An instruction of a pending transaction, decoded as far as its program is known.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedInstruction {
+    pub program_id: String,
+    /// Name of a well-known program.
+    pub program: Option<String>,
+    /// Accounts the instruction uses; accounts loaded from lookup tables are shown by their index.
+    pub accounts: Vec<String>,
+    /// What the instruction does, for the instructions the kit can read.
+    pub description: Option<String>,
+    pub data_len: usize,
+}
+
+impl DecodedInstruction {
+    /// Decodes the top-level instructions of `message`.
+    pub fn from_message(message: &VersionedMessage) -> Vec<DecodedInstruction> {
+        let keys = message.static_account_keys();
+        let key = |index: u8| match keys.get(index as usize) {
+            Some(key) => key.to_string(),
+            None => format!("lookup table account #{}", index as usize - keys.len()),
+        };
+
+        message
+            .instructions()
+            .iter()
+            .map(|ix| {
+                let accounts: Vec<String> = ix.accounts.iter().map(|index| key(*index)).collect();
+                let program_id = keys.get(ix.program_id_index as usize).copied().unwrap_or_default();
+                DecodedInstruction {
+                    program_id: program_id.to_string(),
+                    program: KNOWN_PROGRAMS.iter().find(|(id, _)| *id == program_id).map(|(_, name)| name.to_string()),
+                    description: describe(&program_id, &accounts, &ix.data),
+                    accounts,
+                    data_len: ix.data.len(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Describes the handful of instructions whose meaning matters most to a reviewer: moving funds and fees.
+fn describe(program_id: &Pubkey, accounts: &[String], data: &[u8]) -> Option<String> {
+    let account = |i: usize| accounts.get(i).map_or("?", String::as_str);
+    let u64_at = |offset: usize| Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?));
+
+    if *program_id == system_program::ID {
+        return match limited_deserialize(data).ok()? {
+            SystemInstruction::Transfer { lamports } => {
+                Some(format!("transfer {} lamports from {} to {}", lamports, account(0), account(1)))
+            }
+            SystemInstruction::CreateAccount { lamports, space, .. } => {
+                Some(format!("create account {} with {} lamports and {} bytes", account(1), lamports, space))
+            }
+            _ => None,
+        };
+    }
+
+    if *program_id == compute_budget::ID {
+        return match data.first()? {
+            2 => Some(format!("set compute unit limit to {}", u32::from_le_bytes(data.get(1..5)?.try_into().ok()?))),
+            3 => Some(format!("set compute unit price to {} micro-lamports", u64_at(1)?)),
+            _ => None,
+        };
+    }
+
+    if KNOWN_PROGRAMS[2..4].iter().any(|(id, _)| id == program_id) {
+        return match data.first()? {
+            3 => Some(format!("transfer {} token units from {} to {}", u64_at(1)?, account(0), account(1))),
+            12 => Some(format!(
+                "transfer {} token units of mint {} from {} to {}",
+                u64_at(1)?,
+                account(1),
+                account(0),
+                account(2)
+            )),
+            7 => Some(format!("mint {} token units of mint {} to {}", u64_at(1)?, account(0), account(1))),
+            9 => Some(format!("close token account {}", account(0))),
+            _ => None,
+        };
+    }
+
+    None
+}
+
+/// A transaction waiting for an [`Approver`] before it is signed and broadcast.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingTransaction {
+    /// Name of the plugin action, e.g. `transfer`.
+    pub action: String,
+    pub instructions: Vec<DecodedInstruction>,
+    /// Lamport and token balances the transaction would change, from its simulation.
+    pub balance_changes: Vec<BalanceChange>,
+    /// Estimated fee in lamports.
+    pub fee: Option<u64>,
+    /// What would leave the agent's wallet, fees and rent included.
+    #[serde(skip)]
+    pub outflows: Vec<Outflow>,
+}
+
+impl fmt::Display for PendingTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Pending transaction: {}", self.action)?;
+        writeln!(f, "Instructions:")?;
+        for (i, ix) in self.instructions.iter().enumerate() {
+            let program = ix.program.as_deref().unwrap_or(&ix.program_id);
+            match &ix.description {
+                Some(description) => writeln!(f, "  {}. {}: {}", i + 1, program, description)?,
+                None => writeln!(
+                    f,
+                    "  {}. {}: {} accounts, {} bytes of data",
+                    i + 1,
+                    program,
+                    ix.accounts.len(),
+                    ix.data_len
+                )?,
+            }
+        }
+
+        writeln!(f, "Balance changes:")?;
+        if self.balance_changes.is_empty() {
+            writeln!(f, "  none")?;
+        }
+        for change in &self.balance_changes {
+            match &change.mint {
+                Some(mint) => writeln!(f, "  {}: {:+} units of token {}", change.account, change.delta, mint)?,
+                None => writeln!(f, "  {}: {:+} lamports", change.account, change.delta)?,
+            }
+        }
+
+        match self.fee {
+            Some(fee) => write!(f, "Fee: {} lamports", fee),
+            None => write!(f, "Fee: unknown"),
+        }
+    }
+}
+
+/// The answer of an [`Approver`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Approval {
+    Approve,
+    /// Refuses the transaction for the given reason, which is returned to the caller.
+    Reject(String),
+}
+
+/// Reviews every transaction before the agent signs and broadcasts it.
+///
+/// Register one with [`SolanaAgentKit::with_approver`](crate::SolanaAgentKit::with_approver).
+/// Dry runs are never submitted for approval, since nothing is signed.
+#[async_trait]
+pub trait Approver: Send + Sync {
+    async fn review(&self, pending: &PendingTransaction) -> Approval;
+}
+
+/// Asks an operator at the terminal to approve each transaction.
+///
+/// The summary is printed to stderr and the answer read from stdin; prompts of concurrent tool calls
+/// are asked one after the other.
+#[derive(Debug, Default)]
+pub struct TerminalApprover {
+    prompt: tokio::sync::Mutex<()>,
+}
+
+impl TerminalApprover {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Approver for TerminalApprover {
+    async fn review(&self, pending: &PendingTransaction) -> Approval {
+        let _prompt = self.prompt.lock().await;
+        let summary = pending.to_string();
+
+        let answer = tokio::task::spawn_blocking(move || {
+            let mut stderr = std::io::stderr();
+            write!(stderr, "{}\nApprove? [y/N] ", summary).ok()?;
+            stderr.flush().ok()?;
+
+            let mut answer = String::new();
+            std::io::stdin().read_line(&mut answer).ok()?;
+            Some(answer)
+        })
+        .await;
+
+        match answer {
+            Ok(Some(answer)) if matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") => Approval::Approve,
+            _ => Approval::Reject("declined by the operator".to_string()),
+        }
+    }
+}
+
+/// Approves small transactions on its own and hands the others to another approver.
+///
+/// A transaction is small when the lamports leaving the wallet, fees included, stay within `max_lamports`
+/// and every token leaving it has a threshold it stays within. Tokens without a threshold always go
+/// to the fallback.
+pub struct ThresholdApprover {
+    max_lamports: u64,
+    max_tokens: HashMap<Pubkey, u64>,
+    fallback: Arc<dyn Approver>,
+}
+
+impl ThresholdApprover {
+    pub fn new(max_lamports: u64, fallback: impl Approver + 'static) -> Self {
+        Self { max_lamports, max_tokens: HashMap::new(), fallback: Arc::new(fallback) }
+    }
+
+    /// Approves transfers of up to `amount` raw units of `mint` without asking the fallback.
+    pub fn max_tokens(mut self, mint: Pubkey, amount: u64) -> Self {
+        self.max_tokens.insert(mint, amount);
+        self
+    }
+
+    fn is_below_threshold(&self, pending: &PendingTransaction) -> bool {
+        pending.outflows.iter().all(|outflow| match &outflow.mint {
+            None => outflow.amount <= self.max_lamports,
+            Some(mint) => self.max_tokens.get(mint).is_some_and(|max| outflow.amount <= *max),
+        })
+    }
+}
+
+#[async_trait]
+impl Approver for ThresholdApprover {
+    async fn review(&self, pending: &PendingTransaction) -> Approval {
+        if self.is_below_threshold(pending) {
+            return Approval::Approve;
+        }
+        self.fallback.review(pending).await
+    }
+}
+
+/// Rejects every transaction, e.g. as the fallback of a [`ThresholdApprover`] running unattended.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RejectAll;
+
+#[async_trait]
+impl Approver for RejectAll {
+    async fn review(&self, _pending: &PendingTransaction) -> Approval {
+        Approval::Reject("above the auto-approval threshold".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{compute_budget::ComputeBudgetInstruction, message::Message, system_instruction};
+
+    fn pending(outflows: Vec<Outflow>) -> PendingTransaction {
+        PendingTransaction {
+            action: "transfer".to_string(),
+            instructions: vec![],
+            balance_changes: vec![],
+            fee: Some(5_000),
+            outflows,
+        }
+    }
+
+    #[test]
+    fn test_decode_instructions() {
+        let (from, to) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let message = Message::new(
+            &[ComputeBudgetInstruction::set_compute_unit_limit(450), system_instruction::transfer(&from, &to, 42)],
+            Some(&from),
+        );
+        let instructions = DecodedInstruction::from_message(&VersionedMessage::Legacy(message));
+
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].program.as_deref(), Some("Compute Budget Program"));
+        assert_eq!(instructions[0].description.as_deref(), Some("set compute unit limit to 450"));
+        assert_eq!(instructions[1].program.as_deref(), Some("System Program"));
+        assert_eq!(instructions[1].description, Some(format!("transfer 42 lamports from {} to {}", from, to)));
+    }
+
+    #[test]
+    fn test_pending_transaction_display() {
+        let mut pending = pending(vec![]);
+        pending.instructions = vec![DecodedInstruction {
+            program_id: "Prog".to_string(),
+            program: None,
+            accounts: vec!["A".to_string(), "B".to_string()],
+            description: None,
+            data_len: 8,
+        }];
+        pending.balance_changes =
+            vec![BalanceChange { account: "A".to_string(), mint: None, owner: None, pre: 10, post: 4, delta: -6 }];
+
+        assert_eq!(
+            pending.to_string(),
+            "Pending transaction: transfer\nInstructions:\n  1. Prog: 2 accounts, 8 bytes of data\n\
+             Balance changes:\n  A: -6 lamports\nFee: 5000 lamports"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_threshold_approver() {
+        let mint = Pubkey::new_unique();
+        let approver = ThresholdApprover::new(1_000, RejectAll).max_tokens(mint, 50);
+
+        let sol = |amount| Outflow { mint: None, amount };
+        let token = |mint, amount| Outflow { mint: Some(mint), amount };
+
+        assert_eq!(approver.review(&pending(vec![sol(1_000), token(mint, 50)])).await, Approval::Approve);
+        assert!(matches!(approver.review(&pending(vec![sol(1_001)])).await, Approval::Reject(_)));
+        assert!(matches!(approver.review(&pending(vec![token(mint, 51)])).await, Approval::Reject(_)));
+        assert!(matches!(approver.review(&pending(vec![token(Pubkey::new_unique(), 1)])).await, Approval::Reject(_)));
+    }
+}
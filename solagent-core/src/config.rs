@@ -12,10 +12,72 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#![allow(dead_code)]
+use crate::Network;
+use serde::Deserialize;
+use solagent_wallet_solana::Wallet;
+use std::{collections::HashMap, path::Path};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Failed to read config file {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid config in {location}: {message}")]
+    Invalid { location: String, message: String },
+    #[error("Profile `{profile}` not found in {path}")]
+    UnknownProfile { profile: String, path: String },
+    #[error("Unknown environment variable {0}")]
+    UnknownEnvVar(String),
+    #[error("Invalid value for environment variable {name}: {message}")]
+    InvalidEnvVar { name: String, message: String },
+    #[error("Missing `{0}` in config")]
+    Missing(&'static str),
+    #[error("Failed to load wallet: {0}")]
+    Wallet(String),
+}
+
+/// Where the agent's private key comes from.
+///
+/// In TOML: `wallet = { env = "SOLANA_PRIVATE_KEY" }`, `wallet = { file = "/path/to/key" }` or
+/// `wallet = { keystore = { path = "/path/to/keystore.json", password_env = "WALLET_PASSWORD" } }`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WalletSource {
+    /// Name of an environment variable holding a base58 private key.
+    Env(String),
+    /// Path of a file holding a private key, as base58 text or a Solana CLI `id.json` byte array.
+    File(String),
+    /// Path of an encrypted keystore, and name of the environment variable holding its password.
+    Keystore { path: String, password_env: String },
+}
+
+impl WalletSource {
+    pub fn load(&self) -> Result<Wallet, ConfigError> {
+        let wallet = match self {
+            WalletSource::Env(name) => Wallet::from_env(name),
+            WalletSource::File(path) => Wallet::from_file(path),
+            WalletSource::Keystore { path, password_env } => match std::env::var(password_env) {
+                Ok(password) => Wallet::from_encrypted_file(path, &password),
+                Err(_) => return Err(ConfigError::Wallet(format!("environment variable {} not found", password_env))),
+            },
+        };
+        wallet.map_err(|e| ConfigError::Wallet(format!("{:#}", e)))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
+    pub rpc_url: Option<String>,
+    pub network: Option<Network>,
+    pub wallet: Option<WalletSource>,
+    /// More wallets by label, e.g. `[wallets] treasury = { file = "/path/to/key" }`.
+    pub wallets: Option<HashMap<String, WalletSource>>,
+    /// Label of the wallet paying transaction fees; the default wallet when unset.
+    pub fee_payer: Option<String>,
     pub openai_api_key: Option<String>,
     pub jupiter_referral_account: Option<String>,
     pub jupiter_fee_bps: Option<u16>, // Assuming fee is represented as a percentage (0-10000)
@@ -24,10 +86,150 @@ pub struct Config {
     pub helius_api_key: Option<String>,
     pub cookie_api_key: Option<String>,
     pub birdeye_api_key: Option<String>,
+    /// Slippage used by swaps when the caller doesn't pass one.
+    pub default_slippage_bps: Option<u16>,
+    /// Priority fee attached to transactions built by third-party APIs, in lamports. It also caps the
+    /// priority fee of the transactions the kit sends itself, see [`PriorityFeeStrategy::max_lamports`].
+    ///
+    /// [`PriorityFeeStrategy::max_lamports`]: crate::PriorityFeeStrategy::max_lamports
+    pub priority_fee_lamports: Option<u64>,
+    /// Simulate write actions instead of sending them.
+    pub dry_run: Option<bool>,
+}
+
+impl Config {
+    /// Reads the config from environment variables.
+    ///
+    /// API keys use their usual names (`HELIUS_API_KEY`, `BIRDEYE_API_KEY`, ...). The cluster comes from
+    /// `SOLANA_RPC_URL` and `SOLANA_NETWORK`, the wallet from `SOLANA_WALLET_FILE` or `SOLANA_PRIVATE_KEY`,
+    /// and transaction defaults from `SOLAGENT_DEFAULT_SLIPPAGE_BPS`, `SOLAGENT_PRIORITY_FEE_LAMPORTS` and `SOLAGENT_DRY_RUN`.
+    /// Any other variable starting with `SOLAGENT_` is rejected, so a typo doesn't silently fall back to a default.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_vars(std::env::vars())
+    }
+
+    /// Reads the top-level settings of a TOML file, ignoring its `[profiles.*]` tables.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let (config, _) = Self::load_file(path.as_ref())?;
+        Ok(config)
+    }
+
+    /// Reads a TOML file and applies `[profiles.<profile>]` on top of its top-level settings.
+    pub fn from_file_profile(path: impl AsRef<Path>, profile: &str) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let (config, mut profiles) = Self::load_file(path)?;
+        let overrides = profiles.remove(profile).ok_or_else(|| ConfigError::UnknownProfile {
+            profile: profile.to_string(),
+            path: path.display().to_string(),
+        })?;
+
+        Ok(config.merge(overrides))
+    }
+
+    /// Returns `self` with every setting that `other` defines replaced by `other`'s value.
+    pub fn merge(self, other: Config) -> Config {
+        Config {
+            rpc_url: other.rpc_url.or(self.rpc_url),
+            network: other.network.or(self.network),
+            wallet: other.wallet.or(self.wallet),
+            wallets: other.wallets.or(self.wallets),
+            fee_payer: other.fee_payer.or(self.fee_payer),
+            openai_api_key: other.openai_api_key.or(self.openai_api_key),
+            jupiter_referral_account: other.jupiter_referral_account.or(self.jupiter_referral_account),
+            jupiter_fee_bps: other.jupiter_fee_bps.or(self.jupiter_fee_bps),
+            flash_privilege: other.flash_privilege.or(self.flash_privilege),
+            flexlend_api_key: other.flexlend_api_key.or(self.flexlend_api_key),
+            helius_api_key: other.helius_api_key.or(self.helius_api_key),
+            cookie_api_key: other.cookie_api_key.or(self.cookie_api_key),
+            birdeye_api_key: other.birdeye_api_key.or(self.birdeye_api_key),
+            default_slippage_bps: other.default_slippage_bps.or(self.default_slippage_bps),
+            priority_fee_lamports: other.priority_fee_lamports.or(self.priority_fee_lamports),
+            dry_run: other.dry_run.or(self.dry_run),
+        }
+    }
+
+    fn load_file(path: &Path) -> Result<(Config, HashMap<String, Config>), ConfigError> {
+        let display = path.display().to_string();
+        let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Io { path: display.clone(), source })?;
+        Self::parse_toml(&text, &display)
+    }
+
+    fn parse_toml(text: &str, location: &str) -> Result<(Config, HashMap<String, Config>), ConfigError> {
+        let invalid = |location: String, message: String| ConfigError::Invalid { location, message };
+
+        let mut table: toml::Table =
+            text.parse().map_err(|e: toml::de::Error| invalid(location.to_string(), e.to_string()))?;
+        let profile_tables = match table.remove("profiles") {
+            Some(toml::Value::Table(profiles)) => profiles,
+            Some(_) => return Err(invalid(location.to_string(), "`profiles` must be a table".to_string())),
+            None => toml::Table::new(),
+        };
+
+        let config =
+            Config::deserialize(toml::Value::Table(table)).map_err(|e| invalid(location.to_string(), e.to_string()))?;
+
+        let mut profiles = HashMap::new();
+        for (name, value) in profile_tables {
+            let profile_location = format!("[profiles.{}] of {}", name, location);
+            let profile = Config::deserialize(value).map_err(|e| invalid(profile_location, e.to_string()))?;
+            profiles.insert(name, profile);
+        }
+
+        Ok((config, profiles))
+    }
+
+    fn from_vars(vars: impl IntoIterator<Item = (String, String)>) -> Result<Self, ConfigError> {
+        let mut config = Config::default();
+        let mut private_key_var = None;
+        let mut wallet_file = None;
+
+        for (name, value) in vars {
+            match name.as_str() {
+                "SOLANA_RPC_URL" => config.rpc_url = Some(value),
+                "SOLANA_NETWORK" => config.network = Some(parse_env(&name, &value)?),
+                "SOLANA_PRIVATE_KEY" => private_key_var = Some(name),
+                "SOLANA_WALLET_FILE" => wallet_file = Some(value),
+                "OPENAI_API_KEY" => config.openai_api_key = Some(value),
+                "JUPITER_REFERRAL_ACCOUNT" => config.jupiter_referral_account = Some(value),
+                "JUPITER_FEE_BPS" => config.jupiter_fee_bps = Some(parse_env(&name, &value)?),
+                "FLASH_PRIVILEGE" => config.flash_privilege = Some(value),
+                "FLEXLEND_API_KEY" => config.flexlend_api_key = Some(value),
+                "HELIUS_API_KEY" => config.helius_api_key = Some(value),
+                "COOKIE_API_KEY" => config.cookie_api_key = Some(value),
+                "BIRDEYE_API_KEY" => config.birdeye_api_key = Some(value),
+                "SOLAGENT_DEFAULT_SLIPPAGE_BPS" => config.default_slippage_bps = Some(parse_env(&name, &value)?),
+                "SOLAGENT_PRIORITY_FEE_LAMPORTS" => config.priority_fee_lamports = Some(parse_env(&name, &value)?),
+                "SOLAGENT_DRY_RUN" => config.dry_run = Some(parse_env(&name, &value)?),
+                _ if name.starts_with("SOLAGENT_") => return Err(ConfigError::UnknownEnvVar(name)),
+                _ => {}
+            }
+        }
+
+        // A key file wins over a key held in the environment itself.
+        config.wallet = wallet_file.map(WalletSource::File).or(private_key_var.map(WalletSource::Env));
+
+        Ok(config)
+    }
+}
+
+fn parse_env<T>(name: &str, value: &str) -> Result<T, ConfigError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|e: T::Err| ConfigError::InvalidEnvVar { name: name.to_string(), message: e.to_string() })
 }
 
 #[derive(Default)]
 pub struct ConfigBuilder {
+    rpc_url: Option<String>,
+    network: Option<Network>,
+    wallet: Option<WalletSource>,
+    wallets: Option<HashMap<String, WalletSource>>,
+    fee_payer: Option<String>,
     openai_api_key: Option<String>,
     jupiter_referral_account: Option<String>,
     jupiter_fee_bps: Option<u16>,
@@ -36,9 +238,38 @@ pub struct ConfigBuilder {
     helius_api_key: Option<String>,
     cookie_api_key: Option<String>,
     birdeye_api_key: Option<String>,
+    default_slippage_bps: Option<u16>,
+    priority_fee_lamports: Option<u64>,
+    dry_run: Option<bool>,
 }
 
 impl ConfigBuilder {
+    pub fn rpc_url(mut self, url: String) -> Self {
+        self.rpc_url = Some(url);
+        self
+    }
+
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    pub fn wallet(mut self, source: WalletSource) -> Self {
+        self.wallet = Some(source);
+        self
+    }
+
+    /// Adds a wallet under `label`, next to the default `wallet`.
+    pub fn named_wallet(mut self, label: String, source: WalletSource) -> Self {
+        self.wallets.get_or_insert_with(HashMap::new).insert(label, source);
+        self
+    }
+
+    pub fn fee_payer(mut self, label: String) -> Self {
+        self.fee_payer = Some(label);
+        self
+    }
+
     pub fn openai_api_key(mut self, key: String) -> Self {
         self.openai_api_key = Some(key);
         self
@@ -79,8 +310,28 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn default_slippage_bps(mut self, bps: u16) -> Self {
+        self.default_slippage_bps = Some(bps);
+        self
+    }
+
+    pub fn priority_fee_lamports(mut self, lamports: u64) -> Self {
+        self.priority_fee_lamports = Some(lamports);
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = Some(dry_run);
+        self
+    }
+
     pub fn build(self) -> Config {
         Config {
+            rpc_url: self.rpc_url,
+            network: self.network,
+            wallet: self.wallet,
+            wallets: self.wallets,
+            fee_payer: self.fee_payer,
             openai_api_key: self.openai_api_key,
             jupiter_referral_account: self.jupiter_referral_account,
             jupiter_fee_bps: self.jupiter_fee_bps,
@@ -89,6 +340,9 @@ impl ConfigBuilder {
             helius_api_key: self.helius_api_key,
             cookie_api_key: self.cookie_api_key,
             birdeye_api_key: self.birdeye_api_key,
+            default_slippage_bps: self.default_slippage_bps,
+            priority_fee_lamports: self.priority_fee_lamports,
+            dry_run: self.dry_run,
         }
     }
 }
@@ -132,4 +386,101 @@ mod tests {
         assert_eq!(config.cookie_api_key, Some("test_cookie_key".to_string()));
         assert_eq!(config.birdeye_api_key, Some("birdeye_api_key".to_string()));
     }
+
+    const CONFIG_TOML: &str = r#"
+        rpc_url = "https://api.mainnet-beta.solana.com"
+        wallet = { env = "SOLANA_PRIVATE_KEY" }
+        helius_api_key = "helius_key"
+        default_slippage_bps = 50
+        fee_payer = "treasury"
+
+        [wallets]
+        treasury = { env = "TREASURY_PRIVATE_KEY" }
+        cold = { keystore = { path = "cold.json", password_env = "COLD_PASSWORD" } }
+
+        [profiles.devnet]
+        rpc_url = "https://api.devnet.solana.com"
+        network = "devnet"
+        wallet = { file = "devnet-key.txt" }
+        priority_fee_lamports = 10000
+    "#;
+
+    #[test]
+    fn test_config_from_toml() {
+        let (config, profiles) = Config::parse_toml(CONFIG_TOML, "test").unwrap();
+        assert_eq!(config.rpc_url.as_deref(), Some("https://api.mainnet-beta.solana.com"));
+        assert_eq!(config.wallet, Some(WalletSource::Env("SOLANA_PRIVATE_KEY".to_string())));
+        assert_eq!(config.default_slippage_bps, Some(50));
+        assert!(config.network.is_none());
+        assert_eq!(config.fee_payer.as_deref(), Some("treasury"));
+        assert_eq!(config.wallets.as_ref().unwrap()["treasury"], WalletSource::Env("TREASURY_PRIVATE_KEY".to_string()));
+        assert_eq!(
+            config.wallets.as_ref().unwrap()["cold"],
+            WalletSource::Keystore { path: "cold.json".to_string(), password_env: "COLD_PASSWORD".to_string() }
+        );
+
+        let devnet = config.merge(profiles["devnet"].clone());
+        assert_eq!(devnet.rpc_url.as_deref(), Some("https://api.devnet.solana.com"));
+        assert_eq!(devnet.network, Some(Network::Devnet));
+        assert_eq!(devnet.wallet, Some(WalletSource::File("devnet-key.txt".to_string())));
+        assert_eq!(devnet.helius_api_key.as_deref(), Some("helius_key"));
+        assert_eq!(devnet.default_slippage_bps, Some(50));
+        assert_eq!(devnet.priority_fee_lamports, Some(10000));
+    }
+
+    #[test]
+    fn test_config_from_file_profile() {
+        let path = std::env::temp_dir().join("solagent_test_config_from_file_profile.toml");
+        std::fs::write(&path, CONFIG_TOML).unwrap();
+
+        let devnet = Config::from_file_profile(&path, "devnet").unwrap();
+        assert_eq!(devnet.network, Some(Network::Devnet));
+        assert!(matches!(Config::from_file_profile(&path, "testnet"), Err(ConfigError::UnknownProfile { .. })));
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(Config::from_file(&path), Err(ConfigError::Io { .. })));
+    }
+
+    #[test]
+    fn test_config_rejects_unknown_and_malformed_keys() {
+        let error = Config::parse_toml("helius_key = \"x\"", "test").unwrap_err();
+        assert!(error.to_string().contains("unknown field `helius_key`"), "{}", error);
+
+        let error = Config::parse_toml("[profiles.devnet]\njupiter_fee_bps = \"high\"", "test").unwrap_err();
+        assert!(error.to_string().contains("[profiles.devnet]"), "{}", error);
+
+        let error = Config::parse_toml("wallet = { path = \"key.txt\" }", "test").unwrap_err();
+        assert!(matches!(error, ConfigError::Invalid { .. }));
+    }
+
+    #[test]
+    fn test_config_from_vars() {
+        let vars =
+            |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<Vec<_>>();
+
+        let config = Config::from_vars(vars(&[
+            ("SOLANA_RPC_URL", "https://api.devnet.solana.com"),
+            ("SOLANA_PRIVATE_KEY", "secret"),
+            ("BIRDEYE_API_KEY", "birdeye_key"),
+            ("SOLAGENT_DEFAULT_SLIPPAGE_BPS", "75"),
+            ("SOLAGENT_DRY_RUN", "true"),
+            ("PATH", "/usr/bin"),
+        ]))
+        .unwrap();
+        assert_eq!(config.rpc_url.as_deref(), Some("https://api.devnet.solana.com"));
+        assert_eq!(config.wallet, Some(WalletSource::Env("SOLANA_PRIVATE_KEY".to_string())));
+        assert_eq!(config.birdeye_api_key.as_deref(), Some("birdeye_key"));
+        assert_eq!(config.default_slippage_bps, Some(75));
+        assert_eq!(config.dry_run, Some(true));
+
+        let config =
+            Config::from_vars(vars(&[("SOLANA_PRIVATE_KEY", "secret"), ("SOLANA_WALLET_FILE", "key.txt")])).unwrap();
+        assert_eq!(config.wallet, Some(WalletSource::File("key.txt".to_string())));
+
+        let error = Config::from_vars(vars(&[("JUPITER_FEE_BPS", "lots")])).unwrap_err();
+        assert!(matches!(error, ConfigError::InvalidEnvVar { ref name, .. } if name == "JUPITER_FEE_BPS"));
+
+        let error = Config::from_vars(vars(&[("SOLAGENT_SLIPPAGE", "50")])).unwrap_err();
+        assert!(matches!(error, ConfigError::UnknownEnvVar(ref name) if name == "SOLAGENT_SLIPPAGE"));
+    }
 }
@@ -0,0 +1,152 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Network, PolicyViolation};
+use solagent_parameters::InvalidArguments;
+use solana_client::client_error::ClientError;
+use solana_sdk::{program_error::ProgramError, signer::SignerError, transaction::TransactionError};
+
+/// The error type returned by every solagent plugin and carried by every tool adapter.
+///
+/// The `Display` output is written to be read by an LLM, so each message says what failed
+/// and, where possible, what the caller could change.
+#[derive(Debug, thiserror::Error)]
+pub enum SolagentError {
+    /// The Solana RPC node rejected or failed a request.
+    #[error("Solana RPC error: {0}")]
+    Rpc(Box<ClientError>),
+
+    /// The HTTP request itself failed (connection, timeout, TLS, ...).
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// A third-party API answered with a non-success status code.
+    #[error("API request failed with status {status}: {message}")]
+    Api { status: u16, message: String },
+
+    /// A response or a transaction could not be decoded.
+    #[error("Failed to decode {0}")]
+    Decode(String),
+
+    /// The caller supplied an argument that cannot be used.
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    /// The arguments of a tool call do not satisfy the tool's schema; every offending field is named.
+    #[error("Invalid arguments: {0}")]
+    InvalidArguments(#[from] InvalidArguments),
+
+    /// A required setting, such as an API key, is missing from `Config`.
+    #[error("Missing configuration: {0}")]
+    Config(String),
+
+    /// No wallet of the kit uses this label.
+    #[error("Unknown wallet `{0}`")]
+    UnknownWallet(String),
+
+    /// The wallet does not hold enough funds for the requested action.
+    #[error("Insufficient funds: required {required}, available {available}")]
+    InsufficientFunds { required: u64, available: u64 },
+
+    /// The action cannot run on the cluster the agent is connected to.
+    #[error("`{action}` is not available on {network}")]
+    UnsupportedNetwork { action: String, network: Network },
+
+    /// The action was refused by the agent's policy.
+    #[error("Rejected by policy: {0}")]
+    PolicyRejected(#[from] PolicyViolation),
+
+    /// The transaction was refused by the agent's approver.
+    #[error("Rejected by approver: {0}")]
+    ApprovalRejected(String),
+
+    /// The transaction failed in simulation or on chain; `logs` holds the program logs, if any.
+    #[error("Transaction failed: {error}")]
+    TransactionFailed { error: TransactionError, logs: Vec<String> },
+
+    /// The transaction did not land before its blockhash expired, even after being signed again.
+    #[error("Transaction {signature} was not confirmed after {attempts} attempts, its blockhash expired")]
+    TransactionExpired { signature: String, attempts: u32 },
+
+    /// The embedding model failed, e.g. while indexing tools.
+    #[error("Embedding failed: {0}")]
+    Embedding(String),
+
+    /// The transaction could not be signed.
+    #[error("Failed to sign transaction: {0}")]
+    Signing(#[from] SignerError),
+}
+
+impl SolagentError {
+    /// Builds an [`SolagentError::Api`] from a failed response, keeping its body as the message.
+    pub async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let message = match response.text().await {
+            Ok(text) if !text.is_empty() => text,
+            _ => status.canonical_reason().unwrap_or("Unknown").to_string(),
+        };
+
+        SolagentError::Api { status: status.as_u16(), message }
+    }
+}
+
+impl From<ClientError> for SolagentError {
+    fn from(error: ClientError) -> Self {
+        SolagentError::Rpc(Box::new(error))
+    }
+}
+
+impl From<serde_json::Error> for SolagentError {
+    fn from(error: serde_json::Error) -> Self {
+        SolagentError::Decode(format!("JSON: {}", error))
+    }
+}
+
+impl From<ProgramError> for SolagentError {
+    fn from(error: ProgramError) -> Self {
+        SolagentError::Decode(format!("account data: {}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        let error = SolagentError::Api { status: 429, message: "Too Many Requests".to_string() };
+        assert_eq!(error.to_string(), "API request failed with status 429: Too Many Requests");
+
+        let error = SolagentError::InsufficientFunds { required: 10, available: 3 };
+        assert_eq!(error.to_string(), "Insufficient funds: required 10, available 3");
+
+        let error = SolagentError::UnsupportedNetwork { action: "trade".to_string(), network: Network::Devnet };
+        assert_eq!(error.to_string(), "`trade` is not available on devnet");
+
+        let error = SolagentError::TransactionExpired { signature: "5xYz".to_string(), attempts: 3 };
+        assert_eq!(error.to_string(), "Transaction 5xYz was not confirmed after 3 attempts, its blockhash expired");
+
+        let error = SolagentError::from(PolicyViolation::RecipientDenied("5xYz".to_string()));
+        assert_eq!(error.to_string(), "Rejected by policy: recipient 5xYz is on the denylist");
+
+        let error = SolagentError::ApprovalRejected("declined by the operator".to_string());
+        assert_eq!(error.to_string(), "Rejected by approver: declined by the operator");
+    }
+
+    #[test]
+    fn test_error_from_serde_json() {
+        let error: SolagentError = serde_json::from_str::<u64>("not a number").unwrap_err().into();
+        assert!(matches!(error, SolagentError::Decode(_)));
+    }
+}
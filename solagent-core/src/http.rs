@@ -0,0 +1,209 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SolagentError;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client, Proxy, RequestBuilder,
+};
+use std::{collections::HashMap, time::Duration};
+
+/// A third-party API called by one of the plugins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Service {
+    JupiterQuote,
+    JupiterPrice,
+    JupiterTokens,
+    JupiterWorker,
+    Birdeye,
+    RugCheck,
+    Pyth,
+    GoPlus,
+    Cookie,
+    Helius,
+    /// Helius RPC; defaults to the cluster the agent is connected to.
+    HeliusRpc,
+    Gibwork,
+    PumpFun,
+    PumpPortal,
+    Solayer,
+    DexScreener,
+}
+
+impl Service {
+    /// The public endpoint used when no override is configured.
+    pub fn default_base_url(self) -> &'static str {
+        match self {
+            Service::JupiterQuote => "https://quote-api.jup.ag/v6",
+            Service::JupiterPrice => "https://api.jup.ag/price/v2",
+            Service::JupiterTokens => "https://tokens.jup.ag",
+            Service::JupiterWorker => "https://worker.jup.ag",
+            Service::Birdeye => "https://public-api.birdeye.so",
+            Service::RugCheck => "https://api.rugcheck.xyz/v1",
+            Service::Pyth => "https://hermes.pyth.network/v2",
+            Service::GoPlus => "https://api.gopluslabs.io/api/v1",
+            Service::Cookie => "https://api.cookie.fun",
+            Service::Helius => "https://api.helius.xyz/v0",
+            Service::HeliusRpc => "https://mainnet.helius-rpc.com",
+            Service::Gibwork => "https://api2.gib.work",
+            Service::PumpFun => "https://pump.fun/api",
+            Service::PumpPortal => "https://pumpportal.fun/api",
+            Service::Solayer => "https://app.solayer.org/api",
+            Service::DexScreener => "https://api.dexscreener.com",
+        }
+    }
+}
+
+/// The HTTP client shared by every plugin: one pooled `reqwest::Client` plus per-service base urls.
+#[derive(Debug, Clone, Default)]
+pub struct HttpProvider {
+    client: Client,
+    base_urls: HashMap<Service, String>,
+}
+
+impl HttpProvider {
+    pub fn builder() -> HttpProviderBuilder {
+        HttpProviderBuilder::default()
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The overridden base url of `service`, if any.
+    pub fn base_url_override(&self, service: Service) -> Option<&str> {
+        self.base_urls.get(&service).map(String::as_str)
+    }
+
+    pub fn base_url(&self, service: Service) -> &str {
+        self.base_url_override(service).unwrap_or(service.default_base_url())
+    }
+
+    /// Joins `path` onto the base url of `service`.
+    pub fn url(&self, service: Service, path: &str) -> String {
+        join_url(self.base_url(service), path)
+    }
+
+    pub fn get(&self, service: Service, path: &str) -> RequestBuilder {
+        self.client.get(self.url(service, path))
+    }
+
+    pub fn post(&self, service: Service, path: &str) -> RequestBuilder {
+        self.client.post(self.url(service, path))
+    }
+
+    pub fn delete(&self, service: Service, path: &str) -> RequestBuilder {
+        self.client.delete(self.url(service, path))
+    }
+}
+
+#[derive(Default)]
+pub struct HttpProviderBuilder {
+    client: Option<Client>,
+    base_urls: HashMap<Service, String>,
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+}
+
+impl HttpProviderBuilder {
+    /// Points `service` at another base url, e.g. a proxy or a local mock server.
+    pub fn base_url(mut self, service: Service, url: impl Into<String>) -> Self {
+        self.base_urls.insert(service, url.into());
+        self
+    }
+
+    /// Sends `name: value` with every request.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Uses an already configured client; headers, timeouts and proxy set on this builder are then ignored.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn build(self) -> Result<HttpProvider, SolagentError> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder().default_headers(self.headers);
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(HttpProvider { client, base_urls: self.base_urls })
+    }
+}
+
+fn join_url(base: &str, path: &str) -> String {
+    if path.is_empty() {
+        return base.to_string();
+    }
+    format!("{}/{}", base.trim_end_matches('/'), path.trim_start_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_provider_default_urls() {
+        let http = HttpProvider::default();
+        assert_eq!(http.url(Service::JupiterQuote, "/quote"), "https://quote-api.jup.ag/v6/quote");
+        assert_eq!(http.url(Service::RugCheck, "tokens/abc/report"), "https://api.rugcheck.xyz/v1/tokens/abc/report");
+        assert!(http.base_url_override(Service::Birdeye).is_none());
+    }
+
+    #[test]
+    fn test_http_provider_overrides() {
+        let http = HttpProvider::builder()
+            .base_url(Service::Birdeye, "http://localhost:8080/birdeye/")
+            .header(HeaderName::from_static("x-team"), HeaderValue::from_static("agents"))
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        assert_eq!(http.base_url_override(Service::Birdeye), Some("http://localhost:8080/birdeye/"));
+        assert_eq!(http.url(Service::Birdeye, "/defi/price"), "http://localhost:8080/birdeye/defi/price");
+        assert_eq!(http.base_url(Service::Pyth), "https://hermes.pyth.network/v2");
+    }
+}
@@ -0,0 +1,551 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{BalanceChange, SolagentError, TransactionOutcome};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fmt::Display,
+    fs::{File, OpenOptions},
+    future::Future,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// One tool or plugin call, as written to the [`Journal`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub tool: String,
+    /// Milliseconds since the Unix epoch.
+    pub started_at: u64,
+    pub finished_at: u64,
+    pub arguments: Value,
+    pub result: Option<Value>,
+    pub error: Option<String>,
+    /// Signatures of the transactions the call landed.
+    pub signatures: Vec<String>,
+    /// Total fee paid by those transactions, in lamports.
+    pub fee: Option<u64>,
+    /// Mints named in the arguments or whose balances the call's transactions changed.
+    pub mints: Vec<String>,
+    pub dry_run: bool,
+}
+
+/// Filters for [`Journal::query`]; unset fields match every entry.
+#[derive(Debug, Clone, Default)]
+pub struct JournalQuery {
+    /// Earliest `started_at`, inclusive.
+    pub from: Option<u64>,
+    /// Latest `started_at`, exclusive.
+    pub to: Option<u64>,
+    pub tool: Option<String>,
+    pub mint: Option<String>,
+    pub signature: Option<String>,
+    /// Returns at most this many entries, oldest first.
+    pub limit: Option<usize>,
+}
+
+impl JournalQuery {
+    pub fn matches(&self, entry: &JournalEntry) -> bool {
+        self.from.map_or(true, |from| entry.started_at >= from)
+            && self.to.map_or(true, |to| entry.started_at < to)
+            && self.tool.as_ref().map_or(true, |tool| entry.tool == *tool)
+            && self.mint.as_ref().map_or(true, |mint| entry.mints.contains(mint))
+            && self.signature.as_ref().map_or(true, |signature| entry.signatures.contains(signature))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum JournalError {
+    #[error("Journal I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Malformed journal entry: {0}")]
+    Serde(#[from] serde_json::Error),
+
+    #[cfg(feature = "sqlite")]
+    #[error("Journal database error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+}
+
+/// An append-only record of what the agent did.
+///
+/// Register one with [`SolanaAgentKit::with_journal`](crate::SolanaAgentKit::with_journal); every call made
+/// through [`SolanaAgentKit::journaled`](crate::SolanaAgentKit::journaled) and every transaction sent outside
+/// of such a call is then written to it.
+pub trait Journal: Send + Sync {
+    fn append(&self, entry: &JournalEntry) -> Result<(), JournalError>;
+
+    /// Entries matching `query`, in the order they were written.
+    fn query(&self, query: &JournalQuery) -> Result<Vec<JournalEntry>, JournalError>;
+}
+
+/// A journal kept as one JSON object per line.
+#[derive(Debug)]
+pub struct JsonlJournal {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl JsonlJournal {
+    /// Opens the journal at `path`, creating it if needed; existing entries are kept.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, JournalError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self { path, file: Mutex::new(file) })
+    }
+}
+
+impl Journal for JsonlJournal {
+    fn append(&self, entry: &JournalEntry) -> Result<(), JournalError> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        // One write per entry, so concurrent appends never interleave.
+        self.file.lock().unwrap_or_else(|e| e.into_inner()).write_all(&line)?;
+        Ok(())
+    }
+
+    fn query(&self, query: &JournalQuery) -> Result<Vec<JournalEntry>, JournalError> {
+        let mut entries = vec![];
+        for line in BufReader::new(File::open(&self.path)?).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: JournalEntry = serde_json::from_str(&line)?;
+            if query.matches(&entry) {
+                entries.push(entry);
+                if query.limit.is_some_and(|limit| entries.len() >= limit) {
+                    break;
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// A journal kept in a SQLite database, indexed by time, signature and mint.
+#[cfg(feature = "sqlite")]
+#[derive(Debug)]
+pub struct SqliteJournal {
+    connection: Mutex<rusqlite::Connection>,
+}
+
+#[cfg(feature = "sqlite")]
+const SQLITE_SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS journal (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        tool TEXT NOT NULL,
+        started_at INTEGER NOT NULL,
+        finished_at INTEGER NOT NULL,
+        arguments TEXT NOT NULL,
+        result TEXT,
+        error TEXT,
+        fee INTEGER,
+        dry_run INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS journal_signatures (
+        entry_id INTEGER NOT NULL REFERENCES journal(id),
+        signature TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS journal_mints (
+        entry_id INTEGER NOT NULL REFERENCES journal(id),
+        mint TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS journal_started_at ON journal(started_at);
+    CREATE INDEX IF NOT EXISTS journal_tool ON journal(tool);
+    CREATE INDEX IF NOT EXISTS journal_signatures_signature ON journal_signatures(signature);
+    CREATE INDEX IF NOT EXISTS journal_mints_mint ON journal_mints(mint);
+    CREATE TRIGGER IF NOT EXISTS journal_no_update BEFORE UPDATE ON journal
+        BEGIN SELECT RAISE(ABORT, 'the journal is append-only'); END;
+    CREATE TRIGGER IF NOT EXISTS journal_no_delete BEFORE DELETE ON journal
+        BEGIN SELECT RAISE(ABORT, 'the journal is append-only'); END;
+";
+
+#[cfg(feature = "sqlite")]
+impl SqliteJournal {
+    /// Opens the database at `path`, creating it and its tables if needed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, JournalError> {
+        Self::with_connection(rusqlite::Connection::open(path)?)
+    }
+
+    /// Opens a journal that lives only as long as this value, e.g. for tests.
+    pub fn open_in_memory() -> Result<Self, JournalError> {
+        Self::with_connection(rusqlite::Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: rusqlite::Connection) -> Result<Self, JournalError> {
+        connection.execute_batch(SQLITE_SCHEMA)?;
+        Ok(Self { connection: Mutex::new(connection) })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Journal for SqliteJournal {
+    fn append(&self, entry: &JournalEntry) -> Result<(), JournalError> {
+        use rusqlite::params;
+
+        let mut connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+        let transaction = connection.transaction()?;
+        transaction.execute(
+            "INSERT INTO journal (tool, started_at, finished_at, arguments, result, error, fee, dry_run)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                entry.tool,
+                entry.started_at as i64,
+                entry.finished_at as i64,
+                serde_json::to_string(&entry.arguments)?,
+                entry.result.as_ref().map(serde_json::to_string).transpose()?,
+                entry.error,
+                entry.fee.map(|fee| fee as i64),
+                entry.dry_run,
+            ],
+        )?;
+        let id = transaction.last_insert_rowid();
+        for signature in &entry.signatures {
+            transaction.execute(
+                "INSERT INTO journal_signatures (entry_id, signature) VALUES (?1, ?2)",
+                params![id, signature],
+            )?;
+        }
+        for mint in &entry.mints {
+            transaction.execute("INSERT INTO journal_mints (entry_id, mint) VALUES (?1, ?2)", params![id, mint])?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn query(&self, query: &JournalQuery) -> Result<Vec<JournalEntry>, JournalError> {
+        use rusqlite::{params_from_iter, types::Value as SqlValue};
+
+        let mut sql = "SELECT id, tool, started_at, finished_at, arguments, result, error, fee, dry_run
+                       FROM journal WHERE 1 = 1"
+            .to_string();
+        let mut values: Vec<SqlValue> = vec![];
+        if let Some(from) = query.from {
+            sql.push_str(" AND started_at >= ?");
+            values.push(SqlValue::Integer(from as i64));
+        }
+        if let Some(to) = query.to {
+            sql.push_str(" AND started_at < ?");
+            values.push(SqlValue::Integer(to as i64));
+        }
+        if let Some(tool) = &query.tool {
+            sql.push_str(" AND tool = ?");
+            values.push(SqlValue::Text(tool.clone()));
+        }
+        if let Some(mint) = &query.mint {
+            sql.push_str(" AND id IN (SELECT entry_id FROM journal_mints WHERE mint = ?)");
+            values.push(SqlValue::Text(mint.clone()));
+        }
+        if let Some(signature) = &query.signature {
+            sql.push_str(" AND id IN (SELECT entry_id FROM journal_signatures WHERE signature = ?)");
+            values.push(SqlValue::Text(signature.clone()));
+        }
+        sql.push_str(" ORDER BY id");
+        if let Some(limit) = query.limit {
+            sql.push_str(" LIMIT ?");
+            values.push(SqlValue::Integer(limit as i64));
+        }
+
+        let connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+        let strings = |sql: &str, id: i64| -> rusqlite::Result<Vec<String>> {
+            connection.prepare_cached(sql)?.query_map([id], |row| row.get(0))?.collect()
+        };
+
+        let mut statement = connection.prepare(&sql)?;
+        let mut rows = statement.query(params_from_iter(values))?;
+        let mut entries = vec![];
+        while let Some(row) = rows.next()? {
+            let id: i64 = row.get(0)?;
+            let arguments: String = row.get(4)?;
+            let result: Option<String> = row.get(5)?;
+            entries.push(JournalEntry {
+                tool: row.get(1)?,
+                started_at: row.get::<_, i64>(2)? as u64,
+                finished_at: row.get::<_, i64>(3)? as u64,
+                arguments: serde_json::from_str(&arguments)?,
+                result: result.as_deref().map(serde_json::from_str).transpose()?,
+                error: row.get(6)?,
+                fee: row.get::<_, Option<i64>>(7)?.map(|fee| fee as u64),
+                dry_run: row.get(8)?,
+                signatures: strings("SELECT signature FROM journal_signatures WHERE entry_id = ?1 ORDER BY rowid", id)?,
+                mints: strings("SELECT mint FROM journal_mints WHERE entry_id = ?1 ORDER BY rowid", id)?,
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// Transactions sent while a journaled call runs.
+#[derive(Debug, Default)]
+struct CallRecord {
+    signatures: Vec<String>,
+    fee: Option<u64>,
+    mints: Vec<String>,
+}
+
+impl CallRecord {
+    fn add(&mut self, outcome: &TransactionOutcome, balance_changes: &[BalanceChange]) {
+        if let TransactionOutcome::Confirmed(receipt) = outcome {
+            self.signatures.push(receipt.signature.clone());
+            if let Some(fee) = receipt.fee {
+                self.fee = Some(self.fee.unwrap_or(0) + fee);
+            }
+        }
+        for mint in balance_changes.iter().filter_map(|change| change.mint.as_ref()) {
+            if !self.mints.contains(mint) {
+                self.mints.push(mint.clone());
+            }
+        }
+    }
+}
+
+tokio::task_local! {
+    // A `Mutex` rather than a `RefCell`, so that journaled futures stay `Sync`.
+    static CALL: Mutex<CallRecord>;
+}
+
+/// Attaches a transaction to the journaled call running on this task; `false` when there is no such call.
+fn record_transaction(outcome: &TransactionOutcome, balance_changes: &[BalanceChange]) -> bool {
+    CALL.try_with(|call| call.lock().unwrap_or_else(|e| e.into_inner()).add(outcome, balance_changes)).is_ok()
+}
+
+/// Journals a transaction handled by the sender.
+///
+/// Within a journaled call it is attached to the call's entry, whose result already carries any error;
+/// otherwise it gets an entry of its own, named after the plugin action.
+pub(crate) fn record_send(
+    journal: Option<&dyn Journal>,
+    action: &str,
+    started_at: u64,
+    dry_run: bool,
+    balance_changes: &[BalanceChange],
+    result: &Result<TransactionOutcome, SolagentError>,
+) {
+    match result {
+        Ok(outcome) if record_transaction(outcome, balance_changes) => return,
+        Err(_) if CALL.try_with(|_| ()).is_ok() => return,
+        _ => {}
+    }
+    let Some(journal) = journal else {
+        return;
+    };
+
+    let mut record = CallRecord::default();
+    if let Ok(outcome) = result {
+        record.add(outcome, balance_changes);
+    }
+    append(
+        journal,
+        &JournalEntry {
+            tool: action.to_string(),
+            started_at,
+            finished_at: now(),
+            arguments: Value::Null,
+            result: result.as_ref().ok().and_then(|outcome| serde_json::to_value(outcome).ok()),
+            error: result.as_ref().err().map(ToString::to_string),
+            signatures: record.signatures,
+            fee: record.fee,
+            mints: record.mints,
+            dry_run,
+        },
+    );
+}
+
+/// Runs `call` and journals it along with every transaction it sends.
+pub(crate) async fn record_call<A, T, E, Fut>(
+    journal: &dyn Journal,
+    tool: &str,
+    dry_run: bool,
+    args: A,
+    call: impl FnOnce(A) -> Fut,
+) -> Result<T, E>
+where
+    A: Serialize,
+    T: Serialize,
+    E: Display,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let arguments = serde_json::to_value(&args).unwrap_or(Value::Null);
+    let started_at = now();
+    let (result, record) = CALL
+        .scope(Mutex::new(CallRecord::default()), async {
+            let result = call(args).await;
+            (result, CALL.with(|call| std::mem::take(&mut *call.lock().unwrap_or_else(|e| e.into_inner()))))
+        })
+        .await;
+
+    let mut mints = argument_mints(&arguments);
+    for mint in record.mints {
+        if !mints.contains(&mint) {
+            mints.push(mint);
+        }
+    }
+    let (output, error) = match &result {
+        Ok(output) => (serde_json::to_value(output).ok(), None),
+        Err(error) => (None, Some(error.to_string())),
+    };
+    append(
+        journal,
+        &JournalEntry {
+            tool: tool.to_string(),
+            started_at,
+            finished_at: now(),
+            arguments,
+            result: output,
+            error,
+            signatures: record.signatures,
+            fee: record.fee,
+            mints,
+            dry_run,
+        },
+    );
+    result
+}
+
+/// Writes `entry`, reporting a failure on stderr instead of failing the action: the transaction may have
+/// landed already, and an error would invite the caller to send it again.
+pub(crate) fn append(journal: &dyn Journal, entry: &JournalEntry) {
+    if let Err(error) = journal.append(entry) {
+        eprintln!("solagent: could not write `{}` to the journal: {}", entry.tool, error);
+    }
+}
+
+/// Milliseconds since the Unix epoch.
+pub(crate) fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// String arguments whose name ends in `mint`, such as `mint` or `output_mint`.
+fn argument_mints(arguments: &Value) -> Vec<String> {
+    let Some(arguments) = arguments.as_object() else {
+        return vec![];
+    };
+    arguments
+        .iter()
+        .filter(|(name, _)| name.ends_with("mint"))
+        .filter_map(|(_, value)| value.as_str().map(str::to_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransactionReceipt;
+    use serde_json::json;
+
+    fn entry(tool: &str, started_at: u64, signature: Option<&str>, mint: Option<&str>) -> JournalEntry {
+        JournalEntry {
+            tool: tool.to_string(),
+            started_at,
+            finished_at: started_at + 10,
+            arguments: json!({ "amount": 1 }),
+            result: Some(json!({ "ok": true })),
+            error: None,
+            signatures: signature.into_iter().map(str::to_string).collect(),
+            fee: signature.map(|_| 5_000),
+            mints: mint.into_iter().map(str::to_string).collect(),
+            dry_run: false,
+        }
+    }
+
+    fn check_queries(journal: &dyn Journal) {
+        journal.append(&entry("transfer", 100, Some("sig1"), None)).unwrap();
+        journal.append(&entry("trade", 200, Some("sig2"), Some("USDC"))).unwrap();
+        journal.append(&entry("fetch_price", 300, None, Some("USDC"))).unwrap();
+
+        let tools = |query: JournalQuery| -> Vec<String> {
+            journal.query(&query).unwrap().into_iter().map(|entry| entry.tool).collect()
+        };
+        assert_eq!(tools(JournalQuery::default()), ["transfer", "trade", "fetch_price"]);
+        assert_eq!(tools(JournalQuery { from: Some(200), to: Some(300), ..Default::default() }), ["trade"]);
+        assert_eq!(tools(JournalQuery { tool: Some("transfer".to_string()), ..Default::default() }), ["transfer"]);
+        assert_eq!(
+            tools(JournalQuery { mint: Some("USDC".to_string()), ..Default::default() }),
+            ["trade", "fetch_price"]
+        );
+        assert_eq!(tools(JournalQuery { signature: Some("sig2".to_string()), ..Default::default() }), ["trade"]);
+        assert_eq!(tools(JournalQuery { limit: Some(1), ..Default::default() }), ["transfer"]);
+
+        let entries = journal.query(&JournalQuery { tool: Some("trade".to_string()), ..Default::default() }).unwrap();
+        assert_eq!(entries, vec![entry("trade", 200, Some("sig2"), Some("USDC"))]);
+    }
+
+    #[test]
+    fn test_jsonl_journal() {
+        let path = std::env::temp_dir().join(format!("solagent_test_journal_{}.jsonl", now()));
+        let journal = JsonlJournal::open(&path).unwrap();
+        check_queries(&journal);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_journal() {
+        let journal = SqliteJournal::open_in_memory().unwrap();
+        check_queries(&journal);
+
+        let connection = journal.connection.lock().unwrap();
+        assert!(connection.execute("DELETE FROM journal", []).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_record_call() {
+        let path = std::env::temp_dir().join(format!("solagent_test_record_call_{}.jsonl", now()));
+        let journal = JsonlJournal::open(&path).unwrap();
+
+        let receipt = TransactionReceipt {
+            signature: "sig".to_string(),
+            slot: 1,
+            fee: Some(5_000),
+            compute_units: None,
+            logs: vec![],
+        };
+        let changes = vec![BalanceChange {
+            account: "ata".to_string(),
+            mint: Some("BONK".to_string()),
+            owner: Some("wallet".to_string()),
+            pre: 10,
+            post: 0,
+            delta: -10,
+        }];
+        // Journaled calls stay `Sync`
+        fn sync<F: Future + Sync>(future: F) -> F {
+            future
+        }
+        let result: Result<u64, String> =
+            sync(record_call(&journal, "trade", false, json!({ "input_mint": "USDC" }), |_| async {
+                assert!(record_transaction(&TransactionOutcome::Confirmed(receipt.clone()), &changes));
+                Ok(42)
+            }))
+            .await;
+        assert_eq!(result, Ok(42));
+        assert!(!record_transaction(&TransactionOutcome::Confirmed(receipt), &[]));
+
+        let _: Result<(), String> =
+            record_call(&journal, "transfer", true, json!({}), |_| async { Err("no funds".to_string()) }).await;
+
+        let entries = journal.query(&JournalQuery::default()).unwrap();
+        assert_eq!(entries[0].signatures, ["sig"]);
+        assert_eq!(entries[0].fee, Some(5_000));
+        assert_eq!(entries[0].mints, ["USDC", "BONK"]);
+        assert_eq!(entries[0].result, Some(json!(42)));
+        assert_eq!(entries[1].error.as_deref(), Some("no funds"));
+        assert!(entries[1].dry_run);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
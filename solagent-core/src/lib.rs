@@ -17,30 +17,286 @@
 //!
 //! This powerful toolkit simplifies agent-to-blockchain communication, offering a comprehensive suite of functions for tasks such as token operations, trading, and more. By leveraging solagent.rs, developers can seamlessly connect their AI agents to the Solana ecosystem, unlocking a world of possibilities for on-chain automation and intelligent decision-making.
 
+mod approval;
 mod config;
+mod error;
+mod http;
+mod journal;
+mod network;
+mod policy;
+mod sender;
+mod tool;
+mod utils;
+mod wallets;
 
-use config::Config;
-pub use config::ConfigBuilder;
+pub use approval::{
+    Approval, Approver, DecodedInstruction, PendingTransaction, RejectAll, TerminalApprover, ThresholdApprover,
+};
+pub use config::{Config, ConfigBuilder, ConfigError, WalletSource};
+pub use error::SolagentError;
+pub use http::{HttpProvider, HttpProviderBuilder, Service};
+#[cfg(feature = "sqlite")]
+pub use journal::SqliteJournal;
+pub use journal::{Journal, JournalEntry, JournalError, JournalQuery, JsonlJournal};
+pub use network::Network;
+pub use policy::{Outflow, Policy, PolicyEngine, PolicyViolation, TransactionIntent};
+pub use sender::{
+    BalanceChange, PriorityFeeStrategy, SendOptions, SimulationReport, TransactionOutcome, TransactionReceipt,
+    TransactionSender, MAX_COMPUTE_UNIT_LIMIT,
+};
+pub use tool::{RigTool, SolagentTool, ToolCategory, ToolFuture, ToolRegistry};
+pub use utils::parse_pubkey;
+pub use wallets::{Wallets, DEFAULT_WALLET};
 
+pub use async_trait::async_trait;
 pub use rig;
+pub use solagent_parameters::{ArgumentError, InvalidArguments};
+pub use solagent_wallet_solana::{AgentSigner, RemoteSigner, Wallet};
 pub use solana_client;
 pub use solana_program;
 pub use solana_sdk;
 
-use solagent_wallet_solana::Wallet;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use solagent_parameters::{validate, validate_args, ToolParameters};
+use std::{fmt::Display, future::Future, sync::Arc};
 
 /// Represents a Solana agent that interacts with the blockchain.
 /// Provides a unified interface for token operations, NFT management, trading and more
 pub struct SolanaAgentKit {
-    pub wallet: Wallet,
+    /// Named wallets the plugins can act with; see [`SolanaAgentKit::wallet`].
+    pub wallets: Wallets,
     pub config: Config,
-    pub connection: solana_client::rpc_client::RpcClient,
+    /// The cluster `connection` points at.
+    pub network: Network,
+    /// HTTP client and endpoints used by the plugins to reach third-party APIs.
+    pub http: HttpProvider,
+    /// How plugins price, send and confirm their transactions.
+    pub send_options: SendOptions,
+    /// Limits checked by the sender before any transaction is signed.
+    pub policy: PolicyEngine,
+    /// Reviews every transaction before it is signed and broadcast, when set.
+    pub approver: Option<Arc<dyn Approver>>,
+    /// Records every tool call and transaction, when set.
+    pub journal: Option<Arc<dyn Journal>>,
+    /// Nonblocking RPC client, safe to await from inside the tokio runtime.
+    pub connection: solana_client::nonblocking::rpc_client::RpcClient,
 }
 
 impl SolanaAgentKit {
-    pub fn new(wallet: Wallet, rpc_url: &str, config: Config) -> Self {
-        let connection = solana_client::rpc_client::RpcClient::new(rpc_url);
-        Self { wallet, config, connection }
+    /// Creates a kit for `rpc_url`, inferring the cluster from the url.
+    ///
+    /// `wallet` becomes the [`DEFAULT_WALLET`]; add more with [`SolanaAgentKit::with_wallet`].
+    pub fn new(wallet: impl AgentSigner + 'static, rpc_url: &str, config: Config) -> Self {
+        Self::new_with_network(wallet, rpc_url, Network::from_rpc_url(rpc_url), config)
+    }
+
+    /// Creates a kit for `rpc_url` on an explicitly chosen cluster, e.g. a private mainnet endpoint.
+    pub fn new_with_network(
+        wallet: impl AgentSigner + 'static,
+        rpc_url: &str,
+        network: Network,
+        config: Config,
+    ) -> Self {
+        Self::new_with_wallets(Wallets::new(wallet), rpc_url, network, config)
+    }
+
+    /// Creates a kit acting with a whole set of wallets.
+    pub fn new_with_wallets(wallets: Wallets, rpc_url: &str, network: Network, config: Config) -> Self {
+        let connection = solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url.to_string());
+        Self {
+            wallets,
+            config,
+            network,
+            http: HttpProvider::default(),
+            send_options: SendOptions::default(),
+            policy: PolicyEngine::default(),
+            approver: None,
+            journal: None,
+            connection,
+        }
+    }
+
+    /// The wallet under `label`, or the default wallet when `label` is `None`.
+    pub fn wallet(&self, label: Option<&str>) -> Result<&dyn AgentSigner, SolagentError> {
+        self.wallets.get(label)
+    }
+
+    /// Adds `wallet` under `label`, so plugins can act with it when a call names it.
+    pub fn with_wallet(mut self, label: impl Into<String>, wallet: impl AgentSigner + 'static) -> Self {
+        self.wallets.insert(label, wallet);
+        self
+    }
+
+    /// Adds `wallet` under `label` and makes it pay the fees of the transactions the kit builds.
+    pub fn with_fee_payer(mut self, label: impl Into<String>, wallet: impl AgentSigner + 'static) -> Self {
+        self.wallets.insert_fee_payer(label, wallet);
+        self
+    }
+
+    /// Replaces the HTTP client and endpoints used by the plugins.
+    pub fn with_http(mut self, http: HttpProvider) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Replaces the settings used to price, send and confirm transactions.
+    pub fn with_send_options(mut self, send_options: SendOptions) -> Self {
+        self.send_options = send_options;
+        self
+    }
+
+    /// Replaces the spending policy; what was spent under the previous one is forgotten.
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = PolicyEngine::new(policy);
+        self
+    }
+
+    /// Submits every transaction to `approver` before it is signed and broadcast.
+    pub fn with_approver(mut self, approver: impl Approver + 'static) -> Self {
+        self.approver = Some(Arc::new(approver));
+        self
+    }
+
+    /// Records every journaled call and every transaction in `journal`.
+    pub fn with_journal(mut self, journal: impl Journal + 'static) -> Self {
+        self.journal = Some(Arc::new(journal));
+        self
+    }
+
+    /// Runs `call` with `args` and writes it to the journal under `tool`, along with its result or error
+    /// and the signatures, fees and mints of the transactions it sent.
+    ///
+    /// Without a journal, `call` simply runs.
+    pub async fn journaled<A, T, E, F, Fut>(&self, tool: &str, args: A, call: F) -> Result<T, E>
+    where
+        A: Serialize,
+        T: Serialize,
+        E: Display,
+        F: FnOnce(A) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        match &self.journal {
+            Some(journal) => journal::record_call(journal.as_ref(), tool, self.is_dry_run(), args, call).await,
+            None => call(args).await,
+        }
+    }
+
+    /// Runs a tool call: checks `args` against the tool's schema, then runs `call` with them, journaled
+    /// under `tool`.
+    ///
+    /// Arguments the model got wrong, such as a malformed address or an amount out of range, are
+    /// returned as [`SolagentError::InvalidArguments`] without calling the plugin; the rejected call is
+    /// journaled as well.
+    pub async fn call_tool<A, T, E, F, Fut>(&self, tool: &str, args: A, call: F) -> Result<T, E>
+    where
+        A: Serialize + ToolParameters,
+        T: Serialize,
+        E: Display + From<SolagentError>,
+        F: FnOnce(A) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        self.journaled(tool, args, |args| async move {
+            validate_args(&args).map_err(SolagentError::from)?;
+            call(args).await
+        })
+        .await
+    }
+
+    /// Runs a tool call on the JSON arguments the model sent: checks them against the schema of `A`, then
+    /// runs `call` with them, journaled under `tool`. This is the body of [`SolagentTool::invoke`].
+    pub async fn invoke_tool<A, T, F, Fut>(&self, tool: &str, args: Value, call: F) -> Result<Value, SolagentError>
+    where
+        A: DeserializeOwned + ToolParameters,
+        T: Serialize,
+        F: FnOnce(A) -> Fut,
+        Fut: Future<Output = Result<T, SolagentError>>,
+    {
+        self.journaled(tool, args, |args| async move {
+            validate(&A::schema(), &args)?;
+            let args = serde_json::from_value(args).map_err(|e| InvalidArguments {
+                errors: vec![ArgumentError { field: String::new(), message: e.to_string() }],
+            })?;
+            let output = call(args).await?;
+            serde_json::to_value(output).map_err(|e| SolagentError::Decode(format!("the output of `{}`: {}", tool, e)))
+        })
+        .await
+    }
+
+    /// Turns dry-run mode on or off: write actions then only simulate their transactions.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.config.dry_run = Some(dry_run);
+        self
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.config.dry_run.unwrap_or(false)
+    }
+
+    /// The pipeline every plugin sends its transactions through.
+    pub fn sender(&self) -> TransactionSender<'_> {
+        TransactionSender::new(self)
+    }
+
+    /// Creates a kit entirely from `config`, loading the wallet from `config.wallet`.
+    ///
+    /// The RPC url falls back to the public endpoint of `config.network` when `config.rpc_url` is unset.
+    pub fn from_config(config: Config) -> Result<Self, ConfigError> {
+        let rpc_url = match (&config.rpc_url, &config.network) {
+            (Some(url), _) => url.clone(),
+            (None, Some(network)) => network.rpc_url().to_string(),
+            (None, None) => return Err(ConfigError::Missing("rpc_url")),
+        };
+        let network = config.network.clone().unwrap_or_else(|| Network::from_rpc_url(&rpc_url));
+        let wallet = config.wallet.as_ref().ok_or(ConfigError::Missing("wallet"))?.load()?;
+
+        let mut wallets = Wallets::new(wallet);
+        for (label, source) in config.wallets.iter().flatten() {
+            wallets.insert(label.clone(), source.load()?);
+        }
+        if let Some(label) = &config.fee_payer {
+            wallets
+                .set_fee_payer(label)
+                .map_err(|e| ConfigError::Invalid { location: "fee_payer".to_string(), message: e.to_string() })?;
+        }
+
+        let priority_fee = config.priority_fee_lamports.map(PriorityFeeStrategy::max_lamports);
+        let mut kit = Self::new_with_wallets(wallets, &rpc_url, network, config);
+        if let Some(priority_fee) = priority_fee {
+            kit.send_options.priority_fee = priority_fee;
+        }
+        Ok(kit)
+    }
+
+    /// Refuses `action` when the kit is connected to a known test cluster.
+    ///
+    /// `Custom` endpoints are let through, since their cluster cannot be told.
+    pub fn require_mainnet(&self, action: &str) -> Result<(), SolagentError> {
+        if self.network.is_test_cluster() {
+            return Err(SolagentError::UnsupportedNetwork {
+                action: action.to_string(),
+                network: self.network.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Refuses `action` when the kit is connected to mainnet.
+    pub fn forbid_mainnet(&self, action: &str) -> Result<(), SolagentError> {
+        if self.network.is_mainnet() {
+            return Err(SolagentError::UnsupportedNetwork {
+                action: action.to_string(),
+                network: self.network.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a blocking RPC client pointed at the same endpoint and commitment as `connection`.
+    ///
+    /// Only meant for sync callers living outside of an async runtime; plugins must use `connection`.
+    pub fn blocking_connection(&self) -> solana_client::rpc_client::RpcClient {
+        solana_client::rpc_client::RpcClient::new_with_commitment(self.connection.url(), self.connection.commitment())
     }
 }
 
@@ -56,7 +312,27 @@ mod tests {
         let config = Config { openai_api_key: Some("your_api_key".to_string()), ..Default::default() };
         let agent = SolanaAgentKit::new(wallet, rpc_url, config);
 
-        assert_eq!(agent.wallet.pubkey, wallet_pubkey);
+        assert_eq!(agent.wallets.default_wallet().pubkey(), wallet_pubkey);
+        assert_eq!(agent.network, Network::Mainnet);
+    }
+
+    #[test]
+    fn test_solana_agent_kit_network_guards() {
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default());
+        assert!(agent.forbid_mainnet("request_faucet_funds").is_ok());
+        assert!(matches!(agent.require_mainnet("trade"), Err(SolagentError::UnsupportedNetwork { .. })));
+
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.mainnet-beta.solana.com", Config::default());
+        assert!(agent.require_mainnet("trade").is_ok());
+        assert!(agent.forbid_mainnet("request_faucet_funds").is_err());
+
+        let agent = SolanaAgentKit::new_with_network(
+            Wallet::new(),
+            "https://rpc.example.com",
+            Network::Mainnet,
+            Config::default(),
+        );
+        assert_eq!(agent.network, Network::Mainnet);
     }
 
     #[test]
@@ -71,6 +347,157 @@ mod tests {
 
         assert_eq!(agent.config.openai_api_key, Some("test_api_key".to_string()));
         assert_eq!(agent.config.jupiter_fee_bps, Some(500));
-        assert_eq!(agent.wallet.pubkey, wallet_pubkey);
+        assert_eq!(agent.wallets.default_wallet().pubkey(), wallet_pubkey);
+    }
+
+    #[test]
+    fn test_solana_agent_kit_from_config() {
+        let config = ConfigBuilder::default().network(Network::Devnet).build();
+        assert!(matches!(SolanaAgentKit::from_config(config), Err(ConfigError::Missing("wallet"))));
+
+        let config = ConfigBuilder::default().wallet(WalletSource::Env("TEST_FROM_CONFIG_KEY".to_string())).build();
+        assert!(matches!(SolanaAgentKit::from_config(config), Err(ConfigError::Missing("rpc_url"))));
+
+        let wallet = Wallet::new();
+        std::env::set_var("TEST_FROM_CONFIG_KEY", wallet.to_base58());
+        let config = ConfigBuilder::default()
+            .network(Network::Devnet)
+            .wallet(WalletSource::Env("TEST_FROM_CONFIG_KEY".to_string()))
+            .build();
+        let agent = SolanaAgentKit::from_config(config).unwrap();
+        std::env::remove_var("TEST_FROM_CONFIG_KEY");
+
+        assert_eq!(agent.wallet(None).unwrap().pubkey(), wallet.pubkey);
+        assert_eq!(agent.network, Network::Devnet);
+        assert_eq!(agent.connection.url(), "https://api.devnet.solana.com");
+    }
+
+    #[test]
+    fn test_solana_agent_kit_from_config_priority_fee() {
+        let path = std::env::temp_dir().join("solagent_test_from_config_priority_fee.toml");
+        let profiles = r#"
+            network = "devnet"
+            wallet = { env = "TEST_PRIORITY_FEE_KEY" }
+
+            [profiles.fast]
+            priority_fee_lamports = 14000
+        "#;
+        std::fs::write(&path, profiles).unwrap();
+        std::env::set_var("TEST_PRIORITY_FEE_KEY", Wallet::new().to_base58());
+
+        let agent = SolanaAgentKit::from_config(Config::from_file_profile(&path, "fast").unwrap()).unwrap();
+        // 14,000 lamports over the largest compute unit limit
+        assert_eq!(agent.send_options.priority_fee, PriorityFeeStrategy::Fixed(10_000));
+
+        let agent = SolanaAgentKit::from_config(Config::from_file(&path).unwrap()).unwrap();
+        assert_eq!(agent.send_options.priority_fee, PriorityFeeStrategy::default());
+
+        std::env::remove_var("TEST_PRIORITY_FEE_KEY");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_solana_agent_kit_wallets() {
+        let (treasury, payer) = (Wallet::new(), Wallet::new());
+        let (treasury_pubkey, payer_pubkey) = (treasury.pubkey, payer.pubkey);
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default())
+            .with_wallet("treasury", treasury)
+            .with_fee_payer("payer", payer);
+
+        assert_eq!(agent.wallet(Some("treasury")).unwrap().pubkey(), treasury_pubkey);
+        assert_eq!(agent.wallets.fee_payer().pubkey(), payer_pubkey);
+        assert!(matches!(agent.wallet(Some("hot")), Err(SolagentError::UnknownWallet(_))));
+
+        std::env::set_var("TEST_WALLETS_DEFAULT_KEY", Wallet::new().to_base58());
+        std::env::set_var("TEST_WALLETS_TREASURY_KEY", Wallet::new().to_base58());
+        let config = ConfigBuilder::default()
+            .network(Network::Devnet)
+            .wallet(WalletSource::Env("TEST_WALLETS_DEFAULT_KEY".to_string()))
+            .named_wallet("treasury".to_string(), WalletSource::Env("TEST_WALLETS_TREASURY_KEY".to_string()))
+            .fee_payer("treasury".to_string())
+            .build();
+        let agent = SolanaAgentKit::from_config(config.clone()).unwrap();
+        assert_eq!(agent.wallets.fee_payer().pubkey(), agent.wallet(Some("treasury")).unwrap().pubkey());
+
+        let config = Config { fee_payer: Some("hot".to_string()), ..config };
+        assert!(matches!(SolanaAgentKit::from_config(config), Err(ConfigError::Invalid { .. })));
+        std::env::remove_var("TEST_WALLETS_DEFAULT_KEY");
+        std::env::remove_var("TEST_WALLETS_TREASURY_KEY");
+    }
+
+    #[test]
+    fn test_solana_agent_kit_with_http() {
+        let http = HttpProvider::builder().base_url(Service::Pyth, "http://localhost:9000").build().unwrap();
+        let agent =
+            SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default()).with_http(http);
+
+        assert_eq!(agent.http.base_url(Service::Pyth), "http://localhost:9000");
+        assert_eq!(agent.http.base_url(Service::Birdeye), "https://public-api.birdeye.so");
+    }
+
+    #[test]
+    fn test_solana_agent_kit_dry_run() {
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default());
+        assert!(!agent.is_dry_run());
+        assert!(agent.with_dry_run(true).is_dry_run());
+
+        let config = ConfigBuilder::default().dry_run(true).build();
+        assert!(SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", config).is_dry_run());
+    }
+
+    #[test]
+    fn test_solana_agent_kit_with_policy() {
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default());
+        assert!(!agent.policy.is_kill_switch_engaged());
+
+        let policy = Policy { max_slippage_bps: Some(50), kill_switch: true, ..Default::default() };
+        let agent = agent.with_policy(policy);
+        assert!(agent.policy.is_kill_switch_engaged());
+        assert!(agent.policy.check_slippage(100).is_err());
+    }
+
+    #[test]
+    fn test_solana_agent_kit_with_approver() {
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default());
+        assert!(agent.approver.is_none());
+        assert!(agent.with_approver(ThresholdApprover::new(1_000, RejectAll)).approver.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_solana_agent_kit_call_tool() {
+        #[derive(Serialize, ToolParameters)]
+        struct TransferArgs {
+            #[parameters(address)]
+            to: String,
+            #[parameters(exclusive_minimum = 0)]
+            amount: u64,
+        }
+
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default());
+        let to = Wallet::new().pubkey.to_string();
+        let result: Result<u64, SolagentError> =
+            agent.call_tool("transfer", TransferArgs { to, amount: 1 }, |args| async move { Ok(args.amount) }).await;
+        assert_eq!(result.unwrap(), 1);
+
+        let args = TransferArgs { to: "not-an-address".to_string(), amount: 0 };
+        let result: Result<u64, SolagentError> =
+            agent.call_tool("transfer", args, |_| async { panic!("called with invalid arguments") }).await;
+        match result {
+            Err(SolagentError::InvalidArguments(invalid)) => {
+                let fields: Vec<_> = invalid.errors.iter().map(|error| error.field.as_str()).collect();
+                assert_eq!(fields, ["amount", "to"]);
+            }
+            _ => panic!("expected invalid arguments"),
+        }
+    }
+
+    #[test]
+    fn test_solana_agent_kit_blocking_connection() {
+        let rpc_url = "https://api.devnet.solana.com";
+        let agent = SolanaAgentKit::new(Wallet::new(), rpc_url, Config::default());
+
+        let blocking = agent.blocking_connection();
+        assert_eq!(blocking.url(), rpc_url);
+        assert_eq!(blocking.commitment(), agent.connection.commitment());
     }
 }
@@ -0,0 +1,133 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// The Solana cluster a `SolanaAgentKit` is connected to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Network {
+    #[serde(alias = "mainnet-beta")]
+    Mainnet,
+    Devnet,
+    Testnet,
+    Localnet,
+    /// Any other endpoint; the cluster behind it is unknown.
+    Custom(String),
+}
+
+impl Network {
+    /// Guesses the cluster from an RPC url, falling back to `Custom` when nothing matches.
+    pub fn from_rpc_url(rpc_url: &str) -> Self {
+        let url = rpc_url.to_ascii_lowercase();
+        if url.contains("mainnet") {
+            Network::Mainnet
+        } else if url.contains("devnet") {
+            Network::Devnet
+        } else if url.contains("testnet") {
+            Network::Testnet
+        } else if url.contains("localhost") || url.contains("127.0.0.1") || url.contains("0.0.0.0") {
+            Network::Localnet
+        } else {
+            Network::Custom(rpc_url.to_string())
+        }
+    }
+
+    /// The public RPC endpoint of the cluster.
+    pub fn rpc_url(&self) -> &str {
+        match self {
+            Network::Mainnet => "https://api.mainnet-beta.solana.com",
+            Network::Devnet => "https://api.devnet.solana.com",
+            Network::Testnet => "https://api.testnet.solana.com",
+            Network::Localnet => "http://127.0.0.1:8899",
+            Network::Custom(url) => url,
+        }
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        matches!(self, Network::Mainnet)
+    }
+
+    /// `true` for clusters known not to be mainnet. `Custom` endpoints are neither.
+    pub fn is_test_cluster(&self) -> bool {
+        matches!(self, Network::Devnet | Network::Testnet | Network::Localnet)
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Network::Mainnet => write!(f, "mainnet-beta"),
+            Network::Devnet => write!(f, "devnet"),
+            Network::Testnet => write!(f, "testnet"),
+            Network::Localnet => write!(f, "localnet"),
+            Network::Custom(url) => write!(f, "custom ({})", url),
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = String;
+
+    /// Parses a cluster name, or takes an `http(s)://` url as a `Custom` endpoint.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" | "mainnet-beta" => Ok(Network::Mainnet),
+            "devnet" => Ok(Network::Devnet),
+            "testnet" => Ok(Network::Testnet),
+            "localnet" | "localhost" => Ok(Network::Localnet),
+            _ if s.starts_with("http://") || s.starts_with("https://") => Ok(Network::Custom(s.to_string())),
+            _ => Err(format!("unknown network `{}`, expected mainnet, devnet, testnet, localnet or an url", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_from_rpc_url() {
+        assert_eq!(Network::from_rpc_url("https://api.mainnet-beta.solana.com"), Network::Mainnet);
+        assert_eq!(Network::from_rpc_url("https://mainnet.helius-rpc.com/?api-key=x"), Network::Mainnet);
+        assert_eq!(Network::from_rpc_url("https://api.devnet.solana.com"), Network::Devnet);
+        assert_eq!(Network::from_rpc_url("https://api.testnet.solana.com"), Network::Testnet);
+        assert_eq!(Network::from_rpc_url("http://localhost:8899"), Network::Localnet);
+        assert_eq!(
+            Network::from_rpc_url("https://rpc.example.com"),
+            Network::Custom("https://rpc.example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_network_kind() {
+        assert!(Network::Mainnet.is_mainnet());
+        assert!(Network::Devnet.is_test_cluster());
+        assert!(Network::Localnet.is_test_cluster());
+
+        let custom = Network::Custom("https://rpc.example.com".to_string());
+        assert!(!custom.is_mainnet());
+        assert!(!custom.is_test_cluster());
+        assert_eq!(custom.rpc_url(), "https://rpc.example.com");
+    }
+
+    #[test]
+    fn test_network_from_str() {
+        assert_eq!("mainnet-beta".parse::<Network>(), Ok(Network::Mainnet));
+        assert_eq!("Devnet".parse::<Network>(), Ok(Network::Devnet));
+        assert_eq!("http://10.0.0.1:8899".parse::<Network>(), Ok(Network::Custom("http://10.0.0.1:8899".to_string())));
+        assert!("mainet".parse::<Network>().is_err());
+    }
+}
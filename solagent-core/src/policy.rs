@@ -0,0 +1,425 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::BalanceChange;
+use solana_sdk::{compute_budget, pubkey::Pubkey};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Limits enforced on every transaction before the agent signs it.
+///
+/// Amounts are counted from the simulated balance changes of the agent's wallet, so fees and rent count
+/// as spent SOL, and they are in base units: lamports for SOL, raw amounts for tokens.
+/// The default policy allows everything.
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+    /// Refuses every transaction while set.
+    pub kill_switch: bool,
+    pub max_lamports_per_transaction: Option<u64>,
+    /// Limit over the last 24 hours.
+    pub max_lamports_per_day: Option<u64>,
+    pub max_tokens_per_transaction: HashMap<Pubkey, u64>,
+    /// Limits over the last 24 hours, per mint.
+    pub max_tokens_per_day: HashMap<Pubkey, u64>,
+    /// When set, only these accounts may receive funds.
+    pub recipient_allowlist: Option<HashSet<Pubkey>>,
+    pub recipient_denylist: HashSet<Pubkey>,
+    /// When set, transactions may only invoke these programs. The compute budget program is always allowed.
+    pub allowed_programs: Option<HashSet<Pubkey>>,
+    pub max_slippage_bps: Option<u16>,
+}
+
+/// Why a transaction was refused by the [`Policy`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PolicyViolation {
+    #[error("the kill switch is engaged, no transaction may be signed")]
+    KillSwitchEngaged,
+
+    #[error("the transaction would spend {amount} {}, above the per-transaction limit of {limit}", unit(.mint))]
+    TransactionLimit { mint: Option<String>, amount: u64, limit: u64 },
+
+    #[error("the transaction would spend {amount} {} after {spent} in the last 24 hours, above the daily limit of {limit}", unit(.mint))]
+    DailyLimit { mint: Option<String>, amount: u64, spent: u64, limit: u64 },
+
+    #[error("recipient {0} is on the denylist")]
+    RecipientDenied(String),
+
+    #[error("recipient {0} is not on the allowlist")]
+    RecipientNotAllowed(String),
+
+    #[error("program {0} is not in the allowed programs")]
+    ProgramNotAllowed(String),
+
+    #[error("slippage of {requested_bps} bps is above the maximum of {limit_bps} bps")]
+    SlippageTooHigh { requested_bps: u16, limit_bps: u16 },
+}
+
+fn unit(mint: &Option<String>) -> String {
+    match mint {
+        Some(mint) => format!("units of token {}", mint),
+        None => "lamports".to_string(),
+    }
+}
+
+/// What a plugin's transaction is meant to do, declared to the sender so it can be checked before signing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionIntent {
+    /// Name of the plugin action, e.g. `transfer`.
+    pub action: &'static str,
+    /// Accounts the transaction sends funds to, checked against the recipient lists.
+    pub recipients: Vec<Pubkey>,
+}
+
+impl TransactionIntent {
+    pub fn new(action: &'static str) -> Self {
+        Self { action, recipients: vec![] }
+    }
+
+    pub fn recipient(mut self, recipient: Pubkey) -> Self {
+        self.recipients.push(recipient);
+        self
+    }
+}
+
+/// Amount leaving the agent's wallet in one transaction; `mint` is `None` for lamports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outflow {
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+impl Outflow {
+    /// Nets the simulated balance changes of the accounts of `wallets`, per mint, and keeps what decreased.
+    ///
+    /// Funds moved between `wallets` cancel out.
+    pub fn from_balance_changes(changes: &[BalanceChange], wallets: &[Pubkey]) -> Vec<Outflow> {
+        let wallets: Vec<String> = wallets.iter().map(Pubkey::to_string).collect();
+        let is_wallet = |account: Option<&String>| account.is_some_and(|account| wallets.contains(account));
+        let mut net: Vec<(Option<Pubkey>, i128)> = vec![];
+        for change in changes {
+            let mint = match &change.mint {
+                None if is_wallet(Some(&change.account)) => None,
+                Some(mint) if is_wallet(change.owner.as_ref()) => match Pubkey::from_str(mint) {
+                    Ok(mint) => Some(mint),
+                    Err(_) => continue,
+                },
+                _ => continue,
+            };
+            match net.iter_mut().find(|(m, _)| *m == mint) {
+                Some((_, delta)) => *delta += change.delta,
+                None => net.push((mint, change.delta)),
+            }
+        }
+
+        net.into_iter()
+            .filter(|(_, delta)| *delta < 0)
+            .map(|(mint, delta)| Outflow { mint, amount: u64::try_from(-delta).unwrap_or(u64::MAX) })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Spend {
+    reservation: u64,
+    at: Instant,
+    outflow: Outflow,
+}
+
+/// Enforces a [`Policy`] and keeps track of what was spent over the last 24 hours.
+///
+/// Spending is reserved when a transaction is authorized and released again if it never lands,
+/// so concurrent tool calls cannot slip past the daily limits together.
+#[derive(Debug, Default)]
+pub struct PolicyEngine {
+    policy: Policy,
+    kill_switch: AtomicBool,
+    spent: Mutex<VecDeque<Spend>>,
+    next_reservation: AtomicU64,
+}
+
+impl PolicyEngine {
+    pub fn new(policy: Policy) -> Self {
+        let kill_switch = AtomicBool::new(policy.kill_switch);
+        Self { policy, kill_switch, ..Default::default() }
+    }
+
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Stops the agent from signing anything until [`PolicyEngine::release_kill_switch`] is called.
+    pub fn engage_kill_switch(&self) {
+        self.kill_switch.store(true, Ordering::SeqCst);
+    }
+
+    pub fn release_kill_switch(&self) {
+        self.kill_switch.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_kill_switch_engaged(&self) -> bool {
+        self.kill_switch.load(Ordering::SeqCst)
+    }
+
+    /// Checks the slippage a swap is about to request.
+    pub fn check_slippage(&self, slippage_bps: u16) -> Result<(), PolicyViolation> {
+        match self.policy.max_slippage_bps {
+            Some(limit_bps) if slippage_bps > limit_bps => {
+                Err(PolicyViolation::SlippageTooHigh { requested_bps: slippage_bps, limit_bps })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks a transaction without reserving anything, e.g. for a dry run.
+    pub fn check(
+        &self,
+        intent: &TransactionIntent,
+        programs: &[Pubkey],
+        outflows: &[Outflow],
+    ) -> Result<(), PolicyViolation> {
+        let mut spent = self.spent.lock().unwrap_or_else(|e| e.into_inner());
+        self.evaluate(&mut spent, Instant::now(), intent, programs, outflows)
+    }
+
+    /// Checks a transaction and reserves its outflows against the daily limits.
+    ///
+    /// Returns a reservation to [`PolicyEngine::release`] if the transaction does not land.
+    pub fn authorize(
+        &self,
+        intent: &TransactionIntent,
+        programs: &[Pubkey],
+        outflows: &[Outflow],
+    ) -> Result<u64, PolicyViolation> {
+        self.authorize_at(Instant::now(), intent, programs, outflows)
+    }
+
+    /// Gives back the spending reserved by [`PolicyEngine::authorize`].
+    pub fn release(&self, reservation: u64) {
+        let mut spent = self.spent.lock().unwrap_or_else(|e| e.into_inner());
+        spent.retain(|spend| spend.reservation != reservation);
+    }
+
+    fn authorize_at(
+        &self,
+        now: Instant,
+        intent: &TransactionIntent,
+        programs: &[Pubkey],
+        outflows: &[Outflow],
+    ) -> Result<u64, PolicyViolation> {
+        let mut spent = self.spent.lock().unwrap_or_else(|e| e.into_inner());
+        self.evaluate(&mut spent, now, intent, programs, outflows)?;
+
+        let reservation = self.next_reservation.fetch_add(1, Ordering::SeqCst);
+        spent.extend(outflows.iter().map(|outflow| Spend { reservation, at: now, outflow: *outflow }));
+        Ok(reservation)
+    }
+
+    fn evaluate(
+        &self,
+        spent: &mut VecDeque<Spend>,
+        now: Instant,
+        intent: &TransactionIntent,
+        programs: &[Pubkey],
+        outflows: &[Outflow],
+    ) -> Result<(), PolicyViolation> {
+        let policy = &self.policy;
+        if self.is_kill_switch_engaged() {
+            return Err(PolicyViolation::KillSwitchEngaged);
+        }
+
+        for recipient in &intent.recipients {
+            if policy.recipient_denylist.contains(recipient) {
+                return Err(PolicyViolation::RecipientDenied(recipient.to_string()));
+            }
+            if policy.recipient_allowlist.as_ref().is_some_and(|allowlist| !allowlist.contains(recipient)) {
+                return Err(PolicyViolation::RecipientNotAllowed(recipient.to_string()));
+            }
+        }
+
+        if let Some(allowed) = &policy.allowed_programs {
+            if let Some(program) =
+                programs.iter().find(|program| **program != compute_budget::id() && !allowed.contains(program))
+            {
+                return Err(PolicyViolation::ProgramNotAllowed(program.to_string()));
+            }
+        }
+
+        while spent.front().is_some_and(|spend| now.saturating_duration_since(spend.at) >= DAY) {
+            spent.pop_front();
+        }
+
+        for outflow in outflows {
+            let (per_transaction, per_day) = match &outflow.mint {
+                None => (policy.max_lamports_per_transaction, policy.max_lamports_per_day),
+                Some(mint) => {
+                    (policy.max_tokens_per_transaction.get(mint).copied(), policy.max_tokens_per_day.get(mint).copied())
+                }
+            };
+            let mint = outflow.mint.map(|mint| mint.to_string());
+
+            if let Some(limit) = per_transaction.filter(|limit| outflow.amount > *limit) {
+                return Err(PolicyViolation::TransactionLimit { mint, amount: outflow.amount, limit });
+            }
+            if let Some(limit) = per_day {
+                let spent: u64 = spent
+                    .iter()
+                    .filter(|spend| spend.outflow.mint == outflow.mint)
+                    .map(|spend| spend.outflow.amount)
+                    .fold(0, u64::saturating_add);
+                if spent.saturating_add(outflow.amount) > limit {
+                    return Err(PolicyViolation::DailyLimit { mint, amount: outflow.amount, spent, limit });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sol(amount: u64) -> Outflow {
+        Outflow { mint: None, amount }
+    }
+
+    #[test]
+    fn test_policy_default_allows_everything() {
+        let engine = PolicyEngine::default();
+        let intent = TransactionIntent::new("transfer").recipient(Pubkey::new_unique());
+        assert!(engine.authorize(&intent, &[Pubkey::new_unique()], &[sol(u64::MAX)]).is_ok());
+        assert!(engine.check_slippage(10_000).is_ok());
+    }
+
+    #[test]
+    fn test_policy_kill_switch() {
+        let engine = PolicyEngine::new(Policy { kill_switch: true, ..Default::default() });
+        let intent = TransactionIntent::new("transfer");
+        assert_eq!(engine.check(&intent, &[], &[]), Err(PolicyViolation::KillSwitchEngaged));
+
+        engine.release_kill_switch();
+        assert!(engine.check(&intent, &[], &[]).is_ok());
+        engine.engage_kill_switch();
+        assert!(engine.is_kill_switch_engaged());
+    }
+
+    #[test]
+    fn test_policy_recipients_and_programs() {
+        let (friend, stranger, scammer) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let program = Pubkey::new_unique();
+        let engine = PolicyEngine::new(Policy {
+            recipient_allowlist: Some(HashSet::from([friend, scammer])),
+            recipient_denylist: HashSet::from([scammer]),
+            allowed_programs: Some(HashSet::from([program])),
+            ..Default::default()
+        });
+
+        let check = |recipient, programs: &[Pubkey]| {
+            engine.check(&TransactionIntent::new("transfer").recipient(recipient), programs, &[])
+        };
+        assert!(check(friend, &[program, compute_budget::id()]).is_ok());
+        assert_eq!(check(scammer, &[program]), Err(PolicyViolation::RecipientDenied(scammer.to_string())));
+        assert_eq!(check(stranger, &[program]), Err(PolicyViolation::RecipientNotAllowed(stranger.to_string())));
+
+        let other = Pubkey::new_unique();
+        assert_eq!(check(friend, &[program, other]), Err(PolicyViolation::ProgramNotAllowed(other.to_string())));
+    }
+
+    #[test]
+    fn test_policy_amount_limits() {
+        let mint = Pubkey::new_unique();
+        let engine = PolicyEngine::new(Policy {
+            max_lamports_per_transaction: Some(1_000),
+            max_lamports_per_day: Some(2_500),
+            max_tokens_per_transaction: HashMap::from([(mint, 50)]),
+            ..Default::default()
+        });
+        let intent = TransactionIntent::new("transfer");
+        let now = Instant::now();
+
+        assert_eq!(
+            engine.authorize_at(now, &intent, &[], &[sol(1_001)]),
+            Err(PolicyViolation::TransactionLimit { mint: None, amount: 1_001, limit: 1_000 })
+        );
+        let error = engine.check(&intent, &[], &[Outflow { mint: Some(mint), amount: 60 }]).unwrap_err();
+        assert!(error.to_string().contains(&format!("60 units of token {}", mint)), "{}", error);
+
+        engine.authorize_at(now, &intent, &[], &[sol(1_000)]).unwrap();
+        let reservation = engine.authorize_at(now, &intent, &[], &[sol(1_000)]).unwrap();
+        assert_eq!(
+            engine.authorize_at(now, &intent, &[], &[sol(600)]),
+            Err(PolicyViolation::DailyLimit { mint: None, amount: 600, spent: 2_000, limit: 2_500 })
+        );
+
+        // A transaction that never landed gives its share back.
+        engine.release(reservation);
+        assert!(engine.authorize_at(now, &intent, &[], &[sol(600)]).is_ok());
+
+        // The window rolls over after a day.
+        assert!(engine.authorize_at(now + DAY, &intent, &[], &[sol(1_000)]).is_ok());
+    }
+
+    #[test]
+    fn test_policy_slippage() {
+        let engine = PolicyEngine::new(Policy { max_slippage_bps: Some(100), ..Default::default() });
+        assert!(engine.check_slippage(100).is_ok());
+        assert_eq!(
+            engine.check_slippage(300),
+            Err(PolicyViolation::SlippageTooHigh { requested_bps: 300, limit_bps: 100 })
+        );
+    }
+
+    #[test]
+    fn test_outflows_from_balance_changes() {
+        let (wallet, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mint = Pubkey::new_unique();
+        let change =
+            |account: &Pubkey, mint: Option<&Pubkey>, owner: Option<&Pubkey>, pre: u64, post: u64| BalanceChange {
+                account: account.to_string(),
+                mint: mint.map(Pubkey::to_string),
+                owner: owner.map(Pubkey::to_string),
+                pre,
+                post,
+                delta: post as i128 - pre as i128,
+            };
+
+        let changes = vec![
+            change(&wallet, None, None, 10_000, 4_000),
+            change(&other, None, None, 0, 5_000),
+            change(&Pubkey::new_unique(), Some(&mint), Some(&wallet), 100, 30),
+            change(&Pubkey::new_unique(), Some(&mint), Some(&wallet), 0, 20),
+            change(&Pubkey::new_unique(), Some(&mint), Some(&other), 0, 50),
+        ];
+        assert_eq!(
+            Outflow::from_balance_changes(&changes, &[wallet]),
+            vec![Outflow { mint: None, amount: 6_000 }, Outflow { mint: Some(mint), amount: 50 }]
+        );
+
+        // Moving funds between the agent's own wallets only costs what the other wallet did not receive.
+        assert_eq!(
+            Outflow::from_balance_changes(&changes, &[wallet, other]),
+            vec![Outflow { mint: None, amount: 1_000 }]
+        );
+    }
+}
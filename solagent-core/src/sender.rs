@@ -0,0 +1,813 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    journal, Approval, DecodedInstruction, Outflow, PendingTransaction, SolagentError, SolanaAgentKit,
+    TransactionIntent,
+};
+use serde::{Deserialize, Serialize};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_config::{
+    RpcSendTransactionConfig, RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig, RpcTransactionConfig,
+};
+use solana_sdk::{
+    account::Account,
+    commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::{Instruction, InstructionError},
+    message::{Message, VersionedMessage},
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    signer::SignerError,
+    system_program,
+    transaction::{Transaction, TransactionError, VersionedTransaction},
+};
+use std::time::Duration;
+
+/// The most compute units a single transaction may request.
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+const TOKEN_PROGRAM_IDS: [Pubkey; 2] =
+    [pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"), pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb")];
+
+/// How the compute units of a transaction are priced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriorityFeeStrategy {
+    /// No `SetComputeUnitPrice` instruction is added.
+    None,
+    /// A fixed price, in micro-lamports per compute unit.
+    Fixed(u64),
+    /// The `percentile` of the fees recently paid to write-lock the same accounts, capped at `max` micro-lamports.
+    Recent { percentile: u8, max: u64 },
+}
+
+impl PriorityFeeStrategy {
+    /// A fixed price at which no transaction pays more than `lamports` of priority fees, whatever its
+    /// compute units: how [`Config::priority_fee_lamports`](crate::Config::priority_fee_lamports) prices the
+    /// transactions of the sender.
+    pub fn max_lamports(lamports: u64) -> Self {
+        PriorityFeeStrategy::Fixed(lamports.saturating_mul(1_000_000) / MAX_COMPUTE_UNIT_LIMIT as u64)
+    }
+}
+
+impl Default for PriorityFeeStrategy {
+    fn default() -> Self {
+        PriorityFeeStrategy::Recent { percentile: 50, max: 100_000 }
+    }
+}
+
+/// Settings of the [`TransactionSender`], shared by every plugin of a `SolanaAgentKit`.
+#[derive(Debug, Clone)]
+pub struct SendOptions {
+    pub priority_fee: PriorityFeeStrategy,
+    /// Compute units requested on top of the simulated usage, in percent.
+    pub compute_unit_margin: u32,
+    /// Commitment a transaction must reach before its receipt is returned.
+    pub commitment: CommitmentConfig,
+    /// Delay between two broadcasts of the same transaction.
+    pub rebroadcast_interval: Duration,
+    /// How many times an expired transaction is signed again with a fresh blockhash.
+    pub max_blockhash_refreshes: u32,
+}
+
+impl Default for SendOptions {
+    fn default() -> Self {
+        Self {
+            priority_fee: PriorityFeeStrategy::default(),
+            compute_unit_margin: 10,
+            commitment: CommitmentConfig::confirmed(),
+            rebroadcast_interval: Duration::from_secs(2),
+            max_blockhash_refreshes: 2,
+        }
+    }
+}
+
+/// What a landed transaction did on chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionReceipt {
+    pub signature: String,
+    pub slot: u64,
+    /// Fee paid in lamports; `None` when the node could not return the landed transaction.
+    pub fee: Option<u64>,
+    pub compute_units: Option<u64>,
+    pub logs: Vec<String>,
+}
+
+/// How one account's balance would change, as seen in a simulation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChange {
+    pub account: String,
+    /// The token mint for SPL token accounts, `None` for lamports.
+    pub mint: Option<String>,
+    /// The wallet owning the token account, `None` for lamports.
+    pub owner: Option<String>,
+    pub pre: u64,
+    pub post: u64,
+    pub delta: i128,
+}
+
+impl BalanceChange {
+    fn new(account: &Pubkey, token: Option<(Pubkey, Pubkey)>, pre: u64, post: u64) -> Self {
+        Self {
+            account: account.to_string(),
+            mint: token.map(|(mint, _)| mint.to_string()),
+            owner: token.map(|(_, owner)| owner.to_string()),
+            pre,
+            post,
+            delta: post as i128 - pre as i128,
+        }
+    }
+}
+
+/// What a transaction would do, reported instead of sending it when the agent runs in dry-run mode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationReport {
+    pub logs: Vec<String>,
+    pub compute_units: Option<u64>,
+    /// Lamport and token balances the transaction would change.
+    pub balance_changes: Vec<BalanceChange>,
+    /// Estimated fee in lamports.
+    pub fee: Option<u64>,
+    /// Why the transaction would fail, if it would.
+    pub error: Option<String>,
+}
+
+/// The result of a write action: a landed transaction, or a simulated one in dry-run mode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum TransactionOutcome {
+    Confirmed(TransactionReceipt),
+    Simulated(SimulationReport),
+}
+
+impl TransactionOutcome {
+    /// The signature of the landed transaction; `None` when it was only simulated.
+    pub fn signature(&self) -> Option<&str> {
+        match self {
+            TransactionOutcome::Confirmed(receipt) => Some(&receipt.signature),
+            TransactionOutcome::Simulated(_) => None,
+        }
+    }
+
+    pub fn is_simulated(&self) -> bool {
+        matches!(self, TransactionOutcome::Simulated(_))
+    }
+}
+
+/// Builds, prices, signs and sends the transactions of every plugin, then waits for them to land.
+///
+/// The kit's fee payer pays for the transactions built from instructions, and every wallet of the kit a
+/// transaction needs signs it through its [`AgentSigner`](crate::AgentSigner); `signers` only lists the extra
+/// keypairs a transaction needs, e.g. a freshly generated mint.
+///
+/// Every transaction is simulated and checked against the agent's [`Policy`](crate::Policy) before anything
+/// is signed, then submitted to the agent's [`Approver`](crate::Approver) if it has one. When the agent runs in dry-run mode nothing is signed or sent: a [`SimulationReport`]
+/// is returned instead, or the policy violation the transaction would hit.
+pub struct TransactionSender<'a> {
+    agent: &'a SolanaAgentKit,
+}
+
+impl<'a> TransactionSender<'a> {
+    pub fn new(agent: &'a SolanaAgentKit) -> Self {
+        Self { agent }
+    }
+
+    /// Sends `instructions` in one transaction, prefixed with a compute unit limit taken from a simulation
+    /// and a compute unit price chosen by [`SendOptions::priority_fee`].
+    pub async fn send_instructions(
+        &self,
+        intent: &TransactionIntent,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<TransactionOutcome, SolagentError> {
+        let started_at = journal::now();
+        let options = &self.agent.send_options;
+        let payer = self.agent.wallets.fee_payer().pubkey();
+
+        let price = self.compute_unit_price(instructions).await?;
+        let budget = |limit: u32| {
+            let mut budget = vec![ComputeBudgetInstruction::set_compute_unit_limit(limit)];
+            if let Some(price) = price {
+                budget.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+            }
+            budget.into_iter().chain(instructions.iter().cloned()).collect::<Vec<_>>()
+        };
+
+        let probe = VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+            &budget(MAX_COMPUTE_UNIT_LIMIT),
+            Some(&payer),
+        )));
+
+        let (mut report, error) = self.simulate(&probe).await?;
+        let limit = report
+            .compute_units
+            .map_or(MAX_COMPUTE_UNIT_LIMIT, |units| compute_unit_limit(units, options.compute_unit_margin));
+        let message = VersionedMessage::Legacy(Message::new(&budget(limit), Some(&payer)));
+
+        if self.agent.is_dry_run() {
+            self.check_policy(intent, &probe.message, &report)?;
+            if error.is_none() {
+                report.fee = self.estimate_fee(message).await;
+            }
+            let balance_changes = report.balance_changes.clone();
+            return self.journal(intent, started_at, &balance_changes, Ok(TransactionOutcome::Simulated(report)));
+        }
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        let reservation = self.authorize(intent, &probe.message, &report)?;
+        let balance_changes = report.balance_changes.clone();
+        if let Err(error) = self.approve(intent, message.clone(), report).await {
+            self.agent.policy.release(reservation);
+            return Err(error);
+        }
+        let result = self.send_and_confirm(&message, signers).await;
+        let result = self.settle(reservation, result);
+        self.journal(intent, started_at, &balance_changes, result)
+    }
+
+    /// Sends a transaction built by a third-party API such as Jupiter or Solayer.
+    ///
+    /// Its instructions, including any compute budget the API chose, are kept as they are;
+    /// only the blockhash is replaced before signing.
+    pub async fn send_transaction(
+        &self,
+        intent: &TransactionIntent,
+        transaction: VersionedTransaction,
+        signers: &[&Keypair],
+    ) -> Result<TransactionOutcome, SolagentError> {
+        let started_at = journal::now();
+        let message = transaction.message;
+        let probe = VersionedTransaction {
+            signatures: vec![Signature::default(); message.header().num_required_signatures as usize],
+            message: message.clone(),
+        };
+
+        let (mut report, error) = self.simulate(&probe).await?;
+        if self.agent.is_dry_run() {
+            self.check_policy(intent, &message, &report)?;
+            if error.is_none() {
+                report.fee = self.estimate_fee(message).await;
+            }
+            let balance_changes = report.balance_changes.clone();
+            return self.journal(intent, started_at, &balance_changes, Ok(TransactionOutcome::Simulated(report)));
+        }
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        let reservation = self.authorize(intent, &message, &report)?;
+        let balance_changes = report.balance_changes.clone();
+        if let Err(error) = self.approve(intent, message.clone(), report).await {
+            self.agent.policy.release(reservation);
+            return Err(error);
+        }
+        let result = self.send_and_confirm(&message, signers).await;
+        let result = self.settle(reservation, result);
+        self.journal(intent, started_at, &balance_changes, result)
+    }
+
+    /// Signs `message` for `blockhash`, with the `extra` keypairs and the kit's wallets it needs signatures from.
+    async fn sign(
+        &self,
+        message: &VersionedMessage,
+        blockhash: Hash,
+        extra: &[&Keypair],
+    ) -> Result<VersionedTransaction, SolagentError> {
+        let mut message = message.clone();
+        message.set_recent_blockhash(blockhash);
+        let required = message.static_account_keys()[..usize::from(message.header().num_required_signatures)].to_vec();
+        let bytes = message.serialize();
+        let mut transaction = VersionedTransaction { signatures: vec![Signature::default(); required.len()], message };
+
+        for (position, key) in required.iter().enumerate() {
+            if let Some(keypair) = extra.iter().find(|keypair| keypair.pubkey() == *key) {
+                transaction.signatures[position] = keypair.try_sign_message(&bytes)?;
+            } else if let Some((_, wallet)) = self.agent.wallets.iter().find(|(_, wallet)| wallet.pubkey() == *key) {
+                wallet.sign_transaction(&mut transaction).await?;
+            } else {
+                return Err(SignerError::NotEnoughSigners.into());
+            }
+        }
+        Ok(transaction)
+    }
+
+    /// What would leave the kit's wallets, and the intent without the recipients that are the kit's own wallets.
+    fn policy_input(&self, intent: &TransactionIntent, report: &SimulationReport) -> (TransactionIntent, Vec<Outflow>) {
+        let wallets = &self.agent.wallets;
+        let mut intent = intent.clone();
+        intent.recipients.retain(|recipient| !wallets.contains(recipient));
+        (intent, Outflow::from_balance_changes(&report.balance_changes, &wallets.pubkeys()))
+    }
+
+    /// Checks a transaction against the policy without reserving its spending.
+    fn check_policy(
+        &self,
+        intent: &TransactionIntent,
+        message: &VersionedMessage,
+        report: &SimulationReport,
+    ) -> Result<(), SolagentError> {
+        let (intent, outflows) = self.policy_input(intent, report);
+        Ok(self.agent.policy.check(&intent, &programs(message), &outflows)?)
+    }
+
+    /// Checks a transaction against the policy and reserves its spending until it lands or fails.
+    fn authorize(
+        &self,
+        intent: &TransactionIntent,
+        message: &VersionedMessage,
+        report: &SimulationReport,
+    ) -> Result<u64, SolagentError> {
+        let (intent, outflows) = self.policy_input(intent, report);
+        Ok(self.agent.policy.authorize(&intent, &programs(message), &outflows)?)
+    }
+
+    /// Submits the transaction to the agent's approver, if it has one.
+    async fn approve(
+        &self,
+        intent: &TransactionIntent,
+        message: VersionedMessage,
+        report: SimulationReport,
+    ) -> Result<(), SolagentError> {
+        let Some(approver) = &self.agent.approver else {
+            return Ok(());
+        };
+
+        let pending = PendingTransaction {
+            action: intent.action.to_string(),
+            instructions: DecodedInstruction::from_message(&message),
+            outflows: Outflow::from_balance_changes(&report.balance_changes, &self.agent.wallets.pubkeys()),
+            balance_changes: report.balance_changes,
+            fee: self.estimate_fee(message).await,
+        };
+        match approver.review(&pending).await {
+            Approval::Approve => Ok(()),
+            Approval::Reject(reason) => Err(SolagentError::ApprovalRejected(reason)),
+        }
+    }
+
+    /// Gives the reserved spending back to the policy when the transaction did not land.
+    fn settle(
+        &self,
+        reservation: u64,
+        result: Result<TransactionReceipt, SolagentError>,
+    ) -> Result<TransactionOutcome, SolagentError> {
+        if result.is_err() {
+            self.agent.policy.release(reservation);
+        }
+        result.map(TransactionOutcome::Confirmed)
+    }
+
+    /// Writes the transaction to the agent's journal, if it has one, and passes `result` through.
+    fn journal(
+        &self,
+        intent: &TransactionIntent,
+        started_at: u64,
+        balance_changes: &[BalanceChange],
+        result: Result<TransactionOutcome, SolagentError>,
+    ) -> Result<TransactionOutcome, SolagentError> {
+        journal::record_send(
+            self.agent.journal.as_deref(),
+            intent.action,
+            started_at,
+            self.agent.is_dry_run(),
+            balance_changes,
+            &result,
+        );
+        result
+    }
+
+    /// Simulates `transaction` without checking its signatures and reports its logs, compute units and
+    /// the balances it would change.
+    ///
+    /// A transaction that would fail is not an error here: the failure is part of the report,
+    /// and returned alongside it for callers that need to surface it, as
+    /// [`SolagentError::InsufficientFunds`] when a balance was too short.
+    async fn simulate(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> Result<(SimulationReport, Option<SolagentError>), SolagentError> {
+        let connection = &self.agent.connection;
+        let keys = transaction.message.static_account_keys();
+        let pre = connection.get_multiple_accounts(keys).await?;
+
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(self.agent.send_options.commitment),
+            accounts: Some(RpcSimulateTransactionAccountsConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                addresses: keys.iter().map(Pubkey::to_string).collect(),
+            }),
+            ..Default::default()
+        };
+        let result = connection.simulate_transaction_with_config(transaction, config).await?.value;
+        let post: Vec<Option<Account>> = match result.accounts {
+            Some(accounts) => {
+                accounts.iter().map(|account| account.as_ref().and_then(|account| account.decode())).collect()
+            }
+            None => vec![None; keys.len()],
+        };
+
+        let report = SimulationReport {
+            logs: result.logs.unwrap_or_default(),
+            compute_units: result.units_consumed,
+            balance_changes: if result.err.is_some() { vec![] } else { balance_changes(keys, &pre, &post) },
+            fee: None,
+            error: result.err.as_ref().map(|error| error.to_string()),
+        };
+
+        let failure = match result.err {
+            Some(error) => {
+                let fee = match error {
+                    TransactionError::InsufficientFundsForFee => self.estimate_fee(transaction.message.clone()).await,
+                    _ => None,
+                };
+                Some(
+                    insufficient_funds(&transaction.message, &pre, &error, fee)
+                        .unwrap_or(SolagentError::TransactionFailed { error, logs: report.logs.clone() }),
+                )
+            }
+            None => None,
+        };
+        Ok((report, failure))
+    }
+
+    /// Asks the node what `message` would cost with a current blockhash.
+    async fn estimate_fee(&self, mut message: VersionedMessage) -> Option<u64> {
+        let connection = &self.agent.connection;
+        message.set_recent_blockhash(connection.get_latest_blockhash().await.ok()?);
+        match &message {
+            VersionedMessage::Legacy(message) => connection.get_fee_for_message(message).await.ok(),
+            VersionedMessage::V0(message) => connection.get_fee_for_message(message).await.ok(),
+        }
+    }
+
+    async fn compute_unit_price(&self, instructions: &[Instruction]) -> Result<Option<u64>, SolagentError> {
+        match self.agent.send_options.priority_fee {
+            PriorityFeeStrategy::None => Ok(None),
+            PriorityFeeStrategy::Fixed(price) => Ok(Some(price).filter(|price| *price > 0)),
+            PriorityFeeStrategy::Recent { percentile, max } => {
+                let mut accounts: Vec<Pubkey> = instructions
+                    .iter()
+                    .flat_map(|ix| ix.accounts.iter())
+                    .filter(|meta| meta.is_writable)
+                    .map(|meta| meta.pubkey)
+                    .collect();
+                accounts.sort();
+                accounts.dedup();
+
+                let fees = self.agent.connection.get_recent_prioritization_fees(&accounts).await?;
+                Ok(percentile_fee(fees.into_iter().map(|fee| fee.prioritization_fee).collect(), percentile, max))
+            }
+        }
+    }
+
+    /// Signs `message` and broadcasts it until it lands, signing it again with a fresh blockhash whenever
+    /// the previous one expires.
+    async fn send_and_confirm(
+        &self,
+        message: &VersionedMessage,
+        signers: &[&Keypair],
+    ) -> Result<TransactionReceipt, SolagentError> {
+        let options = &self.agent.send_options;
+        let connection = &self.agent.connection;
+        // Preflight already ran in `simulate`, and the node must not retry on its own: we rebroadcast ourselves.
+        let config = RpcSendTransactionConfig { skip_preflight: true, max_retries: Some(0), ..Default::default() };
+
+        let mut signature = Signature::default();
+        for _ in 0..=options.max_blockhash_refreshes {
+            let (blockhash, last_valid_block_height) =
+                connection.get_latest_blockhash_with_commitment(options.commitment).await?;
+            let transaction = self.sign(message, blockhash, signers).await?;
+            signature = transaction.signatures[0];
+
+            loop {
+                // A failed broadcast is not fatal, an earlier one may still land.
+                let _ = connection.send_transaction_with_config(&transaction, config).await;
+                tokio::time::sleep(options.rebroadcast_interval).await;
+
+                let status = connection.get_signature_statuses(&[signature]).await?.value.pop().flatten();
+                if let Some(status) = status {
+                    if let Some(error) = status.err {
+                        let logs = self.receipt(signature, status.slot).await.logs;
+                        return Err(SolagentError::TransactionFailed { error, logs });
+                    }
+                    if status.satisfies_commitment(options.commitment) {
+                        return Ok(self.receipt(signature, status.slot).await);
+                    }
+                    continue;
+                }
+
+                if connection.get_block_height_with_commitment(options.commitment).await? > last_valid_block_height {
+                    break;
+                }
+            }
+        }
+
+        Err(SolagentError::TransactionExpired {
+            signature: signature.to_string(),
+            attempts: options.max_blockhash_refreshes + 1,
+        })
+    }
+
+    /// Reads fee, compute units and logs of a landed transaction.
+    ///
+    /// The transaction has landed by now, so failing to fetch its details must not turn into an error:
+    /// the caller could otherwise send it twice.
+    async fn receipt(&self, signature: Signature, slot: u64) -> TransactionReceipt {
+        let options = &self.agent.send_options;
+        let commitment =
+            if options.commitment.is_at_least_confirmed() { options.commitment } else { CommitmentConfig::confirmed() };
+        let config = RpcTransactionConfig {
+            commitment: Some(commitment),
+            max_supported_transaction_version: Some(0),
+            ..Default::default()
+        };
+
+        for _ in 0..3 {
+            if let Ok(transaction) = self.agent.connection.get_transaction_with_config(&signature, config).await {
+                let meta = transaction.transaction.meta;
+                return TransactionReceipt {
+                    signature: signature.to_string(),
+                    slot: transaction.slot,
+                    fee: meta.as_ref().map(|meta| meta.fee),
+                    compute_units: meta.as_ref().and_then(|meta| meta.compute_units_consumed.clone().into()),
+                    logs: meta.and_then(|meta| Option::from(meta.log_messages)).unwrap_or_default(),
+                };
+            }
+            tokio::time::sleep(options.rebroadcast_interval).await;
+        }
+
+        TransactionReceipt { signature: signature.to_string(), slot, fee: None, compute_units: None, logs: vec![] }
+    }
+}
+
+/// The compute unit limit to request for a transaction that consumed `units` in simulation.
+fn compute_unit_limit(units: u64, margin: u32) -> u32 {
+    let limit = units.saturating_mul(100 + margin as u64) / 100;
+    limit.min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32
+}
+
+/// Compares the accounts before and after a simulation; closed or missing accounts count as empty.
+fn balance_changes(keys: &[Pubkey], pre: &[Option<Account>], post: &[Option<Account>]) -> Vec<BalanceChange> {
+    let mut changes = vec![];
+    for (i, key) in keys.iter().enumerate() {
+        let pre = pre.get(i).and_then(Option::as_ref);
+        let post = post.get(i).and_then(Option::as_ref);
+
+        let lamports = |account: Option<&Account>| account.map_or(0, |account| account.lamports);
+        if lamports(pre) != lamports(post) {
+            changes.push(BalanceChange::new(key, None, lamports(pre), lamports(post)));
+        }
+
+        let (pre_token, post_token) = (pre.and_then(token_balance), post.and_then(token_balance));
+        if let Some((mint, owner, _)) = pre_token.or(post_token) {
+            let (pre_amount, post_amount) =
+                (pre_token.map_or(0, |(_, _, amount)| amount), post_token.map_or(0, |(_, _, amount)| amount));
+            if pre_amount != post_amount {
+                changes.push(BalanceChange::new(key, Some((mint, owner)), pre_amount, post_amount));
+            }
+        }
+    }
+    changes
+}
+
+/// Mint, owner and amount of an SPL Token or Token-2022 account, read from the layout both programs share.
+fn token_balance(account: &Account) -> Option<(Pubkey, Pubkey, u64)> {
+    if !TOKEN_PROGRAM_IDS.contains(&account.owner) || account.data.len() < 165 {
+        return None;
+    }
+    let mint = Pubkey::try_from(&account.data[..32]).ok()?;
+    let owner = Pubkey::try_from(&account.data[32..64]).ok()?;
+    let amount = u64::from_le_bytes(account.data[64..72].try_into().ok()?);
+    Some((mint, owner, amount))
+}
+
+/// Reads a simulation failure caused by a short balance as [`SolagentError::InsufficientFunds`]: the
+/// amount required comes from the failing system or token transfer, or is the `fee`, and the amount
+/// available from the accounts before the transaction.
+fn insufficient_funds(
+    message: &VersionedMessage,
+    pre: &[Option<Account>],
+    error: &TransactionError,
+    fee: Option<u64>,
+) -> Option<SolagentError> {
+    let account = |index: usize| pre.get(index).and_then(Option::as_ref);
+    let (required, available) = match error {
+        TransactionError::InsufficientFundsForFee => (fee?, account(0).map_or(0, |account| account.lamports)),
+        // `ResultWithNegativeLamports` of the system program, `InsufficientFunds` of the token programs
+        TransactionError::InstructionError(index, InstructionError::Custom(1)) => {
+            let keys = message.static_account_keys();
+            let ix = message.instructions().get(*index as usize)?;
+            let program = keys.get(ix.program_id_index as usize)?;
+            let source = *ix.accounts.first()? as usize;
+            // Accounts from lookup tables were not fetched
+            if source >= keys.len() {
+                return None;
+            }
+            if *program == system_program::ID && ix.data.get(..4)? == 2u32.to_le_bytes() {
+                // `Transfer`: the lamports follow the instruction tag
+                let required = u64::from_le_bytes(ix.data.get(4..12)?.try_into().ok()?);
+                (required, account(source).map_or(0, |account| account.lamports))
+            } else if TOKEN_PROGRAM_IDS.contains(program) && matches!(ix.data.first()?, 3 | 12) {
+                // `Transfer` and `TransferChecked`: the amount follows the instruction tag
+                let required = u64::from_le_bytes(ix.data.get(1..9)?.try_into().ok()?);
+                (required, account(source).and_then(token_balance).map_or(0, |(_, _, amount)| amount))
+            } else {
+                return None;
+            }
+        }
+        _ => return None,
+    };
+    Some(SolagentError::InsufficientFunds { required, available })
+}
+
+/// The programs invoked by the top-level instructions of `message`.
+fn programs(message: &VersionedMessage) -> Vec<Pubkey> {
+    let keys = message.static_account_keys();
+    message.instructions().iter().filter_map(|ix| keys.get(ix.program_id_index as usize).copied()).collect()
+}
+
+/// Picks the `percentile` of `fees`, capped at `max`; `None` when no price is worth setting.
+fn percentile_fee(mut fees: Vec<u64>, percentile: u8, max: u64) -> Option<u64> {
+    if fees.is_empty() {
+        return None;
+    }
+    fees.sort_unstable();
+    let index = (fees.len() - 1) * percentile.min(100) as usize / 100;
+    Some(fees[index].min(max)).filter(|fee| *fee > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_unit_limit() {
+        assert_eq!(compute_unit_limit(200_000, 10), 220_000);
+        assert_eq!(compute_unit_limit(150, 0), 150);
+        assert_eq!(compute_unit_limit(1_390_000, 10), MAX_COMPUTE_UNIT_LIMIT);
+    }
+
+    #[test]
+    fn test_percentile_fee() {
+        assert_eq!(percentile_fee(vec![], 50, 1_000), None);
+        assert_eq!(percentile_fee(vec![0, 0, 0], 90, 1_000), None);
+        assert_eq!(percentile_fee(vec![40, 10, 30, 20, 50], 50, 1_000), Some(30));
+        assert_eq!(percentile_fee(vec![40, 10, 30, 20, 50], 100, 1_000), Some(50));
+        assert_eq!(percentile_fee(vec![5_000, 10_000], 100, 1_000), Some(1_000));
+    }
+
+    fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+        let mut data = vec![0; 165];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        Account { lamports: 2_039_280, data, owner: TOKEN_PROGRAM_IDS[0], executable: false, rent_epoch: 0 }
+    }
+
+    #[test]
+    fn test_balance_changes() {
+        let (wallet, token, untouched) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mint = Pubkey::new_unique();
+        let system = |lamports| Some(Account { lamports, ..Account::default() });
+
+        let pre = vec![system(1_000_000), Some(token_account(&mint, &wallet, 500)), system(7)];
+        let post = vec![system(995_000), Some(token_account(&mint, &wallet, 200)), system(7)];
+        let changes = balance_changes(&[wallet, token, untouched], &pre, &post);
+
+        assert_eq!(
+            changes,
+            vec![
+                BalanceChange::new(&wallet, None, 1_000_000, 995_000),
+                BalanceChange::new(&token, Some((mint, wallet)), 500, 200),
+            ]
+        );
+        assert_eq!(changes[0].delta, -5_000);
+
+        // A token account created by the transaction
+        let changes = balance_changes(&[token], &[None], &[Some(token_account(&mint, &wallet, 42))]);
+        assert_eq!(changes[1], BalanceChange::new(&token, Some((mint, wallet)), 0, 42));
+        assert_eq!(changes[1].owner, Some(wallet.to_string()));
+    }
+
+    #[test]
+    fn test_insufficient_funds() {
+        use solana_sdk::{instruction::AccountMeta, system_instruction};
+
+        let (wallet, recipient, source) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mint = Pubkey::new_unique();
+        let wallet_account = Some(Account { lamports: 3_000, ..Account::default() });
+        let failed = |index| TransactionError::InstructionError(index, InstructionError::Custom(1));
+
+        let instructions = [
+            ComputeBudgetInstruction::set_compute_unit_limit(MAX_COMPUTE_UNIT_LIMIT),
+            system_instruction::transfer(&wallet, &recipient, 10_000),
+        ];
+        let message = VersionedMessage::Legacy(Message::new(&instructions, Some(&wallet)));
+        let pre = vec![wallet_account.clone(), None, None];
+        let error = insufficient_funds(&message, &pre, &failed(1), None).unwrap();
+        assert!(matches!(error, SolagentError::InsufficientFunds { required: 10_000, available: 3_000 }));
+
+        let error = insufficient_funds(&message, &pre, &TransactionError::InsufficientFundsForFee, Some(5_000));
+        assert!(matches!(error, Some(SolagentError::InsufficientFunds { required: 5_000, available: 3_000 })));
+        // Other failures stay transaction failures
+        assert!(insufficient_funds(&message, &pre, &TransactionError::AccountNotFound, None).is_none());
+        assert!(insufficient_funds(&message, &pre, &failed(0), None).is_none());
+
+        // `Transfer` of the token program: tag 3, then the amount
+        let mut data = vec![3];
+        data.extend_from_slice(&700u64.to_le_bytes());
+        let transfer = Instruction::new_with_bytes(
+            TOKEN_PROGRAM_IDS[0],
+            &data,
+            vec![
+                AccountMeta::new(source, false),
+                AccountMeta::new(recipient, false),
+                AccountMeta::new_readonly(wallet, true),
+            ],
+        );
+        let message = VersionedMessage::Legacy(Message::new(&[transfer], Some(&wallet)));
+        let pre: Vec<Option<Account>> = message
+            .static_account_keys()
+            .iter()
+            .map(|key| match *key {
+                key if key == source => Some(token_account(&mint, &wallet, 200)),
+                key if key == wallet => wallet_account.clone(),
+                _ => None,
+            })
+            .collect();
+        let error = insufficient_funds(&message, &pre, &failed(0), None).unwrap();
+        assert!(matches!(error, SolagentError::InsufficientFunds { required: 700, available: 200 }));
+    }
+
+    #[test]
+    fn test_transaction_outcome_serialization() {
+        let report = SimulationReport {
+            logs: vec![],
+            compute_units: Some(450),
+            balance_changes: vec![],
+            fee: Some(5_000),
+            error: None,
+        };
+        let outcome = TransactionOutcome::Simulated(report);
+        assert!(outcome.is_simulated());
+        assert!(outcome.signature().is_none());
+
+        let json = serde_json::to_value(&outcome).unwrap();
+        assert_eq!(json["status"], "simulated");
+        assert_eq!(json["compute_units"], 450);
+    }
+
+    #[test]
+    fn test_send_options_default() {
+        let options = SendOptions::default();
+        assert_eq!(options.priority_fee, PriorityFeeStrategy::Recent { percentile: 50, max: 100_000 });
+        assert_eq!(options.commitment, CommitmentConfig::confirmed());
+    }
+
+    #[tokio::test]
+    async fn test_sign() {
+        use crate::{Config, Wallet};
+        use solana_sdk::system_instruction;
+
+        let (payer, treasury, mint) = (Wallet::new(), Wallet::new(), Keypair::new());
+        let (payer_pubkey, treasury_pubkey) = (payer.pubkey, treasury.pubkey);
+        let agent = SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default())
+            .with_wallet("treasury", treasury)
+            .with_fee_payer("payer", payer);
+        let sender = agent.sender();
+
+        let instructions = [
+            system_instruction::transfer(&treasury_pubkey, &Pubkey::new_unique(), 1),
+            system_instruction::create_account(&treasury_pubkey, &mint.pubkey(), 1, 82, &Pubkey::new_unique()),
+        ];
+        let message = VersionedMessage::Legacy(Message::new(&instructions, Some(&payer_pubkey)));
+        let blockhash = Hash::new_unique();
+
+        // Only the wallets the message needs sign it, the fee payer first.
+        let transaction = sender.sign(&message, blockhash, &[&mint]).await.unwrap();
+        assert_eq!(transaction.message.recent_blockhash(), &blockhash);
+        assert_eq!(transaction.signatures.len(), 3);
+        assert!(transaction.verify_with_results().iter().all(|verified| *verified));
+
+        let error = sender.sign(&message, blockhash, &[]).await.unwrap_err();
+        assert!(matches!(error, SolagentError::Signing(SignerError::NotEnoughSigners)));
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SolagentError;
+use rig::{
+    agent::AgentBuilder,
+    completion::{CompletionModel, ToolDefinition},
+    embeddings::{EmbeddingModel, EmbeddingsBuilder, ToolSchema},
+    tool::{Tool, ToolEmbedding, ToolSet},
+    vector_store::in_memory_store::{InMemoryVectorIndex, InMemoryVectorStore},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{convert::Infallible, future::Future, pin::Pin, sync::Arc};
+
+/// The future of [`SolagentTool::invoke`]. It is `Send` only: [`RigTool`] meets rig's `Sync` bound
+/// by running it on a task of its own.
+pub type ToolFuture<'a> = Pin<Box<dyn Future<Output = Result<Value, SolagentError>> + Send + 'a>>;
+
+/// What a tool may do, so that agents can be given only some kinds of tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCategory {
+    /// Reads chain or API data, and never signs.
+    ReadOnly,
+    /// Signs for the agent's wallets: transfers, swaps, stakes, payments and signed messages.
+    Trading,
+    /// Creates or removes tokens, collections, NFTs and webhooks.
+    Deployment,
+}
+
+impl ToolCategory {
+    pub const ALL: [ToolCategory; 3] = [ToolCategory::ReadOnly, ToolCategory::Trading, ToolCategory::Deployment];
+}
+
+/// A tool an LLM can call, independent of any agent framework: a name, the JSON Schema of its
+/// arguments, and a call on JSON values.
+///
+/// Tools hold the [`SolanaAgentKit`](crate::SolanaAgentKit) they act for. [`SolanaAgentKit::invoke_tool`](crate::SolanaAgentKit::invoke_tool)
+/// turns a typed `run` into `invoke`:
+///
+/// ```ignore
+/// impl SolagentTool for Transfer {
+///     fn name(&self) -> &str {
+///         "transfer"
+///     }
+///
+///     fn description(&self) -> &str {
+///         "Transfer SOL or SPL tokens to a recipient."
+///     }
+///
+///     fn parameters(&self) -> Value {
+///         TransferArgs::schema()
+///     }
+///
+///     fn category(&self) -> ToolCategory {
+///         ToolCategory::Trading
+///     }
+///
+///     fn invoke(&self, args: Value) -> ToolFuture<'_> {
+///         Box::pin(self.agent.invoke_tool(self.name(), args, |args| self.run(args)))
+///     }
+/// }
+/// ```
+pub trait SolagentTool: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// What the tool does, for the model to decide when to call it.
+    fn description(&self) -> &str;
+
+    /// The JSON Schema of the arguments of [`SolagentTool::invoke`].
+    fn parameters(&self) -> Value;
+
+    /// What the tool may do. There is no default, so that no signing tool passes for read-only.
+    fn category(&self) -> ToolCategory;
+
+    /// The texts to embed when tools are retrieved by similarity with the prompt, see
+    /// [`ToolRegistry::attach_dynamic`]: the first line of the description by default. A few phrasings
+    /// of what users ask for, in their words, retrieve a tool better than one summary.
+    fn embedding_docs(&self) -> Vec<String> {
+        let summary = self.description().trim().lines().next().unwrap_or_default();
+        vec![summary.to_string()]
+    }
+
+    /// Runs the tool with the arguments the model sent; the output is returned to the model.
+    fn invoke(&self, args: Value) -> ToolFuture<'_>;
+}
+
+/// A set of tools, looked up by name.
+#[derive(Clone, Default)]
+pub struct ToolRegistry {
+    tools: Vec<Arc<dyn SolagentTool>>,
+}
+
+impl ToolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tool, replacing any tool of the same name.
+    pub fn register(&mut self, tool: Arc<dyn SolagentTool>) {
+        self.tools.retain(|registered| registered.name() != tool.name());
+        self.tools.push(tool);
+    }
+
+    /// Returns `self` with `tool` added.
+    pub fn with(mut self, tool: impl SolagentTool + 'static) -> Self {
+        self.register(Arc::new(tool));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn SolagentTool>> {
+        self.tools.iter().find(|tool| tool.name() == name)
+    }
+
+    /// The tools, in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn SolagentTool>> {
+        self.tools.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tools.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    /// The tools of the given categories, e.g. `&[ToolCategory::ReadOnly]` for an agent that must never
+    /// sign.
+    pub fn only(&self, categories: &[ToolCategory]) -> Self {
+        let tools = self.tools.iter().filter(|tool| categories.contains(&tool.category())).cloned().collect();
+        Self { tools }
+    }
+
+    /// Calls the tool named `name`.
+    pub async fn invoke(&self, name: &str, args: Value) -> Result<Value, SolagentError> {
+        match self.get(name) {
+            Some(tool) => tool.invoke(args).await,
+            None => Err(SolagentError::InvalidInput(format!("unknown tool `{}`", name))),
+        }
+    }
+
+    /// The tools as OpenAI-style function definitions, for plain function calling.
+    pub fn openai_functions(&self) -> Vec<Value> {
+        self.tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": tool.name(),
+                        "description": tool.description().trim(),
+                        "parameters": tool.parameters(),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// The tools as rig tools.
+    pub fn rig_tools(&self) -> impl Iterator<Item = RigTool> + '_ {
+        self.tools.iter().cloned().map(RigTool::new)
+    }
+
+    /// The tools as a rig [`ToolSet`].
+    pub fn rig_toolset(&self) -> ToolSet {
+        self.rig_tools().fold(ToolSet::builder(), |builder, tool| builder.static_tool(tool)).build()
+    }
+
+    /// Adds the tools to a rig agent.
+    pub fn attach<M: CompletionModel>(&self, builder: AgentBuilder<M>) -> AgentBuilder<M> {
+        self.rig_tools().fold(builder, |builder, tool| builder.tool(tool))
+    }
+
+    /// The tools as a rig [`ToolSet`] of dynamic tools, which rig retrieves by their
+    /// [`SolagentTool::embedding_docs`].
+    pub fn rig_dynamic_toolset(&self) -> ToolSet {
+        self.rig_tools().fold(ToolSet::builder(), |builder, tool| builder.dynamic_tool(tool)).build()
+    }
+
+    /// An in-memory vector index of the tools, embedding their [`SolagentTool::embedding_docs`] with
+    /// `model`. Its ids are the names of the tools.
+    pub async fn rig_tool_index<E: EmbeddingModel>(
+        &self,
+        model: E,
+    ) -> Result<InMemoryVectorIndex<E, ToolSchema>, SolagentError> {
+        let schemas = self.rig_dynamic_toolset().schemas().map_err(|e| SolagentError::Embedding(e.to_string()))?;
+        let embeddings = EmbeddingsBuilder::new(model.clone())
+            .documents(schemas)
+            .map_err(|e| SolagentError::Embedding(e.to_string()))?
+            .build()
+            .await
+            .map_err(|e| SolagentError::Embedding(e.to_string()))?;
+
+        let store = InMemoryVectorStore::from_documents_with_id_f(embeddings, |tool| tool.name.clone());
+        Ok(store.index(model))
+    }
+
+    /// Adds the tools to a rig agent, sending the model only the `n` tools closest to each prompt
+    /// instead of all of them, so that large toolsets fit small models.
+    pub async fn attach_dynamic<M: CompletionModel, E: EmbeddingModel + 'static>(
+        &self,
+        builder: AgentBuilder<M>,
+        model: E,
+        n: usize,
+    ) -> Result<AgentBuilder<M>, SolagentError> {
+        let index = self.rig_tool_index(model).await?;
+        Ok(builder.dynamic_tools(n, index, self.rig_dynamic_toolset()))
+    }
+}
+
+impl Extend<Arc<dyn SolagentTool>> for ToolRegistry {
+    fn extend<I: IntoIterator<Item = Arc<dyn SolagentTool>>>(&mut self, tools: I) {
+        for tool in tools {
+            self.register(tool);
+        }
+    }
+}
+
+impl FromIterator<Arc<dyn SolagentTool>> for ToolRegistry {
+    fn from_iter<I: IntoIterator<Item = Arc<dyn SolagentTool>>>(tools: I) -> Self {
+        let mut registry = Self::new();
+        registry.extend(tools);
+        registry
+    }
+}
+
+/// A [`SolagentTool`] as a rig [`Tool`], so that rig agents can call it.
+///
+/// rig names a tool by [`Tool::name`], which returns the name of the wrapped tool; `NAME` is a
+/// placeholder.
+#[derive(Clone)]
+pub struct RigTool(Arc<dyn SolagentTool>);
+
+impl RigTool {
+    pub fn new(tool: Arc<dyn SolagentTool>) -> Self {
+        Self(tool)
+    }
+}
+
+impl Tool for RigTool {
+    const NAME: &'static str = "solagent_tool";
+
+    type Error = SolagentError;
+    type Args = Value;
+    type Output = Value;
+
+    fn name(&self) -> String {
+        self.0.name().to_string()
+    }
+
+    async fn definition(&self, _prompt: String) -> ToolDefinition {
+        ToolDefinition {
+            name: self.0.name().to_string(),
+            description: self.0.description().to_string(),
+            parameters: self.0.parameters(),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        // rig asks for a `Sync` future, which the RPC and signer futures are not: the call runs on a task
+        // of its own, whose `JoinHandle` is `Sync`.
+        let tool = self.0.clone();
+        tokio::spawn(async move { tool.invoke(args).await })
+            .await
+            .unwrap_or_else(|error| std::panic::resume_unwind(error.into_panic()))
+    }
+}
+
+impl ToolEmbedding for RigTool {
+    type InitError = Infallible;
+    type Context = ();
+    type State = Arc<dyn SolagentTool>;
+
+    fn init(state: Self::State, _context: Self::Context) -> Result<Self, Self::InitError> {
+        Ok(Self(state))
+    }
+
+    fn embedding_docs(&self) -> Vec<String> {
+        self.0.embedding_docs()
+    }
+
+    fn context(&self) -> Self::Context {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, SolanaAgentKit, Wallet};
+    use rig::{
+        embeddings::{Embedding, EmbeddingError},
+        vector_store::VectorStoreIndex,
+    };
+    use solagent_parameters::ToolParameters;
+
+    #[derive(Deserialize, Serialize, ToolParameters)]
+    struct DoubleArgs {
+        #[parameters(maximum = 100)]
+        value: u32,
+    }
+
+    struct Double {
+        agent: Arc<SolanaAgentKit>,
+        name: &'static str,
+        category: ToolCategory,
+    }
+
+    impl SolagentTool for Double {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn description(&self) -> &str {
+            "
+            Double a number.
+
+            examples: [{ input: { value: 2 }, output: 4 }]
+            "
+        }
+
+        fn parameters(&self) -> Value {
+            DoubleArgs::schema()
+        }
+
+        fn category(&self) -> ToolCategory {
+            self.category
+        }
+
+        fn invoke(&self, args: Value) -> ToolFuture<'_> {
+            Box::pin(self.agent.invoke_tool(self.name(), args, |args: DoubleArgs| async move { Ok(args.value * 2) }))
+        }
+    }
+
+    /// A tool known by its embedding docs only.
+    struct Described(&'static str, &'static [&'static str]);
+
+    impl SolagentTool for Described {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn description(&self) -> &str {
+            self.1[0]
+        }
+
+        fn parameters(&self) -> Value {
+            json!({ "type": "object" })
+        }
+
+        fn category(&self) -> ToolCategory {
+            ToolCategory::ReadOnly
+        }
+
+        fn embedding_docs(&self) -> Vec<String> {
+            self.1.iter().map(|doc| doc.to_string()).collect()
+        }
+
+        fn invoke(&self, _args: Value) -> ToolFuture<'_> {
+            // Holds a `Cell` across an await, so that the future is not `Sync`, like RPC and signer futures
+            Box::pin(async {
+                let calls = std::cell::Cell::new(0);
+                tokio::task::yield_now().await;
+                calls.set(calls.get() + 1);
+                Ok(json!(calls.get()))
+            })
+        }
+    }
+
+    /// A deterministic embedding model: every word counts in one dimension, picked by its hash.
+    #[derive(Clone)]
+    struct Words;
+
+    impl EmbeddingModel for Words {
+        const MAX_DOCUMENTS: usize = 64;
+
+        fn ndims(&self) -> usize {
+            256
+        }
+
+        fn embed_texts(
+            &self,
+            texts: impl IntoIterator<Item = String> + Send,
+        ) -> impl Future<Output = Result<Vec<Embedding>, EmbeddingError>> + Send {
+            let embeddings = texts
+                .into_iter()
+                .map(|text| {
+                    let mut vec = vec![0.0; self.ndims()];
+                    for word in text.to_lowercase().split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+                        let hash = word.bytes().fold(5381usize, |hash, byte| hash.wrapping_mul(33) ^ byte as usize);
+                        vec[hash % self.ndims()] += 1.0;
+                    }
+                    Embedding { document: text, vec }
+                })
+                .collect();
+            std::future::ready(Ok(embeddings))
+        }
+    }
+
+    fn registry() -> ToolRegistry {
+        let agent = Arc::new(SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", Config::default()));
+        let double = |name, category| Double { agent: agent.clone(), name, category };
+        ToolRegistry::new()
+            .with(double("double", ToolCategory::Trading))
+            .with(double("twice", ToolCategory::Deployment))
+            .with(double("double", ToolCategory::ReadOnly))
+    }
+
+    #[tokio::test]
+    async fn test_tool_registry() {
+        let registry = registry();
+        let names: Vec<&str> = registry.iter().map(|tool| tool.name()).collect();
+        assert_eq!(names, ["twice", "double"]);
+        assert_eq!(registry.get("double").unwrap().embedding_docs(), ["Double a number."]);
+
+        assert_eq!(registry.invoke("double", json!({ "value": 21 })).await.unwrap(), json!(42));
+        assert!(matches!(registry.invoke("triple", json!({})).await, Err(SolagentError::InvalidInput(_))));
+        assert!(matches!(
+            registry.invoke("double", json!({ "value": 101 })).await,
+            Err(SolagentError::InvalidArguments(_))
+        ));
+        assert!(matches!(registry.invoke("double", json!({})).await, Err(SolagentError::InvalidArguments(_))));
+
+        let function = &registry.openai_functions()[1];
+        assert_eq!(function["type"], "function");
+        assert_eq!(function["function"]["name"], "double");
+        assert!(function["function"]["description"].as_str().unwrap().starts_with("Double a number."));
+        assert_eq!(function["function"]["parameters"], DoubleArgs::schema());
+
+        let read_only = registry.only(&[ToolCategory::ReadOnly]);
+        assert_eq!(read_only.iter().map(|tool| tool.name()).collect::<Vec<_>>(), ["double"]);
+        assert!(registry.only(&[ToolCategory::Trading]).is_empty());
+        assert_eq!(registry.only(&ToolCategory::ALL).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rig_tool() {
+        let tool = registry().rig_tools().nth(1).unwrap();
+        assert_eq!(Tool::name(&tool), "double");
+        assert_eq!(tool.definition(String::new()).await.parameters, DoubleArgs::schema());
+        assert_eq!(Tool::call(&tool, json!({ "value": 4 })).await.unwrap(), json!(8));
+        assert_eq!(ToolEmbedding::embedding_docs(&tool), ["Double a number."]);
+    }
+
+    #[tokio::test]
+    async fn test_rig_tool_not_sync() {
+        let tool = RigTool::new(Arc::new(Described("get_tps", &[])));
+        assert_eq!(Tool::call(&tool, json!({})).await.unwrap(), json!(1));
+    }
+
+    #[tokio::test]
+    async fn test_rig_tool_index() {
+        let registry = ToolRegistry::new()
+            .with(Described("get_balance", &["Get the balance of a wallet.", "How much SOL do I have?"]))
+            .with(Described("trade", &["Swap tokens on Jupiter.", "Buy or sell a token."]))
+            .with(Described("get_tps", &["Get the transactions per second of the network."]));
+        assert_eq!(registry.rig_dynamic_toolset().schemas().unwrap().len(), 3);
+
+        let index = registry.rig_tool_index(Words).await.unwrap();
+        let closest = |query: &'static str| {
+            let index = &index;
+            async move { index.top_n_ids(query, 1).await.unwrap().remove(0).1 }
+        };
+        assert_eq!(closest("how much SOL do I have").await, "get_balance");
+        assert_eq!(closest("buy a token").await, "trade");
+        assert_eq!(closest("is the network congested? transactions per second").await, "get_tps");
+        assert_eq!(index.top_n_ids("anything", 5).await.unwrap().len(), 3);
+    }
+}
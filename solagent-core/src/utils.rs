@@ -0,0 +1,37 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SolagentError;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Parses a base58 public key, naming the offending field when it is malformed.
+pub fn parse_pubkey(field: &str, value: &str) -> Result<Pubkey, SolagentError> {
+    Pubkey::from_str(value)
+        .map_err(|_| SolagentError::InvalidInput(format!("`{}` is not a valid Solana address: {}", field, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pubkey() {
+        let pubkey = parse_pubkey("mint", "So11111111111111111111111111111111111111112").unwrap();
+        assert_eq!(pubkey.to_string(), "So11111111111111111111111111111111111111112");
+
+        let error = parse_pubkey("to", "not-a-key").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid input: `to` is not a valid Solana address: not-a-key");
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{parse_pubkey, SolagentError};
+use solagent_wallet_solana::AgentSigner;
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+
+/// Label of the wallet a kit is created with, used when a call names no wallet.
+pub const DEFAULT_WALLET: &str = "default";
+
+/// The wallets of a `SolanaAgentKit`, by label.
+///
+/// A wallet is any [`AgentSigner`]: a local keypair [`Wallet`](solagent_wallet_solana::Wallet), a
+/// [`RemoteSigner`](solagent_wallet_solana::RemoteSigner), or a signer of your own.
+///
+/// Plugins act with the [`DEFAULT_WALLET`] unless a call names another one. The fee payer pays for the
+/// transactions the kit builds itself and falls back to the default wallet; transactions built by
+/// third-party APIs are paid by the wallet they act for.
+pub struct Wallets {
+    wallets: Vec<(String, Arc<dyn AgentSigner>)>,
+    fee_payer: Option<String>,
+}
+
+impl Wallets {
+    pub fn new(default: impl AgentSigner + 'static) -> Self {
+        Self { wallets: vec![(DEFAULT_WALLET.to_string(), Arc::new(default))], fee_payer: None }
+    }
+
+    /// Adds `wallet` under `label`, replacing any wallet already using it.
+    pub fn insert(&mut self, label: impl Into<String>, wallet: impl AgentSigner + 'static) {
+        let label = label.into();
+        let wallet: Arc<dyn AgentSigner> = Arc::new(wallet);
+        match self.wallets.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, slot)) => *slot = wallet,
+            None => self.wallets.push((label, wallet)),
+        }
+    }
+
+    /// Adds `wallet` under `label` and makes it the fee payer.
+    pub fn insert_fee_payer(&mut self, label: impl Into<String>, wallet: impl AgentSigner + 'static) {
+        let label = label.into();
+        self.insert(label.clone(), wallet);
+        self.fee_payer = Some(label);
+    }
+
+    /// Makes the wallet under `label` pay the fees of the transactions the kit builds.
+    pub fn set_fee_payer(&mut self, label: &str) -> Result<(), SolagentError> {
+        self.get(Some(label))?;
+        self.fee_payer = Some(label.to_string());
+        Ok(())
+    }
+
+    /// The wallet under `label`, or the default wallet when `label` is `None`.
+    pub fn get(&self, label: Option<&str>) -> Result<&dyn AgentSigner, SolagentError> {
+        let label = label.unwrap_or(DEFAULT_WALLET);
+        self.wallets
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, wallet)| wallet.as_ref())
+            .ok_or_else(|| SolagentError::UnknownWallet(label.to_string()))
+    }
+
+    pub fn default_wallet(&self) -> &dyn AgentSigner {
+        self.wallets[0].1.as_ref()
+    }
+
+    pub fn fee_payer(&self) -> &dyn AgentSigner {
+        self.fee_payer.as_deref().and_then(|label| self.get(Some(label)).ok()).unwrap_or(self.default_wallet())
+    }
+
+    /// Labels and wallets, the default wallet first.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &dyn AgentSigner)> {
+        self.wallets.iter().map(|(label, wallet)| (label.as_str(), wallet.as_ref()))
+    }
+
+    pub fn pubkeys(&self) -> Vec<Pubkey> {
+        self.wallets.iter().map(|(_, wallet)| wallet.pubkey()).collect()
+    }
+
+    pub fn contains(&self, pubkey: &Pubkey) -> bool {
+        self.wallets.iter().any(|(_, wallet)| wallet.pubkey() == *pubkey)
+    }
+
+    /// Reads an address argument that is either a wallet label or a base58 public key, so tools can move
+    /// funds between the kit's own wallets by name.
+    pub fn address(&self, name: &str, value: &str) -> Result<Pubkey, SolagentError> {
+        match self.wallets.iter().find(|(label, _)| label == value) {
+            Some((_, wallet)) => Ok(wallet.pubkey()),
+            None => parse_pubkey(name, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solagent_wallet_solana::{RemoteSigner, Wallet};
+
+    #[test]
+    fn test_wallets() {
+        let (default, treasury) = (Wallet::new(), Wallet::new());
+        let (default_pubkey, treasury_pubkey) = (default.pubkey, treasury.pubkey);
+
+        let mut wallets = Wallets::new(default);
+        assert_eq!(wallets.fee_payer().pubkey(), default_pubkey);
+
+        wallets.insert("treasury", treasury);
+        assert_eq!(wallets.get(None).unwrap().pubkey(), default_pubkey);
+        assert_eq!(wallets.get(Some("treasury")).unwrap().pubkey(), treasury_pubkey);
+        assert!(matches!(wallets.get(Some("hot")), Err(SolagentError::UnknownWallet(label)) if label == "hot"));
+
+        assert!(wallets.set_fee_payer("hot").is_err());
+        wallets.set_fee_payer("treasury").unwrap();
+        assert_eq!(wallets.fee_payer().pubkey(), treasury_pubkey);
+
+        // The fee payer's key may live elsewhere.
+        let payer_pubkey = Pubkey::new_unique();
+        wallets.insert_fee_payer("payer", RemoteSigner::new("http://localhost:8080", payer_pubkey));
+        assert_eq!(wallets.fee_payer().pubkey(), payer_pubkey);
+
+        assert_eq!(wallets.pubkeys(), vec![default_pubkey, treasury_pubkey, payer_pubkey]);
+        assert!(wallets.contains(&treasury_pubkey));
+        assert_eq!(wallets.iter().map(|(label, _)| label).collect::<Vec<_>>(), ["default", "treasury", "payer"]);
+    }
+
+    #[test]
+    fn test_wallets_address() {
+        let treasury = Wallet::new();
+        let treasury_pubkey = treasury.pubkey;
+        let mut wallets = Wallets::new(Wallet::new());
+        wallets.insert("treasury", treasury);
+
+        assert_eq!(wallets.address("to", "treasury").unwrap(), treasury_pubkey);
+        let other = Pubkey::new_unique();
+        assert_eq!(wallets.address("to", &other.to_string()).unwrap(), other);
+        assert!(matches!(wallets.address("to", "nope"), Err(SolagentError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `ToolParameters` derive macro, re-exported by `solagent-parameters`.
+
+mod serde_attr;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use serde_attr::{Container, DefaultValue, Field, Variant};
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields};
+
+/// Derives `ToolParameters`, the JSON Schema of a type as it is deserialized by serde.
+///
+/// Named structs become objects, unit-only enums become string enums and newtype structs take the
+/// schema of their field. Doc comments become descriptions, and the serde attributes `rename`,
+/// `rename_all`, `default`, `skip` and `deny_unknown_fields` are followed. A field is required unless
+/// it is an `Option` or has a serde default; defaults are serialized into the schema, so the type of
+/// such a field must implement `Serialize`.
+///
+/// `#[parameters(...)]` constrains a field further, for the model and for `validate`:
+/// - `address`: a base58 Solana address;
+/// - `minimum`, `maximum`, `exclusive_minimum`, `exclusive_maximum` = a number;
+/// - `decimals` = the number of decimal places allowed;
+/// - `min_length`, `max_length` = a number of characters.
+///
+/// On a list, the constraints apply to its items.
+#[proc_macro_derive(ToolParameters, attributes(parameters))]
+pub fn derive_tool_parameters(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let container = Container::from_attrs(&input.attrs)?;
+    let description = docs(&input.attrs);
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => object(&container, description, fields)?,
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                quote! {
+                    let mut schema = <#ty as ::solagent_parameters::ToolParameters>::schema();
+                    ::solagent_parameters::__private::describe(&mut schema, #description);
+                    schema
+                }
+            }
+            _ => return Err(syn::Error::new_spanned(&input.ident, "ToolParameters needs named fields or a newtype")),
+        },
+        Data::Enum(_) if container.tagged => {
+            return Err(syn::Error::new_spanned(&input.ident, "ToolParameters only supports externally tagged enums"));
+        }
+        Data::Enum(data) => {
+            let mut names = Vec::new();
+            let mut descriptions = Vec::new();
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(syn::Error::new_spanned(variant, "ToolParameters only supports unit variants"));
+                }
+                let attrs = Variant::from_attrs(&variant.attrs)?;
+                if attrs.skip {
+                    continue;
+                }
+                let name = attrs.rename.unwrap_or_else(|| container.rename_all.apply_to_variant(&variant.ident));
+                let doc = docs(&variant.attrs);
+                if !doc.is_empty() {
+                    descriptions.push(format!("- `{}`: {}", name, doc));
+                }
+                names.push(name);
+            }
+            let description =
+                [description].into_iter().chain(descriptions).filter(|line| !line.is_empty()).collect::<Vec<_>>();
+            let description = description.join("\n");
+            quote! {
+                let mut schema = ::solagent_parameters::__private::serde_json::json!({
+                    "type": "string",
+                    "enum": [#(#names),*],
+                });
+                ::solagent_parameters::__private::describe(&mut schema, #description);
+                schema
+            }
+        }
+        Data::Union(_) => return Err(syn::Error::new_spanned(&input.ident, "ToolParameters does not support unions")),
+    };
+
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::solagent_parameters::ToolParameters));
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::solagent_parameters::ToolParameters for #ident #ty_generics #where_clause {
+            #[allow(unused_mut)]
+            fn schema() -> ::solagent_parameters::__private::serde_json::Value {
+                #body
+            }
+        }
+    })
+}
+
+fn object(container: &Container, description: String, fields: &syn::FieldsNamed) -> syn::Result<TokenStream2> {
+    let mut properties = Vec::new();
+    for field in &fields.named {
+        let attrs = Field::from_attrs(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        if attrs.flatten {
+            return Err(syn::Error::new_spanned(field, "ToolParameters does not support `#[serde(flatten)]`"));
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let name = attrs.rename.unwrap_or_else(|| container.rename_all.apply_to_field(ident));
+        let doc = docs(&field.attrs);
+        let constraints = constraints(&field.attrs)?
+            .into_iter()
+            .map(|(key, value)| quote!(::solagent_parameters::__private::constrain(&mut schema, #key, #value);));
+        let default = attrs.default.or(if container.default { Some(DefaultValue::Container) } else { None });
+        let default = match default {
+            // Without a default, serde only accepts a missing field for an `Option`.
+            None => quote! {
+                if !<#ty as ::solagent_parameters::ToolParameters>::is_optional() {
+                    required.push(::solagent_parameters::__private::serde_json::Value::from(#name));
+                }
+            },
+            Some(default) => {
+                let value = match default {
+                    DefaultValue::Default => quote!(<#ty as ::std::default::Default>::default()),
+                    DefaultValue::Path(path) => quote!(#path()),
+                    DefaultValue::Container => quote!(<Self as ::std::default::Default>::default().#ident),
+                };
+                quote! {
+                    let default: #ty = #value;
+                    ::solagent_parameters::__private::set_default(&mut schema, &default);
+                }
+            }
+        };
+
+        properties.push(quote! {
+            let mut schema = <#ty as ::solagent_parameters::ToolParameters>::schema();
+            ::solagent_parameters::__private::describe(&mut schema, #doc);
+            #(#constraints)*
+            #default
+            properties.insert(#name.to_string(), schema);
+        });
+    }
+
+    let deny_unknown_fields = container.deny_unknown_fields;
+    Ok(quote! {
+        let mut properties = ::solagent_parameters::__private::serde_json::Map::new();
+        let mut required: ::std::vec::Vec<::solagent_parameters::__private::serde_json::Value> = ::std::vec::Vec::new();
+        #(#properties)*
+
+        let mut schema = ::solagent_parameters::__private::serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        });
+        if #deny_unknown_fields {
+            schema["additionalProperties"] = false.into();
+        }
+        ::solagent_parameters::__private::describe(&mut schema, #description);
+        schema
+    })
+}
+
+/// The JSON Schema keywords set by the `#[parameters(...)]` attributes of a field, with their values.
+fn constraints(attrs: &[Attribute]) -> syn::Result<Vec<(&'static str, TokenStream2)>> {
+    let json = quote!(::solagent_parameters::__private::serde_json::json);
+    let mut constraints = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("parameters")) {
+        attr.parse_nested_meta(|meta| {
+            let key = ["minimum", "maximum", "exclusive_minimum", "exclusive_maximum", "min_length", "max_length"]
+                .into_iter()
+                .find(|key| meta.path.is_ident(key));
+            if meta.path.is_ident("address") {
+                constraints.push(("format", quote!(#json!(::solagent_parameters::SOLANA_ADDRESS))));
+            } else if meta.path.is_ident("decimals") {
+                let decimals: syn::LitInt = meta.value()?.parse()?;
+                let decimals = decimals.base10_parse::<i32>()?;
+                constraints.push(("multipleOf", quote!(#json!(10f64.powi(-#decimals)))));
+            } else if let Some(key) = key {
+                let value: syn::Expr = meta.value()?.parse()?;
+                constraints.push((camel_case(key), quote!(#json!(#value))));
+            } else {
+                return Err(meta.error("unknown `parameters` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(constraints)
+}
+
+fn camel_case(key: &str) -> &'static str {
+    match key {
+        "exclusive_minimum" => "exclusiveMinimum",
+        "exclusive_maximum" => "exclusiveMaximum",
+        "min_length" => "minLength",
+        "max_length" => "maxLength",
+        "minimum" => "minimum",
+        _ => "maximum",
+    }
+}
+
+/// The doc comment of an item, one line per line of comment; empty without one.
+fn docs(attrs: &[Attribute]) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(doc), .. }),
+                ..
+            }) => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .collect();
+    lines.join("\n").trim().to_string()
+}
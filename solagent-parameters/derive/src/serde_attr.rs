@@ -0,0 +1,241 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The parts of `#[serde(...)]` that change how a type is deserialized, and so its schema.
+
+use syn::{meta::ParseNestedMeta, Attribute, ExprPath, Ident, LitStr, Token};
+
+/// Attributes of the struct or enum.
+#[derive(Default)]
+pub struct Container {
+    pub rename_all: RenameRule,
+    pub default: bool,
+    pub deny_unknown_fields: bool,
+    /// `tag`, `content` or `untagged`: enums are no longer plain strings.
+    pub tagged: bool,
+}
+
+/// Attributes of a struct field.
+#[derive(Default)]
+pub struct Field {
+    pub rename: Option<String>,
+    pub default: Option<DefaultValue>,
+    pub skip: bool,
+    pub flatten: bool,
+}
+
+/// Attributes of an enum variant.
+#[derive(Default)]
+pub struct Variant {
+    pub rename: Option<String>,
+    pub skip: bool,
+}
+
+/// Where the value of a missing field comes from.
+pub enum DefaultValue {
+    /// `#[serde(default)]` on the field.
+    Default,
+    /// `#[serde(default = "path")]` on the field.
+    Path(ExprPath),
+    /// `#[serde(default)]` on the struct.
+    Container,
+}
+
+impl Container {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut container = Self::default();
+        for_each_meta(attrs, |meta| {
+            if meta.path.is_ident("rename_all") {
+                if let Some(rule) = deserialize_name(&meta)? {
+                    container.rename_all = RenameRule::from_str(&rule)
+                        .ok_or_else(|| meta.error(format!("unknown rename rule `{}`", rule)))?;
+                }
+            } else if meta.path.is_ident("default") {
+                container.default = true;
+                ignore(meta)?;
+            } else if meta.path.is_ident("deny_unknown_fields") {
+                container.deny_unknown_fields = true;
+            } else if meta.path.is_ident("tag") || meta.path.is_ident("content") || meta.path.is_ident("untagged") {
+                container.tagged = true;
+                ignore(meta)?;
+            } else {
+                ignore(meta)?;
+            }
+            Ok(())
+        })?;
+        Ok(container)
+    }
+}
+
+impl Field {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut field = Self::default();
+        for_each_meta(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                field.rename = deserialize_name(&meta)?.or(field.rename.take());
+            } else if meta.path.is_ident("default") {
+                field.default = Some(if meta.input.peek(Token![=]) {
+                    DefaultValue::Path(meta.value()?.parse::<LitStr>()?.parse()?)
+                } else {
+                    DefaultValue::Default
+                });
+            } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                field.skip = true;
+            } else if meta.path.is_ident("flatten") {
+                field.flatten = true;
+            } else {
+                ignore(meta)?;
+            }
+            Ok(())
+        })?;
+        Ok(field)
+    }
+}
+
+impl Variant {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut variant = Self::default();
+        for_each_meta(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                variant.rename = deserialize_name(&meta)?.or(variant.rename.take());
+            } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                variant.skip = true;
+            } else {
+                ignore(meta)?;
+            }
+            Ok(())
+        })?;
+        Ok(variant)
+    }
+}
+
+fn for_each_meta(attrs: &[Attribute], mut parse: impl FnMut(ParseNestedMeta) -> syn::Result<()>) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(&mut parse)?;
+    }
+    Ok(())
+}
+
+/// The name of `rename = "..."` or `rename(deserialize = "...")`; `None` when only the serialized
+/// name changes.
+fn deserialize_name(meta: &ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(Token![=]) {
+        return Ok(Some(meta.value()?.parse::<LitStr>()?.value()));
+    }
+    let mut name = None;
+    meta.parse_nested_meta(|inner| {
+        if inner.path.is_ident("deserialize") {
+            name = Some(inner.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            ignore(inner)
+        }
+    })?;
+    Ok(name)
+}
+
+/// Skips an attribute that does not change the schema, along with its value.
+fn ignore(meta: ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(ignore)?;
+    }
+    Ok(())
+}
+
+/// The case conventions of `#[serde(rename_all = "...")]`.
+#[derive(Default, Clone, Copy)]
+pub enum RenameRule {
+    #[default]
+    None,
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    fn from_str(rule: &str) -> Option<Self> {
+        Some(match rule {
+            "lowercase" => Self::LowerCase,
+            "UPPERCASE" => Self::UpperCase,
+            "PascalCase" => Self::PascalCase,
+            "camelCase" => Self::CamelCase,
+            "snake_case" => Self::SnakeCase,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnakeCase,
+            "kebab-case" => Self::KebabCase,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebabCase,
+            _ => return None,
+        })
+    }
+
+    /// Renames a `snake_case` field, as serde does.
+    pub fn apply_to_field(self, ident: &Ident) -> String {
+        let field = unraw(ident);
+        match self {
+            Self::None | Self::LowerCase | Self::SnakeCase => field,
+            Self::UpperCase | Self::ScreamingSnakeCase => field.to_ascii_uppercase(),
+            Self::PascalCase | Self::CamelCase => {
+                let mut pascal = String::new();
+                let mut capitalize = matches!(self, Self::PascalCase);
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+                pascal
+            }
+            Self::KebabCase => field.replace('_', "-"),
+            Self::ScreamingKebabCase => field.replace('_', "-").to_ascii_uppercase(),
+        }
+    }
+
+    /// Renames a `PascalCase` variant, as serde does.
+    pub fn apply_to_variant(self, ident: &Ident) -> String {
+        let variant = unraw(ident);
+        match self {
+            Self::None | Self::PascalCase => variant,
+            Self::LowerCase => variant.to_ascii_lowercase(),
+            Self::UpperCase => variant.to_ascii_uppercase(),
+            Self::CamelCase => variant[..1].to_ascii_lowercase() + &variant[1..],
+            Self::SnakeCase => {
+                let mut snake = String::new();
+                for (i, c) in variant.char_indices() {
+                    if i > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnakeCase => Self::SnakeCase.apply_to_variant(ident).to_ascii_uppercase(),
+            Self::KebabCase => Self::SnakeCase.apply_to_variant(ident).replace('_', "-"),
+            Self::ScreamingKebabCase => Self::ScreamingSnakeCase.apply_to_variant(ident).replace('_', "-"),
+        }
+    }
+}
+
+fn unraw(ident: &Ident) -> String {
+    ident.to_string().trim_start_matches("r#").to_string()
+}
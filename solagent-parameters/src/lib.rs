@@ -12,6 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+// The code of `#[derive(ToolParameters)]` names this crate, which includes its own tests.
+extern crate self as solagent_parameters;
+
+mod tool_parameters;
+pub use tool_parameters::ToolParameters;
+#[doc(hidden)]
+pub use tool_parameters::__private;
+
+mod validate;
+pub use validate::{validate, validate_args, ArgumentError, InvalidArguments, SOLANA_ADDRESS};
+
+/// Derives [`ToolParameters`] from a struct or enum and its serde attributes.
+pub use solagent_parameters_derive::ToolParameters;
+
+/// Builds a tool's parameter schema from a list of names and types.
+///
+/// Types are matched by name, so `f64` and `Option<T>` are described as objects and nothing is
+/// required. Prefer `#[derive(ToolParameters)]` on the args struct, whose schema follows serde.
 #[macro_export]
 macro_rules! parameters {
     ($($name:ident: $type:ty),* $(,)?) => {{
@@ -69,7 +87,6 @@ macro_rules! parameters {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use serde_json::json;
 
     #[test]
@@ -0,0 +1,337 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// A type that can be a tool argument, described by the JSON Schema of what serde deserializes it from.
+///
+/// Derive it on a tool's `*Args` struct and hand [`ToolParameters::schema`] to the model:
+///
+/// ```ignore
+/// #[derive(Deserialize, Serialize, ToolParameters)]
+/// pub struct TransferArgs {
+///     /// Recipient address, or the label of one of the agent's wallets.
+///     to: String,
+///     /// Amount in lamports, or in raw units of `mint`.
+///     amount: u64,
+///     mint: Option<String>,
+/// }
+///
+/// let parameters = TransferArgs::schema();
+/// ```
+pub trait ToolParameters {
+    /// The JSON Schema of this type.
+    fn schema() -> Value;
+
+    /// Whether an argument of this type may be left out; only `Option<T>` may.
+    fn is_optional() -> bool {
+        false
+    }
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {$(
+        impl ToolParameters for $ty {
+            fn schema() -> Value {
+                json!({ "type": "integer", "minimum": <$ty>::MIN, "maximum": <$ty>::MAX })
+            }
+        }
+    )*};
+}
+
+// Bounds past 2^53 are not exact as JSON numbers, only the sign is worth telling.
+macro_rules! wide_integer {
+    ($($ty:ty),*) => {$(
+        impl ToolParameters for $ty {
+            fn schema() -> Value {
+                if <$ty>::MIN == 0 {
+                    json!({ "type": "integer", "minimum": 0 })
+                } else {
+                    json!({ "type": "integer" })
+                }
+            }
+        }
+    )*};
+}
+
+integer!(i8, i16, i32, u8, u16, u32);
+wide_integer!(i64, i128, isize, u64, u128, usize);
+
+impl ToolParameters for f32 {
+    fn schema() -> Value {
+        json!({ "type": "number" })
+    }
+}
+
+impl ToolParameters for f64 {
+    fn schema() -> Value {
+        json!({ "type": "number" })
+    }
+}
+
+impl ToolParameters for bool {
+    fn schema() -> Value {
+        json!({ "type": "boolean" })
+    }
+}
+
+impl ToolParameters for String {
+    fn schema() -> Value {
+        json!({ "type": "string" })
+    }
+}
+
+impl ToolParameters for char {
+    fn schema() -> Value {
+        json!({ "type": "string", "minLength": 1, "maxLength": 1 })
+    }
+}
+
+/// Any JSON value.
+impl ToolParameters for Value {
+    fn schema() -> Value {
+        json!({})
+    }
+}
+
+impl<T: ToolParameters> ToolParameters for Option<T> {
+    fn schema() -> Value {
+        T::schema()
+    }
+
+    fn is_optional() -> bool {
+        true
+    }
+}
+
+impl<T: ToolParameters> ToolParameters for Box<T> {
+    fn schema() -> Value {
+        T::schema()
+    }
+
+    fn is_optional() -> bool {
+        T::is_optional()
+    }
+}
+
+impl<T: ToolParameters> ToolParameters for Vec<T> {
+    fn schema() -> Value {
+        json!({ "type": "array", "items": T::schema() })
+    }
+}
+
+impl<T: ToolParameters, S> ToolParameters for HashSet<T, S> {
+    fn schema() -> Value {
+        json!({ "type": "array", "items": T::schema(), "uniqueItems": true })
+    }
+}
+
+impl<T: ToolParameters> ToolParameters for BTreeSet<T> {
+    fn schema() -> Value {
+        json!({ "type": "array", "items": T::schema(), "uniqueItems": true })
+    }
+}
+
+impl<T: ToolParameters, S> ToolParameters for HashMap<String, T, S> {
+    fn schema() -> Value {
+        json!({ "type": "object", "additionalProperties": T::schema() })
+    }
+}
+
+impl<T: ToolParameters> ToolParameters for BTreeMap<String, T> {
+    fn schema() -> Value {
+        json!({ "type": "object", "additionalProperties": T::schema() })
+    }
+}
+
+/// A base58 address, as serde reads a `Pubkey`.
+#[cfg(feature = "solana")]
+impl ToolParameters for solana_pubkey::Pubkey {
+    fn schema() -> Value {
+        json!({ "type": "string", "format": crate::SOLANA_ADDRESS, "description": "A base58 Solana address." })
+    }
+}
+
+/// Used by the code of `#[derive(ToolParameters)]`.
+#[doc(hidden)]
+pub mod __private {
+    use serde::Serialize;
+    pub use serde_json;
+    use serde_json::Value;
+
+    /// Sets the description of `schema`, unless `description` is empty.
+    pub fn describe(schema: &mut Value, description: &str) {
+        if let (Value::Object(schema), false) = (schema, description.is_empty()) {
+            schema.insert("description".to_string(), description.into());
+        }
+    }
+
+    /// Adds a keyword of `#[parameters(...)]` to `schema`, or to its items for a list.
+    pub fn constrain(schema: &mut Value, key: &str, value: Value) {
+        let schema = if schema["type"] == "array" { &mut schema["items"] } else { schema };
+        if let Value::Object(schema) = schema {
+            schema.insert(key.to_string(), value);
+        }
+    }
+
+    /// Records the value a missing field takes.
+    pub fn set_default<T: Serialize>(schema: &mut Value, default: &T) {
+        match (schema, serde_json::to_value(default)) {
+            (Value::Object(schema), Ok(default)) if !default.is_null() => {
+                schema.insert("default".to_string(), default);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToolParameters;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    /// How a swap is routed.
+    #[derive(Deserialize, Serialize, ToolParameters, Default, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum Route {
+        /// Best price across all venues.
+        #[default]
+        BestPrice,
+        DirectOnly,
+        #[serde(rename = "jupiter")]
+        Aggregator,
+        #[serde(skip)]
+        #[allow(dead_code)]
+        Internal,
+    }
+
+    #[derive(Deserialize, Serialize, ToolParameters)]
+    struct Creator {
+        address: String,
+        share: u8,
+    }
+
+    fn default_slippage() -> u16 {
+        50
+    }
+
+    /// Swap one token for another.
+    #[derive(Deserialize, Serialize, ToolParameters)]
+    #[serde(deny_unknown_fields)]
+    struct SwapArgs {
+        /// Mint of the token to buy.
+        output_mint: String,
+        /// Amount to sell,
+        /// in UI units.
+        amount: f64,
+        lamports: u64,
+        input_mint: Option<String>,
+        #[serde(default = "default_slippage")]
+        slippage_bps: u16,
+        #[serde(default)]
+        route: Route,
+        #[serde(default)]
+        dry_run: bool,
+        #[serde(rename = "tags")]
+        labels: Vec<String>,
+        creators: Option<Vec<Creator>>,
+        #[serde(skip)]
+        #[allow(dead_code)]
+        cache: Option<String>,
+    }
+
+    #[test]
+    fn test_derive_struct() {
+        let schema = SwapArgs::schema();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["description"], "Swap one token for another.");
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(schema["required"], json!(["output_mint", "amount", "lamports", "tags"]));
+
+        let properties = &schema["properties"];
+        assert_eq!(properties["output_mint"], json!({ "type": "string", "description": "Mint of the token to buy." }));
+        assert_eq!(properties["amount"], json!({ "type": "number", "description": "Amount to sell,\nin UI units." }));
+        assert_eq!(properties["lamports"], json!({ "type": "integer", "minimum": 0 }));
+        assert_eq!(properties["input_mint"], json!({ "type": "string" }));
+        assert_eq!(
+            properties["slippage_bps"],
+            json!({ "type": "integer", "minimum": 0, "maximum": 65535, "default": 50 })
+        );
+        assert_eq!(properties["route"]["default"], "best_price");
+        assert_eq!(properties["dry_run"], json!({ "type": "boolean", "default": false }));
+        assert_eq!(properties["tags"], json!({ "type": "array", "items": { "type": "string" } }));
+        assert_eq!(properties["creators"]["items"]["required"], json!(["address", "share"]));
+        assert!(properties.get("labels").is_none());
+        assert!(properties.get("cache").is_none());
+    }
+
+    #[test]
+    fn test_derive_enum() {
+        assert_eq!(
+            Route::schema(),
+            json!({
+                "type": "string",
+                "enum": ["best_price", "direct_only", "jupiter"],
+                "description": "How a swap is routed.\n- `best_price`: Best price across all venues.",
+            })
+        );
+    }
+
+    #[test]
+    fn test_derive_container_default() {
+        #[derive(Deserialize, Serialize, ToolParameters)]
+        #[serde(default, rename_all = "camelCase")]
+        struct Options {
+            initial_liquidity_sol: f64,
+            priority_fee: f64,
+        }
+
+        impl Default for Options {
+            fn default() -> Self {
+                Self { initial_liquidity_sol: 0.0001, priority_fee: 0.00005 }
+            }
+        }
+
+        let schema = Options::schema();
+        assert_eq!(schema["required"], json!([]));
+        assert_eq!(schema["properties"]["initialLiquiditySol"]["default"], 0.0001);
+        assert_eq!(schema["properties"]["priorityFee"]["default"], 0.00005);
+    }
+
+    /// The schema has to agree with what serde accepts.
+    #[test]
+    fn test_schema_matches_deserialize() {
+        let schema = SwapArgs::schema();
+        let required = schema["required"].as_array().unwrap();
+        let minimal: serde_json::Map<String, serde_json::Value> = required
+            .iter()
+            .map(|name| {
+                let value = match name.as_str().unwrap() {
+                    "amount" | "lamports" => json!(1),
+                    "tags" => json!([]),
+                    _ => json!("x"),
+                };
+                (name.as_str().unwrap().to_string(), value)
+            })
+            .collect();
+        let args: SwapArgs = serde_json::from_value(minimal.into()).unwrap();
+        assert_eq!(args.slippage_bps, 50);
+        assert_eq!(args.route, Route::BestPrice);
+
+        let route: Route = serde_json::from_value(json!("jupiter")).unwrap();
+        assert_eq!(route, Route::Aggregator);
+    }
+}
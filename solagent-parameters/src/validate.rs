@@ -0,0 +1,366 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ToolParameters;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// The `format` of strings holding a base58 Solana address.
+pub const SOLANA_ADDRESS: &str = "solana-address";
+
+/// An argument that does not satisfy the tool's schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArgumentError {
+    /// Path of the argument, e.g. `amount`, `options.slippage_bps` or `tags[2]`.
+    pub field: String,
+    pub message: String,
+}
+
+/// Every argument of a tool call that does not satisfy the tool's schema.
+///
+/// It is written for the model to read, so that it can correct the call: each error names its field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvalidArguments {
+    pub errors: Vec<ArgumentError>,
+}
+
+impl fmt::Display for InvalidArguments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let errors: Vec<String> =
+            self.errors.iter().map(|error| format!("`{}` {}", error.field, error.message)).collect();
+        write!(f, "{}", errors.join("; "))
+    }
+}
+
+impl std::error::Error for InvalidArguments {}
+
+/// Checks the arguments of a tool call, as the model sent them, against the tool's schema.
+///
+/// The subset of JSON Schema written by `#[derive(ToolParameters)]` is checked: `type`, `enum`,
+/// `required`, `properties`, `additionalProperties`, `items`, `uniqueItems`, numeric bounds,
+/// `multipleOf`, string lengths and the [`SOLANA_ADDRESS`] format. A `null` stands for a missing
+/// optional argument.
+pub fn validate(schema: &Value, args: &Value) -> Result<(), InvalidArguments> {
+    let mut errors = Vec::new();
+    check(schema, args, "", &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(InvalidArguments { errors })
+    }
+}
+
+/// Checks deserialized tool arguments against their own schema.
+///
+/// serde already enforced the types; this catches values out of range, malformed addresses and
+/// the like before they reach a plugin.
+pub fn validate_args<A: ToolParameters + Serialize>(args: &A) -> Result<(), InvalidArguments> {
+    let value = serde_json::to_value(args).map_err(|e| InvalidArguments {
+        errors: vec![ArgumentError { field: String::new(), message: format!("cannot be serialized: {}", e) }],
+    })?;
+    validate(&A::schema(), &value)
+}
+
+fn check(schema: &Value, value: &Value, path: &str, errors: &mut Vec<ArgumentError>) {
+    let Value::Object(schema) = schema else { return };
+    let mut error = |message: String| errors.push(ArgumentError { field: field(path), message });
+
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        if !has_type(value, expected) {
+            return error(format!("must be {}", article(expected)));
+        }
+    }
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            let allowed: Vec<String> = allowed.iter().map(|value| format!("`{}`", plain(value))).collect();
+            return error(format!("must be one of {}", allowed.join(", ")));
+        }
+    }
+
+    match value {
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or(f64::NAN);
+            if !number.is_finite() {
+                return error("must be a finite number".to_string());
+            }
+            let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+            if let Some(minimum) = bound("minimum").filter(|minimum| number < *minimum) {
+                error(format!("must be at least {}", minimum));
+            } else if let Some(minimum) = bound("exclusiveMinimum").filter(|minimum| number <= *minimum) {
+                error(format!("must be greater than {}", minimum));
+            }
+            if let Some(maximum) = bound("maximum").filter(|maximum| number > *maximum) {
+                error(format!("must be at most {}", maximum));
+            } else if let Some(maximum) = bound("exclusiveMaximum").filter(|maximum| number >= *maximum) {
+                error(format!("must be less than {}", maximum));
+            }
+            if let Some(multiple) = bound("multipleOf").filter(|multiple| *multiple > 0.0) {
+                let quotient = number / multiple;
+                if (quotient - quotient.round()).abs() > 1e-6 {
+                    error(match decimals(multiple) {
+                        Some(0) => "must be a whole number".to_string(),
+                        Some(decimals) => format!("must have at most {} decimal places", decimals),
+                        None => format!("must be a multiple of {}", multiple),
+                    });
+                }
+            }
+        }
+        Value::String(string) => {
+            let length = string.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64).filter(|min| length < *min) {
+                error(format!("must be at least {} characters long", min));
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64).filter(|max| length > *max) {
+                error(format!("must be at most {} characters long", max));
+            }
+            if schema.get("format").and_then(Value::as_str) == Some(SOLANA_ADDRESS) && !is_address(string) {
+                error(format!("is not a valid Solana address: `{}`", string));
+            }
+        }
+        Value::Array(items) => {
+            if let Some(items_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(items_schema, item, &format!("{}[{}]", path, index), errors);
+                }
+            }
+            if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+                let duplicate = items.iter().enumerate().any(|(index, item)| items[..index].contains(item));
+                if duplicate {
+                    errors.push(ArgumentError { field: field(path), message: "must not repeat items".to_string() });
+                }
+            }
+        }
+        Value::Object(object) => check_object(schema, object, path, errors),
+        Value::Bool(_) | Value::Null => {}
+    }
+}
+
+fn check_object(schema: &Map<String, Value>, object: &Map<String, Value>, path: &str, errors: &mut Vec<ArgumentError>) {
+    let empty = Map::new();
+    let properties = schema.get("properties").and_then(Value::as_object).unwrap_or(&empty);
+    let required: Vec<&str> =
+        schema.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str).collect();
+
+    for name in &required {
+        if object.get(*name).is_none_or(Value::is_null) {
+            errors.push(ArgumentError { field: join(path, name), message: "is required".to_string() });
+        }
+    }
+    for (name, value) in object {
+        let field_path = join(path, name);
+        match properties.get(name) {
+            // A missing optional argument.
+            Some(_) if value.is_null() && !required.contains(&name.as_str()) => {}
+            Some(property) => check(property, value, &field_path, errors),
+            None => match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => {
+                    errors.push(ArgumentError { field: field_path, message: "is not a known argument".to_string() })
+                }
+                Some(additional @ Value::Object(_)) => check(additional, value, &field_path, errors),
+                _ => {}
+            },
+        }
+    }
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn article(ty: &str) -> String {
+    match ty {
+        "integer" | "array" | "object" => format!("an {}", ty),
+        "null" => "null".to_string(),
+        _ => format!("a {}", ty),
+    }
+}
+
+/// `k` when `multiple` is `10^-k`.
+fn decimals(multiple: f64) -> Option<u32> {
+    let decimals = -multiple.log10();
+    (decimals >= 0.0 && (decimals - decimals.round()).abs() < 1e-9).then(|| decimals.round() as u32)
+}
+
+fn plain(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn field(path: &str) -> String {
+    if path.is_empty() {
+        "arguments".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+/// Whether `address` is the base58 encoding of 32 bytes.
+fn is_address(address: &str) -> bool {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    if address.is_empty() || address.len() > 44 {
+        return false;
+    }
+
+    // Big-endian base-256 digits of the number, then one zero byte per leading '1'.
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in address.bytes() {
+        let Some(mut carry) = ALPHABET.iter().position(|a| *a == c).map(|digit| digit as u32) else {
+            return false;
+        };
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = address.bytes().take_while(|c| *c == b'1').count();
+    zeros + bytes.len() == 32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    /// How a message is encoded.
+    #[derive(Deserialize, Serialize, ToolParameters)]
+    #[serde(rename_all = "lowercase")]
+    enum Format {
+        Offchain,
+        Raw,
+    }
+
+    #[derive(Deserialize, Serialize, ToolParameters)]
+    #[serde(deny_unknown_fields)]
+    struct TradeArgs {
+        #[parameters(address)]
+        output_mint: String,
+        #[parameters(exclusive_minimum = 0, decimals = 9)]
+        input_amount: f64,
+        #[parameters(address)]
+        input_mint: Option<String>,
+        #[parameters(maximum = 10_000)]
+        slippage_bps: Option<u32>,
+        #[parameters(address)]
+        accounts: Vec<String>,
+        format: Option<Format>,
+    }
+
+    const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    fn fields(result: Result<(), InvalidArguments>) -> Vec<String> {
+        result.unwrap_err().errors.into_iter().map(|error| format!("{} {}", error.field, error.message)).collect()
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        let schema = TradeArgs::schema();
+        let args = json!({ "output_mint": USDC, "input_amount": 1.5, "accounts": [USDC], "input_mint": null });
+        assert_eq!(validate(&schema, &args), Ok(()));
+
+        let args = TradeArgs {
+            output_mint: USDC.to_string(),
+            input_amount: 0.000000001,
+            input_mint: Some("So11111111111111111111111111111111111111112".to_string()),
+            slippage_bps: Some(10_000),
+            accounts: vec!["11111111111111111111111111111111".to_string()],
+            format: Some(Format::Raw),
+        };
+        assert_eq!(validate_args(&args), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_invalid() {
+        let schema = TradeArgs::schema();
+        let args = json!({
+            "input_amount": -1.0000000001,
+            "input_mint": "not-a-mint",
+            "slippage_bps": 20_000,
+            "accounts": [USDC, "0OIl"],
+            "format": "base64",
+            "amount": 1,
+        });
+        assert_eq!(
+            fields(validate(&schema, &args)),
+            [
+                "output_mint is required",
+                "accounts[1] is not a valid Solana address: `0OIl`",
+                "amount is not a known argument",
+                "format must be one of `offchain`, `raw`",
+                "input_amount must be greater than 0",
+                "input_amount must have at most 9 decimal places",
+                "input_mint is not a valid Solana address: `not-a-mint`",
+                "slippage_bps must be at most 10000",
+            ]
+        );
+
+        let error = validate(&schema, &json!({ "output_mint": 1, "input_amount": "1", "accounts": [] })).unwrap_err();
+        assert_eq!(error.to_string(), "`input_amount` must be a number; `output_mint` must be a string");
+        assert_eq!(fields(validate(&schema, &json!([]))), ["arguments must be an object"]);
+    }
+
+    #[test]
+    fn test_validate_integers() {
+        #[derive(Deserialize, Serialize, ToolParameters)]
+        struct Args {
+            lamports: u64,
+            #[parameters(minimum = 1, maximum = 1000)]
+            limit: u32,
+        }
+        let schema = Args::schema();
+        assert_eq!(validate(&schema, &json!({ "lamports": 1, "limit": 1000 })), Ok(()));
+        assert_eq!(
+            fields(validate(&schema, &json!({ "lamports": -1, "limit": 0 }))),
+            ["lamports must be at least 0", "limit must be at least 1"]
+        );
+        assert_eq!(fields(validate(&schema, &json!({ "lamports": 1.5, "limit": 1 }))), ["lamports must be an integer"]);
+    }
+
+    #[test]
+    fn test_is_address() {
+        assert!(is_address(USDC));
+        assert!(is_address("11111111111111111111111111111111"));
+        assert!(is_address("So11111111111111111111111111111111111111112"));
+        // 31 bytes, an invalid character, too long
+        assert!(!is_address("1111111111111111111111111111111"));
+        assert!(!is_address("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt10"));
+        assert!(!is_address(&format!("{}{}", USDC, USDC)));
+        assert!(!is_address(""));
+    }
+}
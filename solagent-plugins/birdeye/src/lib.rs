@@ -1,5 +1,4 @@
-use anyhow::Result;
-use solagent_core::SolanaAgentKit;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 mod primitive;
 pub use primitive::*;
@@ -15,18 +14,16 @@ pub use primitive::*;
 /// # Returns
 ///
 /// A `Result` TokenOverviewResponse
-pub async fn get_token_overview(agent: &SolanaAgentKit, address: &str) -> Result<TokenOverviewResponse> {
+pub async fn get_token_overview(agent: &SolanaAgentKit, address: &str) -> Result<TokenOverviewResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/token_overview", BIRDEYE_URL);
-
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/token_overview")
         .query(&[("address", address)])
         .header("X-API-KEY", api_key)
         .header("accept", "application/json")
@@ -50,18 +47,16 @@ pub async fn get_token_overview(agent: &SolanaAgentKit, address: &str) -> Result
 /// # Returns
 ///
 /// A `Result` TokenMarketDataResponse
-pub async fn get_market_data(agent: &SolanaAgentKit, address: &str) -> Result<TokenMarketDataResponse> {
+pub async fn get_market_data(agent: &SolanaAgentKit, address: &str) -> Result<TokenMarketDataResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/v3/token/market-data", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/v3/token/market-data")
         .query(&[("address", address)])
         .header("X-API-KEY", api_key)
         .header("accept", "application/json")
@@ -85,18 +80,19 @@ pub async fn get_market_data(agent: &SolanaAgentKit, address: &str) -> Result<To
 /// # Returns
 ///
 /// A `WalletPortfolioResponse`
-pub async fn get_wallet_portfolio(agent: &SolanaAgentKit, wallet_address: &str) -> Result<WalletPortfolioResponse> {
+pub async fn get_wallet_portfolio(
+    agent: &SolanaAgentKit,
+    wallet_address: &str,
+) -> Result<WalletPortfolioResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/v1/wallet/token_list", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
-    let response = client
-        .get(&url)
+    let response = agent
+        .http
+        .get(Service::Birdeye, "/v1/wallet/token_list")
         .query(&[("wallet", wallet_address)])
         .header("accept", "application/json")
         .header("X-API-KEY", api_key)
@@ -123,22 +119,20 @@ pub async fn get_wallet_portfolio(agent: &SolanaAgentKit, wallet_address: &str)
 pub async fn get_token_holders(
     agent: &SolanaAgentKit,
     query_params: TokenHolderQueryParams,
-) -> Result<TokenHolderResponse> {
+) -> Result<TokenHolderResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/v3/token/holder", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
     let address = query_params.address;
     let offset = query_params.offset.unwrap_or(0);
     let limit = query_params.limit.unwrap_or(100);
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/v3/token/holder")
         .query(&[("address", address), ("offset", offset.to_string()), ("limit", limit.to_string())])
         .header("X-API-KEY", api_key)
         .header("accept", "application/json")
@@ -162,18 +156,16 @@ pub async fn get_token_holders(
 /// # Returns
 ///
 /// A `Result` TokenMetadataResponse
-pub async fn get_token_metadata(agent: &SolanaAgentKit, address: &str) -> Result<TokenMetadataResponse> {
+pub async fn get_token_metadata(agent: &SolanaAgentKit, address: &str) -> Result<TokenMetadataResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/v3/token/meta-data/single", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/v3/token/meta-data/single")
         .query(&[("address", address)])
         .header("X-API-KEY", api_key)
         .header("accept", "application/json")
@@ -197,18 +189,16 @@ pub async fn get_token_metadata(agent: &SolanaAgentKit, address: &str) -> Result
 /// # Returns
 ///
 /// A `Result` TokenPriceResponse
-pub async fn get_token_price(agent: &SolanaAgentKit, address: &str) -> Result<TokenPriceResponse> {
+pub async fn get_token_price(agent: &SolanaAgentKit, address: &str) -> Result<TokenPriceResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/price", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/price")
         .query(&[("address", address)])
         .header("X-API-KEY", api_key)
         .header("accept", "application/json")
@@ -235,21 +225,19 @@ pub async fn get_token_price(agent: &SolanaAgentKit, address: &str) -> Result<To
 pub async fn get_token_price_volume(
     agent: &SolanaAgentKit,
     query_params: TokenPriceVolumeQueryParams,
-) -> Result<TokenPriceVolumeResponse> {
+) -> Result<TokenPriceVolumeResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/price_volume/single", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
     let address = query_params.address;
     let vh = query_params.vh;
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/price_volume/single")
         .query(&[("address", address), ("type", vh)])
         .header("X-API-KEY", api_key)
         .header("accept", "application/json")
@@ -276,23 +264,21 @@ pub async fn get_token_price_volume(
 pub async fn get_token_trending(
     agent: &SolanaAgentKit,
     query_params: TokenTrendingQueryParams,
-) -> Result<TokenTrendingResponse> {
+) -> Result<TokenTrendingResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/token_trending", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
     let sort_by = query_params.sort_by;
     let sort_type = query_params.sort_type;
     let offset = query_params.offset.unwrap_or(0);
     let limit = query_params.limit.unwrap_or(20);
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/token_trending")
         .query(&[
             ("sort_by", sort_by),
             ("sort_type", sort_type),
@@ -324,23 +310,21 @@ pub async fn get_token_trending(
 pub async fn get_token_mintburn_tx(
     agent: &SolanaAgentKit,
     query_params: TokenMintOrBurnQueryParams,
-) -> Result<TokenMintOrBurnResponse> {
+) -> Result<TokenMintOrBurnResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/v3/token/mint-burn-txs", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
     let address = query_params.address;
     let tx_type = query_params.tx_type;
     let offset = query_params.offset.unwrap_or(0);
     let limit = query_params.limit.unwrap_or(20);
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/v3/token/mint-burn-txs")
         .query(&[
             ("address", address),
             ("type", tx_type),
@@ -374,23 +358,21 @@ pub async fn get_token_mintburn_tx(
 pub async fn search_token_or_market_data(
     agent: &SolanaAgentKit,
     query_params: TokenOrMarketQueryParams,
-) -> Result<TokenOrMarketResponse> {
+) -> Result<TokenOrMarketResponse, SolagentError> {
     let api_key = agent
         .config
         .birdeye_api_key
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Missing Birdeye API key in agent.config.birdeye_api_key"))?;
-
-    let client = reqwest::Client::new();
-    let url = format!("{}/defi/v3/search", BIRDEYE_URL);
+        .ok_or_else(|| SolagentError::Config("Birdeye API key (agent.config.birdeye_api_key)".to_string()))?;
 
     let keyword = query_params.keyword;
     let target = query_params.target;
     let offset = query_params.offset.unwrap_or(0);
     let limit = query_params.limit.unwrap_or(20);
 
-    let resp = client
-        .get(url)
+    let resp = agent
+        .http
+        .get(Service::Birdeye, "/defi/v3/search")
         .query(&[
             ("chain", "solana".to_string()),
             ("keyworkd", keyword),
@@ -409,3 +391,64 @@ pub async fn search_token_or_market_data(
 
     Ok(resp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solagent_core::{Config, HttpProvider, Wallet};
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const BONK: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
+
+    fn kit(server: &MockServer, birdeye_api_key: Option<&str>) -> SolanaAgentKit {
+        let http = HttpProvider::builder().base_url(Service::Birdeye, server.uri()).build().unwrap();
+        let config = Config { birdeye_api_key: birdeye_api_key.map(str::to_string), ..Config::default() };
+        SolanaAgentKit::new(Wallet::new(), "https://api.devnet.solana.com", config).with_http(http)
+    }
+
+    #[tokio::test]
+    async fn test_get_market_data() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/defi/v3/token/market-data"))
+            .and(query_param("address", BONK))
+            .and(header("X-API-KEY", "birdeye-key"))
+            .and(header("x-chain", "solana"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "address": BONK,
+                    "price": 0.0000215,
+                    "liquidity": 12000000.5,
+                    "supply": 88000000000000.0,
+                    "total_supply": 88000000000000.0,
+                    "circulating_supply": 77000000000000.0,
+                    "marketcap": 1892000000.0,
+                    "fdv": 1892000000.0,
+                    "circulating_marketcap": 1655500000.0,
+                    "market_cap": 1892000000.0
+                },
+                "success": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = get_market_data(&kit(&server, Some("birdeye-key")), BONK).await.unwrap();
+        assert!(response.success);
+        assert_eq!(response.data.address, BONK);
+        assert_eq!(response.data.price, 0.0000215);
+        assert_eq!(response.data.liquidity, 12000000.5);
+    }
+
+    #[tokio::test]
+    async fn test_get_market_data_without_api_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET")).respond_with(ResponseTemplate::new(200)).expect(0).mount(&server).await;
+
+        let error = get_market_data(&kit(&server, None), BONK).await.unwrap_err();
+        assert!(matches!(error, SolagentError::Config(_)));
+    }
+}
@@ -1,7 +1,4 @@
 use serde::{Deserialize, Serialize};
-
-pub const BIRDEYE_URL: &str = "https://public-api.birdeye.so";
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TokenOverviewResponse {
     pub data: TokenData,
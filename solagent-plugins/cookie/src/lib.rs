@@ -1,6 +1,5 @@
 use serde_json::Value;
-use solagent_core::SolanaAgentKit;
-use std::error::Error;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 /// Retrieve agent details in specified interval by one of its tokens contract address.
 ///
@@ -17,18 +16,19 @@ pub async fn get_agent_by_ca(
     agent: &SolanaAgentKit,
     contract_address: &str,
     interval: Option<u32>,
-) -> Result<Value, Box<dyn Error>> {
+) -> Result<Value, SolagentError> {
     // Get the Cookie API key from the agent's configuration
     let api_key = match agent.config.cookie_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Cookie API key in agent.config.cookie_api_key".into()),
+        None => return Err(SolagentError::Config("Cookie API key (agent.config.cookie_api_key)".to_string())),
     };
 
-    let api_url = "https://api.cookie.fun/v2/agents/contractAddress";
-    let url = format!("{}/{}?interval=_{}Days", api_url, contract_address, interval.unwrap_or(7));
-    let client = reqwest::Client::new();
+    let path = format!("/v2/agents/contractAddress/{}?interval=_{}Days", contract_address, interval.unwrap_or(7));
 
-    let response = client.get(&url).header("x-api-key", api_key).send().await?;
+    let response = agent.http.get(Service::Cookie, &path).header("x-api-key", api_key).send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let json: Value = response.json().await?;
     Ok(json)
@@ -49,18 +49,19 @@ pub async fn get_agent_by_name(
     agent: &SolanaAgentKit,
     twitter_name: &str,
     interval: Option<u32>,
-) -> Result<Value, Box<dyn Error>> {
+) -> Result<Value, SolagentError> {
     // Get the Cookie API key from the agent's configuration
     let api_key = match agent.config.cookie_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Cookie API key in agent.config.cookie_api_key".into()),
+        None => return Err(SolagentError::Config("Cookie API key (agent.config.cookie_api_key)".to_string())),
     };
 
-    let api_url = "https://api.cookie.fun/v2/agents/twitterUsername";
-    let url = format!("{}/{}?interval=_{}Days", api_url, twitter_name, interval.unwrap_or(7));
-    let client = reqwest::Client::new();
+    let path = format!("/v2/agents/twitterUsername/{}?interval=_{}Days", twitter_name, interval.unwrap_or(7));
 
-    let response = client.get(&url).header("x-api-key", api_key).send().await?;
+    let response = agent.http.get(Service::Cookie, &path).header("x-api-key", api_key).send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let json: Value = response.json().await?;
     Ok(json)
@@ -78,23 +79,19 @@ pub async fn get_agent_by_name(
 /// # Returns
 ///
 /// A `Result` that tweets details
-pub async fn search_tweets(
-    agent: &SolanaAgentKit,
-    tweets: &str,
-    from: &str,
-    to: &str,
-) -> Result<Value, Box<dyn Error>> {
+pub async fn search_tweets(agent: &SolanaAgentKit, tweets: &str, from: &str, to: &str) -> Result<Value, SolagentError> {
     // Get the Cookie API key from the agent's configuration
     let api_key = match agent.config.cookie_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Cookie API key in agent.config.cookie_api_key".into()),
+        None => return Err(SolagentError::Config("Cookie API key (agent.config.cookie_api_key)".to_string())),
     };
 
-    let api_url = "https://api.cookie.fun/v1/hackathon/search";
-    let url = format!("{}/{}?from={}&to={}", api_url, tweets, from, to);
-    let client = reqwest::Client::new();
+    let path = format!("/v1/hackathon/search/{}?from={}&to={}", tweets, from, to);
 
-    let response = client.get(&url).header("x-api-key", api_key).send().await?;
+    let response = agent.http.get(Service::Cookie, &path).header("x-api-key", api_key).send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let json: Value = response.json().await?;
     Ok(json)
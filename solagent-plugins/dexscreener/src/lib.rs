@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::error::Error;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 // Define the Txns struct to represent transaction information
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -136,33 +136,29 @@ pub struct Extensions {
 ///
 /// # Parameters
 ///
+/// - `agent`: An instance of `SolanaAgentKit`.
 /// - `ticker`: Ticker of the token, e.g. 'USDC'
 ///
 /// # Returns
 ///
 /// A `Result`
-pub async fn get_token_data_by_ticker(ticker: &str) -> Result<DexTokenData, Box<dyn Error>> {
-    let client = reqwest::Client::new();
-    let address = get_token_address_from_ticker(&client, ticker).await?;
+pub async fn get_token_data_by_ticker(
+    agent: &SolanaAgentKit,
+    ticker: &str,
+) -> Result<DexTokenData, SolagentError> {
+    let address = get_token_address_from_ticker(agent, ticker).await?;
 
-    get_token_data_by_address(&client, &address).await
+    get_token_data_by_address(agent, &address).await
 }
 
 async fn get_token_address_from_ticker(
-    client: &reqwest::Client,
+    agent: &SolanaAgentKit,
     ticker: &str,
-) -> Result<String, Box<dyn Error>> {
-    let url = format!(
-        "https://api.dexscreener.com/latest/dex/search?q=${}",
-        ticker
-    );
-    let response = client.get(&url).send().await?;
+) -> Result<String, SolagentError> {
+    let path = format!("/latest/dex/search?q=${}", ticker);
+    let response = agent.http.get(Service::DexScreener, &path).send().await?;
     if !response.status().is_success() {
-        return Err(format!(
-            "Failed to get token address from ticker: {}",
-            response.status()
-        )
-        .into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: SearchTokenData = response.json().await?;
@@ -185,21 +181,19 @@ async fn get_token_address_from_ticker(
         .into_iter()
         .next()
         .map(|pair| pair.base_token.address)
-        .ok_or("get address error".into())
+        .ok_or_else(|| {
+            SolagentError::InvalidInput(format!("no Solana token found for ticker `{}`", ticker))
+        })
 }
 
 async fn get_token_data_by_address(
-    client: &reqwest::Client,
+    agent: &SolanaAgentKit,
     address: &str,
-) -> Result<DexTokenData, Box<dyn Error>> {
-    let url = format!("https://tokens.jup.ag/token/${}", address);
-    let response = client.get(&url).send().await?;
+) -> Result<DexTokenData, SolagentError> {
+    let path = format!("/token/${}", address);
+    let response = agent.http.get(Service::JupiterTokens, &path).send().await?;
     if !response.status().is_success() {
-        return Err(format!(
-            "Failed to get token address from ticker: {}",
-            response.status()
-        )
-        .into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: DexTokenData = response.json().await?;
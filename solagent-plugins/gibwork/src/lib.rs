@@ -1,10 +1,8 @@
 use base64::{engine::general_purpose, Engine};
 use serde::{Deserialize, Serialize};
 use solagent_core::{
-    solana_sdk::{
-        commitment_config::CommitmentConfig, pubkey::Pubkey, transaction::VersionedTransaction,
-    },
-    SolanaAgentKit,
+    solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction},
+    Network, Service, SolagentError, SolanaAgentKit, TransactionIntent, TransactionOutcome,
 };
 
 #[derive(Serialize)]
@@ -36,7 +34,8 @@ struct TaskResponse {
 pub struct GibworkCreateTaskResponse {
     pub status: String,
     pub task_id: String,
-    pub signature: String,
+    pub transaction: TransactionOutcome,
+    pub network: Network,
 }
 
 /// Create a new task on Gibwork
@@ -50,7 +49,7 @@ pub struct GibworkCreateTaskResponse {
 /// * `tags` - List of tags associated with the task
 /// * `token_mint_address` - Token mint address for payment
 /// * `token_amount` - Payment amount for the task
-/// * `payer` - Optional payer address (defaults to agent's wallet address)
+/// * `payer` - Optional payer address (defaults to the agent's default wallet address)
 ///
 /// # Returns
 ///
@@ -65,13 +64,17 @@ pub async fn create_gibwork_task(
     token_mint_address: &str,
     token_amount: u64,
     payer: Option<Pubkey>,
-) -> Result<GibworkCreateTaskResponse, Box<dyn std::error::Error>> {
+) -> Result<GibworkCreateTaskResponse, SolagentError> {
+    agent.require_mainnet("create_gibwork_task")?;
+
     let request = TaskRequest {
         title: title.to_string(),
         content: content.to_string(),
         requirements: requirements.to_string(),
         tags,
-        payer: payer.unwrap_or(agent.wallet.pubkey).to_string(),
+        payer: payer
+            .unwrap_or(agent.wallets.default_wallet().pubkey())
+            .to_string(),
         token: TokenInfo {
             mint_address: token_mint_address.to_string(),
             amount: token_amount,
@@ -79,44 +82,41 @@ pub async fn create_gibwork_task(
     };
 
     // Send request to Gibwork API
-    let client = reqwest::Client::new();
-    let response = client
-        .post("https://api2.gib.work/tasks/public/transaction")
+    let response = agent
+        .http
+        .post(Service::Gibwork, "/tasks/public/transaction")
         .json(&request)
         .send()
         .await?;
 
     if !response.status().is_success() {
-        return Err(format!("API request failed: {}", response.status()).into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let task_response: TaskResponse = response.json().await?;
 
     // Deserialize and sign transaction
-    let transaction_data =
-        general_purpose::STANDARD.decode(task_response.serialized_transaction.as_str())?;
-
-    let mut versioned_transaction: VersionedTransaction = bincode::deserialize(&transaction_data)?;
+    let transaction_data = general_purpose::STANDARD
+        .decode(task_response.serialized_transaction.as_str())
+        .map_err(|e| SolagentError::Decode(format!("gibwork transaction: {}", e)))?;
 
-    // Get latest blockhash and sign transaction
-    let blockhash = agent.connection.get_latest_blockhash()?;
-    versioned_transaction
-        .message
-        .set_recent_blockhash(blockhash);
-    let signed_transaction =
-        VersionedTransaction::try_new(versioned_transaction.message, &[&agent.wallet.keypair])?;
+    let versioned_transaction: VersionedTransaction = bincode::deserialize(&transaction_data)
+        .map_err(|e| SolagentError::Decode(format!("gibwork transaction: {}", e)))?;
 
-    // Send and confirm transaction
-    let signature = agent.connection.send_transaction(&signed_transaction)?;
-    agent.connection.confirm_transaction_with_spinner(
-        &signature,
-        &blockhash,
-        CommitmentConfig::confirmed(),
-    )?;
+    // Sign, send and confirm transaction
+    let transaction = agent
+        .sender()
+        .send_transaction(
+            &TransactionIntent::new("create_gibwork_task"),
+            versioned_transaction,
+            &[],
+        )
+        .await?;
 
     Ok(GibworkCreateTaskResponse {
         status: "success".to_string(),
         task_id: task_response.task_id,
-        signature: signature.to_string(),
+        transaction,
+        network: agent.network.clone(),
     })
 }
@@ -12,45 +12,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
+
 pub async fn get_token_security_info(
+    agent: &SolanaAgentKit,
     chain_id: &str,
     contract_address: &str,
-) -> Result<serde_json::Value, reqwest::Error> {
-    let url =
-        format!("https://api.gopluslabs.io/api/v1/token_security/{}?contract_addresses={}", chain_id, contract_address);
+) -> Result<serde_json::Value, SolagentError> {
+    let path = format!("/token_security/{}?contract_addresses={}", chain_id, contract_address);
 
-    let client = reqwest::Client::new();
-    let response = client.get(url).header("Content-Type", "application/json").send().await?;
+    let response = agent.http.get(Service::GoPlus, &path).header("Content-Type", "application/json").send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data = response.json::<serde_json::Value>().await?;
     Ok(data)
 }
 
-pub async fn get_solana_token_security_info(contract_address: &str) -> Result<serde_json::Value, reqwest::Error> {
-    let url = format!("https://api.gopluslabs.io/api/v1/solana/token_security?contract_addresses={}", contract_address);
+pub async fn get_solana_token_security_info(
+    agent: &SolanaAgentKit,
+    contract_address: &str,
+) -> Result<serde_json::Value, SolagentError> {
+    let path = format!("/solana/token_security?contract_addresses={}", contract_address);
 
-    let client = reqwest::Client::new();
-    let response = client.get(url).header("Content-Type", "application/json").send().await?;
+    let response = agent.http.get(Service::GoPlus, &path).header("Content-Type", "application/json").send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data = response.json::<serde_json::Value>().await?;
     Ok(data)
 }
 
-pub async fn get_token_malicious_info(chain_id: &str, address: &str) -> Result<serde_json::Value, reqwest::Error> {
-    let url = format!("https://api.gopluslabs.io/api/v1/address_security/{}?chain_id={}", address, chain_id);
+pub async fn get_token_malicious_info(
+    agent: &SolanaAgentKit,
+    chain_id: &str,
+    address: &str,
+) -> Result<serde_json::Value, SolagentError> {
+    let path = format!("/address_security/{}?chain_id={}", address, chain_id);
 
-    let client = reqwest::Client::new();
-    let response = client.get(url).header("Content-Type", "application/json").send().await?;
+    let response = agent.http.get(Service::GoPlus, &path).header("Content-Type", "application/json").send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data = response.json::<serde_json::Value>().await?;
     Ok(data)
 }
 
-pub async fn get_token_phishing_site_info(url: &str) -> Result<serde_json::Value, reqwest::Error> {
-    let url = format!("https://api.gopluslabs.io/api/v1/aphishing_site?url={}", url);
-
-    let client = reqwest::Client::new();
-    let response = client.get(url).header("Content-Type", "application/json").send().await?;
+pub async fn get_token_phishing_site_info(
+    agent: &SolanaAgentKit,
+    url: &str,
+) -> Result<serde_json::Value, SolagentError> {
+    let response = agent
+        .http
+        .get(Service::GoPlus, "/aphishing_site")
+        .query(&[("url", url)])
+        .header("Content-Type", "application/json")
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data = response.json::<serde_json::Value>().await?;
     Ok(data)
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::SolanaAgentKit;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 #[derive(Deserialize, Serialize)]
 pub struct HeliusWebhookResponse {
@@ -25,14 +25,14 @@ pub async fn create_webhook(
     agent: &SolanaAgentKit,
     account_addresses: Vec<String>,
     webhook_url: String,
-) -> Result<HeliusWebhookResponse, Box<dyn std::error::Error>> {
+) -> Result<HeliusWebhookResponse, SolagentError> {
     // Get the Helius API key from the agent's configuration
     let api_key = match agent.config.helius_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Helius API key in agent.config.HELIUS_API_KEY".into()),
+        None => return Err(SolagentError::Config("Helius API key (agent.config.helius_api_key)".to_string())),
     };
 
-    let url = format!("https://api.helius.xyz/v0/webhooks?api-key={}", api_key);
+    let path = format!("/webhooks?api-key={}", api_key);
 
     let body = serde_json::json!({
         "webhookURL": webhook_url,
@@ -42,12 +42,21 @@ pub async fn create_webhook(
         "txnStatus": "all",
     });
 
-    let client = reqwest::Client::new();
-    let response = client.post(url).header("Content-Type", "application/json").json(&body).send().await?;
+    let response =
+        agent.http.post(Service::Helius, &path).header("Content-Type", "application/json").json(&body).send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data = response.json::<serde_json::Value>().await?;
-    let webhook_url = data.get("webhookURL").expect("webhookURL field").as_str().expect("webhookURL text");
-    let webhook_id = data.get("webhookID").expect("webhookID field").as_str().expect("webhookID text");
+    let webhook_url = data
+        .get("webhookURL")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| SolagentError::Decode("webhook response: missing `webhookURL` field".to_string()))?;
+    let webhook_id = data
+        .get("webhookID")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| SolagentError::Decode("webhook response: missing `webhookID` field".to_string()))?;
 
     Ok(HeliusWebhookResponse { webhook_url: webhook_url.to_string(), webhook_id: webhook_id.to_string() })
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use solagent_core::SolanaAgentKit;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 /// Deletes a Helius Webhook by its ID.
 ///
@@ -22,33 +22,22 @@ use solagent_core::SolanaAgentKit;
 ///
 /// # Returns
 /// The response body from the Helius API (which may contain status or other info)
-pub async fn delete_webhook(
-    agent: &SolanaAgentKit,
-    webhook_id: &str,
-) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+pub async fn delete_webhook(agent: &SolanaAgentKit, webhook_id: &str) -> Result<serde_json::Value, SolagentError> {
     // Get the Helius API key from the agent's configuration
     let api_key = match agent.config.helius_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Helius API key in agent.config.HELIUS_API_KEY".into()),
+        None => return Err(SolagentError::Config("Helius API key (agent.config.helius_api_key)".to_string())),
     };
 
     // Construct the URL for the DELETE request
-    let url = format!("https://api.helius.xyz/v0/webhooks/{}?api-key={}", webhook_id, api_key);
-
-    // Create an HTTP client
-    let client = reqwest::Client::new();
+    let path = format!("/webhooks/{}?api-key={}", webhook_id, api_key);
 
     // Send the DELETE request
-    let response = client.delete(&url).header("Content-Type", "application/json").send().await?;
+    let response = agent.http.delete(Service::Helius, &path).header("Content-Type", "application/json").send().await?;
 
     // Check if the request was successful
     if !response.status().is_success() {
-        return Err(format!(
-            "Failed to delete webhook: {} {}",
-            response.status(),
-            response.status().canonical_reason().unwrap_or("Unknown")
-        )
-        .into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     // Handle different response status codes
@@ -57,8 +46,8 @@ pub async fn delete_webhook(
     }
 
     // Check if the response body is empty
-    let content_length = response.headers().get("Content-Length");
-    if content_length.is_none() || content_length.expect("HeaderValue").to_str()? == "0" {
+    let content_length = response.headers().get("Content-Length").and_then(|v| v.to_str().ok());
+    if matches!(content_length, None | Some("0")) {
         return Ok(serde_json::json!({"message": "Webhook deleted successfully (empty body)"}));
     }
 
@@ -13,22 +13,31 @@
 // limitations under the License.
 
 use serde_json::json;
-use solagent_core::SolanaAgentKit;
+use solagent_core::{Network, Service, SolagentError, SolanaAgentKit};
 
 pub async fn get_assets_by_owner(
     agent: &SolanaAgentKit,
     owner_public_key: &str,
     limit: u32,
-) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+) -> Result<serde_json::Value, SolagentError> {
     // Get the Helius API key from the agent's configuration
     let api_key = match agent.config.helius_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Helius API key in agent.config.HELIUS_API_KEY".into()),
+        None => return Err(SolagentError::Config("Helius API key (agent.config.helius_api_key)".to_string())),
     };
 
-    let url = format!("https://mainnet.helius-rpc.com/?api-key={}", api_key);
-
-    let client = reqwest::Client::new();
+    let base_url = match (agent.http.base_url_override(Service::HeliusRpc), &agent.network) {
+        (Some(url), _) => url,
+        (None, Network::Mainnet | Network::Custom(_)) => Service::HeliusRpc.default_base_url(),
+        (None, Network::Devnet) => "https://devnet.helius-rpc.com",
+        (None, _) => {
+            return Err(SolagentError::UnsupportedNetwork {
+                action: "get_assets_by_owner".to_string(),
+                network: agent.network.clone(),
+            })
+        }
+    };
+    let url = format!("{}/?api-key={}", base_url.trim_end_matches('/'), api_key);
 
     let request_body = json!({
         "jsonrpc": "2.0",
@@ -42,15 +51,11 @@ pub async fn get_assets_by_owner(
         }),
     });
 
-    let response = client.post(&url).header("Content-Type", "application/json").json(&request_body).send().await?;
+    let response =
+        agent.http.client().post(&url).header("Content-Type", "application/json").json(&request_body).send().await?;
 
     if !response.status().is_success() {
-        return Err(format!(
-            "Failed to fetch: {} - {}",
-            response.status(),
-            response.status().canonical_reason().unwrap_or("Unknown")
-        )
-        .into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: serde_json::Value = response.json().await?;
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
-use solagent_core::SolanaAgentKit;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HeliusWebhookIdResponse {
@@ -32,20 +32,19 @@ pub struct HeliusWebhookIdResponse {
 ///
 /// # Returns
 /// A HeliusWebhook object containing { wallet, webhookURL, transactionTypes, accountAddresses, webhookType }
-pub async fn get_webhook(
-    agent: &SolanaAgentKit,
-    webhook_id: &str,
-) -> Result<HeliusWebhookIdResponse, Box<dyn std::error::Error>> {
+pub async fn get_webhook(agent: &SolanaAgentKit, webhook_id: &str) -> Result<HeliusWebhookIdResponse, SolagentError> {
     // Get the Helius API key from the agent's configuration
     let api_key = match agent.config.helius_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Helius API key in agent.config.HELIUS_API_KEY".into()),
+        None => return Err(SolagentError::Config("Helius API key (agent.config.helius_api_key)".to_string())),
     };
 
-    let client = reqwest::Client::new();
-    let url = format!("https://api.helius.xyz/v0/webhooks/{}?api-key={}", webhook_id, api_key);
+    let path = format!("/webhooks/{}?api-key={}", webhook_id, api_key);
 
-    let response = client.get(url).header("Content-Type", "application/json").send().await?;
+    let response = agent.http.get(Service::Helius, &path).header("Content-Type", "application/json").send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data = response.json::<HeliusWebhookIdResponse>().await?;
     Ok(data)
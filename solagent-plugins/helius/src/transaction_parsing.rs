@@ -14,7 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use solagent_core::SolanaAgentKit;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HeliusWebhookIdResponse {
@@ -36,21 +36,24 @@ pub struct HeliusWebhookIdResponse {
 pub async fn transaction_parse(
     agent: &SolanaAgentKit,
     transaction_id: &str,
-) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+) -> Result<serde_json::Value, SolagentError> {
     // Get the Helius API key from the agent's configuration
     let api_key = match agent.config.helius_api_key.as_ref() {
         Some(key) => key,
-        None => return Err("Missing Helius API key in agent.config.HELIUS_API_KEY".into()),
+        None => return Err(SolagentError::Config("Helius API key (agent.config.helius_api_key)".to_string())),
     };
 
-    let client = reqwest::Client::new();
-    let url = format!("https://api.helius.xyz/v0/transactions/?api-key={}", api_key);
+    let path = format!("/transactions/?api-key={}", api_key);
 
     let body = json!( {
         "transactions": vec![transaction_id.to_string()],
     });
 
-    let response = client.post(url).header("Content-Type", "application/json").json(&body).send().await?;
+    let response =
+        agent.http.post(Service::Helius, &path).header("Content-Type", "application/json").json(&body).send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data = response.json().await?;
     Ok(data)
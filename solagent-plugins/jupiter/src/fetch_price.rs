@@ -14,8 +14,8 @@
 
 #![allow(dead_code)]
 
-use crate::JUP_PRICE_V2;
 use serde::Deserialize;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 #[derive(Deserialize, Debug)]
 struct PriceResponse {
@@ -35,16 +35,21 @@ struct TokenData {
 ///
 /// # Parameters
 ///
+/// - `agent`: An instance of `SolanaAgentKit`.
 /// - `token_id`: The token mint address as a string.
 ///
 /// # Returns
 ///
 /// The price of the token quoted in USDC as a string.
-pub async fn fetch_price(token_id: &str) -> Result<String, Box<dyn std::error::Error>> {
-    let url = format!("{}{}", JUP_PRICE_V2, token_id);
-    let response = reqwest::get(&url).await?;
+pub async fn fetch_price(agent: &SolanaAgentKit, token_id: &str) -> Result<String, SolagentError> {
+    let response = agent
+        .http
+        .get(Service::JupiterPrice, "")
+        .query(&[("ids", token_id)])
+        .send()
+        .await?;
     if !response.status().is_success() {
-        return Err(format!("Failed to fetch price: {}", response.status()).into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: PriceResponse = response.json().await?;
@@ -55,5 +60,71 @@ pub async fn fetch_price(token_id: &str) -> Result<String, Box<dyn std::error::E
         }
     }
 
-    Err("Price data not available for the given token.".into())
+    Err(SolagentError::InvalidInput(format!(
+        "Price data not available for the given token: {}",
+        token_id
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderName, HeaderValue};
+    use solagent_core::{Config, HttpProvider, Wallet};
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const JUP: &str = "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN";
+
+    fn kit(server: &MockServer) -> SolanaAgentKit {
+        let http = HttpProvider::builder()
+            .base_url(Service::JupiterPrice, format!("{}/price/v2", server.uri()))
+            .header(
+                HeaderName::from_static("x-team"),
+                HeaderValue::from_static("agents"),
+            )
+            .build()
+            .unwrap();
+        SolanaAgentKit::new(
+            Wallet::new(),
+            "https://api.devnet.solana.com",
+            Config::default(),
+        )
+        .with_http(http)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_price() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/price/v2"))
+            .and(query_param("ids", JUP))
+            .and(header("x-team", "agents"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { JUP: { "id": JUP, "type": "derivedPrice", "price": "0.8123" } },
+                "timeTaken": 0.003
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert_eq!(fetch_price(&kit(&server), JUP).await.unwrap(), "0.8123");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_price_unknown_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/price/v2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": {} })),
+            )
+            .mount(&server)
+            .await;
+
+        let error = fetch_price(&kit(&server), JUP).await.unwrap_err();
+        assert!(matches!(error, SolagentError::InvalidInput(_)));
+    }
 }
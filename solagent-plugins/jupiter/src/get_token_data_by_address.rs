@@ -12,22 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
+
 /// Fetches the price of a given token quoted in USDC using Jupiter API.
 ///
 /// # Parameters
 ///
+/// - `agent`: An instance of `SolanaAgentKit`.
 /// - `mint`: The token mint address as a string.
 ///
 /// # Returns
 ///
 /// The token data.
 pub async fn get_token_data_by_address(
+    agent: &SolanaAgentKit,
     mint: &str,
-) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
-    let url = format!("https://tokens.jup.ag/token/{}", mint);
-    let response = reqwest::get(&url).await?;
+) -> Result<serde_json::Value, SolagentError> {
+    let response = agent
+        .http
+        .get(Service::JupiterTokens, &format!("token/{}", mint))
+        .send()
+        .await?;
     if !response.status().is_success() {
-        return Err(format!("Failed to get token data: {}", response.status()).into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: serde_json::Value = response.json().await?;
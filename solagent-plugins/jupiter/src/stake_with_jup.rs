@@ -12,11 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use anyhow::Result;
 use base64::{engine::general_purpose, Engine as _};
 use solagent_core::{
-    solana_sdk::{commitment_config::CommitmentConfig, transaction::VersionedTransaction},
-    SolanaAgentKit,
+    solana_sdk::transaction::VersionedTransaction, Service, SolagentError, SolanaAgentKit,
+    TransactionIntent, TransactionOutcome,
 };
 
 /// Stake SOL with Jupiter validator
@@ -25,55 +24,62 @@ use solagent_core::{
 ///
 /// * `agent` - SolanaAgentKit instance
 /// * `amount` - Amount of SOL to stake (in SOL)
+/// * `wallet` - Label of the kit wallet to stake from (defaults to the default wallet)
 ///
 /// # Returns
 ///
-/// Transaction signature as a string
+/// Receipt of the landed stake transaction, or a simulation report in dry-run mode
 pub async fn stake_with_jup(
     agent: &SolanaAgentKit,
     amount: f64,
-) -> Result<String, Box<dyn std::error::Error>> {
+    wallet: Option<&str>,
+) -> Result<TransactionOutcome, SolagentError> {
+    agent.require_mainnet("stake_with_jup")?;
+
+    let wallet = agent.wallet(wallet)?.pubkey();
+
     // Convert SOL amount to lamports
     let amount_lamports = (amount * 1e9) as u64;
 
-    // Build stake URL
-    let stake_url = format!(
-        "https://worker.jup.ag/blinks/swap/So11111111111111111111111111111111111111112/jupSoLaHXQiZZTSfEWMTRRgpnyFm8f6sZdosWBjx93v/{}",
+    // Build stake path
+    let stake_path = format!(
+        "blinks/swap/So11111111111111111111111111111111111111112/jupSoLaHXQiZZTSfEWMTRRgpnyFm8f6sZdosWBjx93v/{}",
         amount_lamports
     );
 
     // Get stake transaction
-    let client = reqwest::Client::new();
     let stake_request = serde_json::json!({
-        "account": agent.wallet.pubkey.to_string(),
+        "account": wallet.to_string(),
     });
 
-    let response = client.post(&stake_url).json(&stake_request).send().await?;
+    let response = agent
+        .http
+        .post(Service::JupiterWorker, &stake_path)
+        .json(&stake_request)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
 
     let data: serde_json::Value = response.json().await?;
+    let transaction = data["transaction"].as_str().ok_or_else(|| {
+        SolagentError::Decode("stake response: missing `transaction` field".to_string())
+    })?;
     let transaction_data = general_purpose::STANDARD
-        .decode(data["transaction"].as_str().expect("decode transaction"))?;
-
-    let mut versioned_transaction: VersionedTransaction = bincode::deserialize(&transaction_data)?;
-
-    let blockhash = agent.connection.get_latest_blockhash()?;
-    versioned_transaction
-        .message
-        .set_recent_blockhash(blockhash);
-
-    // Sign and send transaction
-    let signed_transaction =
-        VersionedTransaction::try_new(versioned_transaction.message, &[&agent.wallet.keypair])?;
-
-    let signature = agent.connection.send_transaction(&signed_transaction)?;
+        .decode(transaction)
+        .map_err(|e| SolagentError::Decode(format!("stake transaction: {}", e)))?;
 
-    // Confirm transaction
-    let latest_blockhash = agent.connection.get_latest_blockhash()?;
-    agent.connection.confirm_transaction_with_spinner(
-        &signature,
-        &latest_blockhash,
-        CommitmentConfig::confirmed(),
-    )?;
+    let versioned_transaction: VersionedTransaction = bincode::deserialize(&transaction_data)
+        .map_err(|e| SolagentError::Decode(format!("stake transaction: {}", e)))?;
 
-    Ok(signature.to_string())
+    // Sign, send and confirm transaction
+    agent
+        .sender()
+        .send_transaction(
+            &TransactionIntent::new("stake_with_jup"),
+            versioned_transaction,
+            &[],
+        )
+        .await
 }
@@ -12,19 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::JUP_API;
-use anyhow::Result;
 use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
 use solagent_core::{
-    solana_sdk::{
-        commitment_config::CommitmentConfig, program_pack::Pack, pubkey::Pubkey,
-        transaction::VersionedTransaction,
-    },
-    SolanaAgentKit,
+    parse_pubkey,
+    solana_sdk::{program_pack::Pack, transaction::VersionedTransaction},
+    Service, SolagentError, SolanaAgentKit, TransactionIntent, TransactionOutcome,
 };
 use spl_token::state::Mint;
-use std::str::FromStr;
 
 #[derive(Serialize)]
 struct SwapRequest {
@@ -37,7 +32,7 @@ struct SwapRequest {
     #[serde(rename = "dynamicComputeUnitLimit")]
     dynamic_compute_unit_limit: bool,
     #[serde(rename = "prioritizationFeeLamports")]
-    prioritization_fee_lamports: String,
+    prioritization_fee_lamports: serde_json::Value,
     #[serde(rename = "feeAccount")]
     fee_account: Option<String>,
 }
@@ -62,27 +57,38 @@ struct QuoteResponse {
 /// * `input_amount` - Amount to swap (in token decimals)
 /// * `input_mint` - Source token mint address (defaults to SOL)
 /// * `slippage_bps` - Slippage tolerance in basis points (default: 300 = 3%)
+/// * `wallet` - Label of the kit wallet to swap from (defaults to the default wallet)
 ///
 /// # Returns
 ///
-/// Transaction signature as a string
+/// Receipt of the landed swap transaction, or a simulation report in dry-run mode
 pub async fn trade(
     agent: &SolanaAgentKit,
     output_mint: &str,
     input_amount: f64,
     input_mint: Option<String>,
     slippage_bps: Option<u32>,
-) -> Result<String, Box<dyn std::error::Error>> {
+    wallet: Option<&str>,
+) -> Result<TransactionOutcome, SolagentError> {
+    agent.require_mainnet("trade")?;
+
+    let wallet = agent.wallet(wallet)?.pubkey();
+
     // Convert strings to Pubkeys
-    let output_mint = Pubkey::from_str(output_mint)?;
+    let output_mint = parse_pubkey("output_mint", output_mint)?;
     let input_mint = input_mint
         .as_deref()
-        .map(Pubkey::from_str)
+        .map(|mint| parse_pubkey("input_mint", mint))
         .transpose()?
         .unwrap_or(spl_token::native_mint::id());
 
     // Use defaults if not provided
-    let slippage_bps = slippage_bps.unwrap_or(300);
+    let slippage_bps = slippage_bps
+        .or(agent.config.default_slippage_bps.map(u32::from))
+        .unwrap_or(300);
+    agent
+        .policy
+        .check_slippage(u16::try_from(slippage_bps).unwrap_or(u16::MAX))?;
 
     // Check if input token is native SOL
     let is_native_sol = input_mint == spl_token::native_mint::id();
@@ -91,7 +97,7 @@ pub async fn trade(
     let input_decimals = if is_native_sol {
         9
     } else {
-        let account = agent.connection.get_account(&input_mint)?;
+        let account = agent.connection.get_account(&input_mint).await?;
         let mint = Mint::unpack(&account.data)?;
         mint.decimals
     };
@@ -99,52 +105,56 @@ pub async fn trade(
     // Calculate scaled amount
     let scaled_amount = (input_amount * 10f64.powf(input_decimals as f64)) as u64;
 
-    // Build quote URL
-    let quote_url = format!(
-        "{}/quote?inputMint={}&outputMint={}&amount={}&slippageBps={}&onlyDirectRoutes=true&maxAccounts=20",
-        JUP_API, input_mint, output_mint, scaled_amount, slippage_bps
+    // Build quote path
+    let quote_path = format!(
+        "quote?inputMint={}&outputMint={}&amount={}&slippageBps={}&onlyDirectRoutes=true&maxAccounts=20",
+        input_mint, output_mint, scaled_amount, slippage_bps
     );
 
     // Get quote
-    let client = reqwest::Client::new();
-    let quote_response: QuoteResponse = client.get(&quote_url).send().await?.json().await?;
+    let response = agent
+        .http
+        .get(Service::JupiterQuote, &quote_path)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
+    let quote_response: QuoteResponse = response.json().await?;
 
     // Get swap transaction
     let swap_request = SwapRequest {
         quote_response,
-        user_public_key: agent.wallet.pubkey.to_string(),
+        user_public_key: wallet.to_string(),
         wrap_and_unwrap_sol: true,
         dynamic_compute_unit_limit: true,
-        prioritization_fee_lamports: "auto".to_string(),
+        prioritization_fee_lamports: agent
+            .config
+            .priority_fee_lamports
+            .map_or_else(|| "auto".into(), serde_json::Value::from),
         fee_account: None,
     };
 
-    let swap_response: SwapResponse = client
-        .post(format!("{}/swap", JUP_API))
+    let response = agent
+        .http
+        .post(Service::JupiterQuote, "swap")
         .json(&swap_request)
         .send()
-        .await?
-        .json()
         .await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
+    }
+    let swap_response: SwapResponse = response.json().await?;
 
     let swap_transaction = general_purpose::STANDARD
         .decode(&swap_response.swap_transaction)
-        .expect("decode swap_transaction");
-
-    let versioned_transaction: VersionedTransaction = bincode::deserialize(&swap_transaction)?;
-
-    let signed_transaction =
-        VersionedTransaction::try_new(versioned_transaction.message, &[&agent.wallet.keypair])?;
-
-    let signature = agent.connection.send_transaction(&signed_transaction)?;
-
-    let latest_blockhash = agent.connection.get_latest_blockhash()?;
+        .map_err(|e| SolagentError::Decode(format!("swap transaction: {}", e)))?;
 
-    agent.connection.confirm_transaction_with_spinner(
-        &signature,
-        &latest_blockhash,
-        CommitmentConfig::confirmed(),
-    )?;
+    let versioned_transaction: VersionedTransaction = bincode::deserialize(&swap_transaction)
+        .map_err(|e| SolagentError::Decode(format!("swap transaction: {}", e)))?;
 
-    Ok(signature.to_string())
+    agent
+        .sender()
+        .send_transaction(&TransactionIntent::new("trade"), versioned_transaction, &[])
+        .await
 }
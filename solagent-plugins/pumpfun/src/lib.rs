@@ -1,15 +1,15 @@
-use reqwest::{multipart::Part, Client as ReqwestClient};
+use reqwest::multipart::Part;
 use serde::{Deserialize, Serialize};
 use solagent_core::{
-    solana_client,
     solana_sdk::{
-        commitment_config::CommitmentConfig, signature::Signer, signer::keypair::Keypair,
+        pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
         transaction::VersionedTransaction,
     },
-    SolanaAgentKit,
+    Network, Service, SolagentError, SolanaAgentKit, TransactionIntent, TransactionOutcome,
 };
+use solagent_parameters::ToolParameters;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToolParameters)]
 pub struct PumpFunTokenOptions {
     pub twitter: Option<String>,
     pub telegram: Option<String>,
@@ -21,9 +21,10 @@ pub struct PumpFunTokenOptions {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PumpfunTokenResponse {
-    pub signature: String,
+    pub transaction: TransactionOutcome,
     pub mint: String,
     pub metadata_uri: String,
+    pub network: Network,
 }
 
 pub struct TokenMetadata {
@@ -42,10 +43,11 @@ pub struct TokenMetadata {
 /// - `description` - The description of the token.
 /// - `imageUrl` - The URL of the token image.
 /// - `options` - Optional token options which include `twitter`, `telegram`, `website`, `initialLiquiditySOL`, `slippageBps`, and `priorityFee`.
+/// - `wallet` - Label of the kit wallet that creates the token; the default wallet when `None`.
 ///
 /// # Returns
 ///
-/// If successful, it returns the signature of the transaction, the mint address, and the metadata URI. Otherwise, it returns an error.
+/// If successful, it returns the transaction outcome, the mint address, and the metadata URI. Otherwise, it returns an error.
 ///
 /// To get a transaction for signing and sending with a custom RPC, send a POST request to:
 /// https://pumpportal.fun/local-trading-api/trading-api/
@@ -71,100 +73,70 @@ pub async fn launch_token_pumpfun(
     description: &str,
     image_url: &str,
     options: Option<PumpFunTokenOptions>,
-) -> Result<PumpfunTokenResponse, Box<dyn std::error::Error>> {
-    let reqwest_client = ReqwestClient::new();
+    wallet: Option<&str>,
+) -> Result<PumpfunTokenResponse, SolagentError> {
+    agent.require_mainnet("launch_token_pumpfun")?;
+    let wallet = agent.wallet(wallet)?.pubkey();
 
     // 0. download image
-    let image_data = fetch_image(&reqwest_client, image_url)
-        .await
-        .expect("fetch_image");
+    let image_data = fetch_image(agent, image_url).await?;
 
     // 1. fetch token metadata metadataUri
     let token_metadata = fetch_token_metadata(
-        &reqwest_client,
+        agent,
         token_name,
         token_symbol,
         description,
         options,
         &image_data,
     )
-    .await
-    .expect("fetch_token_metadata");
+    .await?;
 
     // 2. Create a new keypair for the mint
     let mint_keypair = Keypair::new();
 
     // 3. request pumpportal tx
-    let mut versioned_tx =
-        request_pumpportal_tx(agent, &reqwest_client, &token_metadata, &mint_keypair)
-            .await
-            .expect("request_pumpportal_tx");
-
-    // 4. sign&send transaction
-    let signature = sign_and_send_tx(agent, &mut versioned_tx, &mint_keypair)
-        .await
-        .expect("sign_and_send_tx");
+    let versioned_tx =
+        request_pumpportal_tx(agent, &wallet, &token_metadata, &mint_keypair).await?;
+
+    // 4. sign&send transaction, the mint keypair must co-sign
+    let transaction = agent
+        .sender()
+        .send_transaction(
+            &TransactionIntent::new("launch_token_pumpfun"),
+            versioned_tx,
+            &[&mint_keypair],
+        )
+        .await?;
 
     let res = PumpfunTokenResponse {
-        signature,
+        transaction,
         mint: mint_keypair.pubkey().to_string(),
         metadata_uri: token_metadata.uri,
+        network: agent.network.clone(),
     };
 
     Ok(res)
 }
 
-// try signed vtx: NotEnoughSigners -> mint_keypair is needed
-async fn sign_and_send_tx(
-    agent: &SolanaAgentKit,
-    vtx: &mut VersionedTransaction,
-    mint_keypair: &Keypair,
-) -> Result<String, Box<std::io::Error>> {
-    let recent_blockhash = agent
-        .connection
-        .get_latest_blockhash()
-        .expect("get_latest_blockhash");
-    vtx.message.set_recent_blockhash(recent_blockhash);
-    let signed_vtx =
-        VersionedTransaction::try_new(vtx.message.clone(), &[mint_keypair, &agent.wallet.keypair])
-            .expect("try signed vtx");
-
-    let signature = agent
-        .connection
-        .send_and_confirm_transaction_with_spinner_and_config(
-            &signed_vtx,
-            CommitmentConfig::finalized(),
-            solana_client::rpc_config::RpcSendTransactionConfig {
-                skip_preflight: false,
-                ..Default::default()
-            },
-        )
-        .expect("send_and_confirm_tx");
-
-    Ok(signature.to_string())
-}
-
-async fn fetch_image(
-    client: &ReqwestClient,
-    image_url: &str,
-) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let response = client.get(image_url).send().await?;
-    if response.status().is_success() {
-        let image_data = response.bytes().await.expect("image data");
-        return Ok(image_data.to_vec());
+async fn fetch_image(agent: &SolanaAgentKit, image_url: &str) -> Result<Vec<u8>, SolagentError> {
+    let response = agent.http.client().get(image_url).send().await?;
+    if !response.status().is_success() {
+        return Err(SolagentError::from_response(response).await);
     }
 
-    Err("fetch image error".into())
+    let image_data = response.bytes().await?;
+    Ok(image_data.to_vec())
 }
 
 async fn fetch_token_metadata(
-    client: &ReqwestClient,
+    agent: &SolanaAgentKit,
     name: &str,
     symbol: &str,
     description: &str,
     options: Option<PumpFunTokenOptions>,
     image_data: &[u8],
-) -> Result<TokenMetadata, Box<dyn std::error::Error>> {
+) -> Result<TokenMetadata, SolagentError> {
     let part = Part::bytes(image_data.to_vec())
         .file_name("image_name")
         .mime_str("image/png")?; // Important: set the correct MIME type
@@ -191,27 +163,25 @@ async fn fetch_token_metadata(
         form = form.text("showName", "true");
     }
 
-    let res = client
-        .post("https://pump.fun/api/ipfs")
+    let res = agent
+        .http
+        .post(Service::PumpFun, "/ipfs")
         .multipart(form)
         .send()
         .await?;
 
-    let status = res.status();
-    if !status.is_success() {
-        let text = res.text().await?;
-        eprintln!("Error response: {}", text);
-        return Err(format!("Upload failed with status: {}", status).into());
+    if !res.status().is_success() {
+        return Err(SolagentError::from_response(res).await);
     }
 
     let response_json = res.json::<serde_json::Value>().await?;
+    let uri = response_json.get("metadataUri").ok_or_else(|| {
+        SolagentError::Decode("ipfs upload response: missing `metadataUri` field".to_string())
+    })?;
     let md = TokenMetadata {
         name: name.to_string(),
         symbol: symbol.to_string(),
-        uri: response_json
-            .get("metadataUri")
-            .expect("metadataUri")
-            .to_string(),
+        uri: uri.to_string(),
     };
 
     Ok(md)
@@ -219,12 +189,15 @@ async fn fetch_token_metadata(
 
 async fn request_pumpportal_tx(
     agent: &SolanaAgentKit,
-    client: &ReqwestClient,
+    wallet: &Pubkey,
     token_matedata: &TokenMetadata,
     mint_keypair: &Keypair,
-) -> Result<VersionedTransaction, Box<dyn std::error::Error>> {
+) -> Result<VersionedTransaction, SolagentError> {
+    let slippage_bps = agent.config.default_slippage_bps.unwrap_or(1_000);
+    agent.policy.check_slippage(slippage_bps)?;
+
     let request_body = serde_json::json!({
-        "publicKey": agent.wallet.pubkey.to_string(),
+        "publicKey": wallet.to_string(),
         "action": "create",
         "tokenMetadata": {
             "name": token_matedata.name,
@@ -234,30 +207,24 @@ async fn request_pumpportal_tx(
         "mint": mint_keypair.pubkey().to_string(),
         "denominatedInSol": "true",
         "amount": 1,
-        "slippage": 10,
-        "priorityFee": 0.0005,
+        "slippage": f64::from(slippage_bps) / 100.0,
+        "priorityFee": agent.config.priority_fee_lamports.map_or(0.0005, |lamports| lamports as f64 / 1e9),
         "pool": "pump"
     });
 
-    let res = client
-        .post("https://pumpportal.fun/api/trade-local")
+    let res = agent
+        .http
+        .post(Service::PumpPortal, "/trade-local")
         .header("Content-Type", "application/json")
         .json(&request_body)
         .send()
         .await?;
 
-    let status = res.status();
-    if !status.is_success() {
-        let text = res.text().await?;
-        eprintln!("Error response: {}", text);
-        return Err(format!("trade-local failed with status: {}", status).into());
-    }
-
-    if let Ok(bytes) = res.bytes().await {
-        if let Ok(tx) = bincode::deserialize::<VersionedTransaction>(&bytes) {
-            return Ok(tx);
-        }
+    if !res.status().is_success() {
+        return Err(SolagentError::from_response(res).await);
     }
 
-    Err("fetch token metadata error".into())
+    let bytes = res.bytes().await?;
+    bincode::deserialize::<VersionedTransaction>(&bytes)
+        .map_err(|e| SolagentError::Decode(format!("pumpportal transaction: {}", e)))
 }
@@ -1,6 +1,5 @@
 use serde::{Deserialize, Serialize};
-
-pub const PYTH_API: &str = "https://hermes.pyth.network/v2";
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Price {
@@ -40,6 +39,7 @@ struct Response {
 /// Fetch the price of a given price feed from Pyth.
 ///
 /// # Parameters
+/// - `agent`: An instance of `SolanaAgentKit`.
 /// - `price_feed_id`: Price feed ID.
 ///
 /// # Returns
@@ -47,13 +47,11 @@ struct Response {
 ///
 /// You can find priceFeedIDs here: https://www.pyth.network/developers/price-feed-ids#stable
 /// get Hermes service URL from https://docs.pyth.network/price-feeds/api-instances-and-providers/hermes
-pub async fn fetch_price_by_pyth(price_feed_id: &str) -> Result<f64, Box<dyn std::error::Error>> {
-    let url = format!("{}{}", PYTH_API, "/updates/price/latest");
-    let ids = [price_feed_id];
+pub async fn fetch_price_by_pyth(agent: &SolanaAgentKit, price_feed_id: &str) -> Result<f64, SolagentError> {
     let response =
-        reqwest::Client::new().get(&url).query(&ids.iter().map(|id| ("ids[]", *id)).collect::<Vec<_>>()).send().await?;
+        agent.http.get(Service::Pyth, "/updates/price/latest").query(&[("ids[]", price_feed_id)]).send().await?;
     if !response.status().is_success() {
-        return Err(format!("Failed to fetch price: {}", response.status()).into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: Response = response.json().await?;
@@ -61,14 +59,17 @@ pub async fn fetch_price_by_pyth(price_feed_id: &str) -> Result<f64, Box<dyn std
     if !parsed_data.is_empty() {
         let price_data = &parsed_data[0];
         let price_info = &price_data.price;
-        let price = price_info.price.parse::<f64>().expect("parse price errors");
+        let price = price_info
+            .price
+            .parse::<f64>()
+            .map_err(|e| SolagentError::Decode(format!("Pyth price `{}`: {}", price_info.price, e)))?;
         let expo = price_info.expo;
 
         let price = price * (10.0_f64.powi(expo));
         return Ok(price);
     }
 
-    Err("Price data not available for the given token.".into())
+    Err(SolagentError::InvalidInput(format!("no price data available for feed `{}`", price_feed_id)))
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -92,26 +93,30 @@ struct Attributes {
 /// Fetch the price feed ID for a given token symbol from Pyth.
 ///
 /// # Parameters
+/// - `agent`: An instance of `SolanaAgentKit`.
 /// - `token_symbol`: Token symbol
 ///
-pub async fn fetch_pyth_price_feed_id(token_symbol: &str) -> Result<String, Box<dyn std::error::Error>> {
-    let url = format!("{}{}?query={}&asset_type=crypto", PYTH_API, "/price_feeds", token_symbol);
-
-    let response = reqwest::Client::new().get(&url).send().await?;
+pub async fn fetch_pyth_price_feed_id(agent: &SolanaAgentKit, token_symbol: &str) -> Result<String, SolagentError> {
+    let response = agent
+        .http
+        .get(Service::Pyth, "/price_feeds")
+        .query(&[("query", token_symbol), ("asset_type", "crypto")])
+        .send()
+        .await?;
     if !response.status().is_success() {
-        return Err(format!("Failed to fetch price: {}", response.status()).into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: Vec<Asset> = response.json().await?;
     if data.is_empty() {
-        return Err(format!("No price feed found for {}", token_symbol).into());
+        return Err(SolagentError::InvalidInput(format!("no Pyth price feed found for `{}`", token_symbol)));
     }
 
     let filter_data: Vec<&Asset> =
         data.iter().filter(|a| a.attributes.base.to_ascii_lowercase() == token_symbol.to_ascii_lowercase()).collect();
 
     if filter_data.is_empty() {
-        return Err(format!("No price feed found for {}", token_symbol).into());
+        return Err(SolagentError::InvalidInput(format!("no Pyth price feed found for `{}`", token_symbol)));
     }
 
     Ok(filter_data[0].id.clone())
@@ -12,13 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::RUGCHECK_URL;
 use serde_json::Value;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 /// Fetches a detailed report for a specific token.
 ///
 /// # Parameters
 ///
+/// - `agent` - An instance of `SolanaAgentKit`.
 /// - `mint` - The mint address of the token.
 ///
 /// # Returns
@@ -26,12 +27,12 @@ use serde_json::Value;
 ///
 /// # Errors
 /// Throws an error if the API call fails.
-pub async fn fetch_detailed_report(mint: String) -> Result<Value, Box<dyn std::error::Error>> {
-    let url = format!("{}/tokens/{}/report", RUGCHECK_URL, mint);
+pub async fn fetch_detailed_report(agent: &SolanaAgentKit, mint: String) -> Result<Value, SolagentError> {
+    let path = format!("/tokens/{}/report", mint);
 
-    let response = reqwest::get(&url).await?;
+    let response = agent.http.get(Service::RugCheck, &path).send().await?;
     if !response.status().is_success() {
-        return Err(format!("HTTP error! status: {}", response.status()).into());
+        return Err(SolagentError::from_response(response).await);
     }
 
     let data: serde_json::Value = response.json().await?;
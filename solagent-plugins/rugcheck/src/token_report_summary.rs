@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::RUGCHECK_URL;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solagent_core::{Service, SolagentError, SolanaAgentKit};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Risk {
@@ -34,6 +35,7 @@ pub struct TokenCheck {
 ///
 /// # Parameters
 ///
+/// - `agent` - An instance of `SolanaAgentKit`.
 /// - `mint` - The mint address of the token.
 ///
 /// # Returns
@@ -41,31 +43,31 @@ pub struct TokenCheck {
 ///
 /// # Errors
 /// Throws an error if the API call fails.
-pub async fn fetch_summary_report(mint: String) -> Result<TokenCheck, Box<dyn std::error::Error>> {
-    let url = format!("{}/tokens/{}/report/summary", RUGCHECK_URL, mint);
+pub async fn fetch_summary_report(agent: &SolanaAgentKit, mint: String) -> Result<TokenCheck, SolagentError> {
+    let path = format!("/tokens/{}/report/summary", mint);
 
-    let response = reqwest::get(&url).await?;
+    let response = agent.http.get(Service::RugCheck, &path).send().await?;
     if !response.status().is_success() {
-        return Err(format!("HTTP error! status: {}", response.status()).into());
+        return Err(SolagentError::from_response(response).await);
     }
 
-    let data: serde_json::Value = response.json().await?;
+    let data: Value = response.json().await?;
 
     let mut token_check = TokenCheck::default();
-    let token_program = data.get("tokenProgram").and_then(|p| p.as_str()).expect("tokenProgram field");
+    let token_program = str_field(&data, "tokenProgram")?;
     token_check.token_program = token_program.into();
 
-    let token_type = data.get("tokenType").and_then(|p| p.as_str()).expect("tokenType field");
+    let token_type = str_field(&data, "tokenType")?;
     token_check.token_type = token_type.into();
 
     let mut risks: Vec<Risk> = vec![];
-    let risks_data = data.get("risks").and_then(|p| p.as_array()).expect("risks field");
+    let risks_data = data.get("risks").and_then(|p| p.as_array()).ok_or_else(|| missing_field("risks"))?;
     for risk in risks_data {
         let mut r = Risk::default();
-        let name = risk.get("name").and_then(|p| p.as_str()).expect("name field");
-        let description = risk.get("description").and_then(|p| p.as_str()).expect("description field");
-        let score = risk.get("score").and_then(|p| p.as_f64()).expect("score field");
-        let level = risk.get("level").and_then(|p| p.as_str()).expect("level field");
+        let name = str_field(risk, "name")?;
+        let description = str_field(risk, "description")?;
+        let score = risk.get("score").and_then(|p| p.as_f64()).ok_or_else(|| missing_field("score"))?;
+        let level = str_field(risk, "level")?;
 
         r.name = name.into();
         r.description = description.into();
@@ -78,3 +80,11 @@ pub async fn fetch_summary_report(mint: String) -> Result<TokenCheck, Box<dyn st
 
     Ok(token_check)
 }
+
+fn str_field<'a>(value: &'a Value, name: &str) -> Result<&'a str, SolagentError> {
+    value.get(name).and_then(|p| p.as_str()).ok_or_else(|| missing_field(name))
+}
+
+fn missing_field(name: &str) -> SolagentError {
+    SolagentError::Decode(format!("RugCheck summary report: missing `{}` field", name))
+}
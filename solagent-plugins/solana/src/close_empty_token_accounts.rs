@@ -15,10 +15,11 @@
 use serde::{Deserialize, Serialize};
 use solagent_core::{
     solana_client::rpc_request::TokenAccountsFilter,
-    solana_sdk::{instruction::Instruction, pubkey::Pubkey, transaction::Transaction},
-    SolanaAgentKit,
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey},
+    Network, SolagentError, SolanaAgentKit, TransactionIntent, TransactionOutcome,
 };
-use spl_token::instruction::close_account;
+use spl_token_2022::instruction::close_account;
+use std::str::FromStr;
 
 pub const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
 
@@ -45,17 +46,24 @@ pub struct Amount {
     pub decimals: u8,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct CloseEmptyTokenAccountsData {
-    pub signature: String,
+    /// `None` when there was no account to close.
+    pub transaction: Option<TransactionOutcome>,
     pub closed_size: usize,
+    pub network: Network,
 }
 
 impl CloseEmptyTokenAccountsData {
-    pub fn new(signature: String, closed_size: usize) -> Self {
+    pub fn new(
+        transaction: Option<TransactionOutcome>,
+        closed_size: usize,
+        network: Network,
+    ) -> Self {
         CloseEmptyTokenAccountsData {
-            signature,
+            transaction,
             closed_size,
+            network,
         }
     }
 }
@@ -65,13 +73,16 @@ impl CloseEmptyTokenAccountsData {
 /// # Parameters
 ///
 /// - `agent`: An instance of `SolanaAgentKit`.
+/// - `wallet`: Label of the kit wallet whose accounts are closed; the default wallet when `None`.
 ///
 /// # Returns
 ///
-/// Transaction signature and total number of accounts closed or an error if the account doesn't exist.
+/// Transaction outcome and total number of accounts closed or an error if the account doesn't exist.
 pub async fn close_empty_token_accounts(
     agent: &SolanaAgentKit,
-) -> Result<CloseEmptyTokenAccountsData, Box<dyn std::error::Error>> {
+    wallet: Option<&str>,
+) -> Result<CloseEmptyTokenAccountsData, SolagentError> {
+    let wallet = agent.wallet(wallet)?.pubkey();
     let max_instructions = 40_u32;
     let mut transaction: Vec<Instruction> = vec![];
     let mut closed_size = 0;
@@ -81,12 +92,10 @@ pub async fn close_empty_token_accounts(
         let accounts = agent
             .connection
             .get_token_accounts_by_owner(
-                &agent.wallet.pubkey,
+                &wallet,
                 TokenAccountsFilter::ProgramId(token_program.to_owned()),
             )
-            .expect("get_token_accounts_by_owner");
-
-        closed_size += accounts.len();
+            .await?;
 
         for account in accounts {
             if transaction.len() >= max_instructions as usize {
@@ -95,24 +104,20 @@ pub async fn close_empty_token_accounts(
 
             if let solana_account_decoder::UiAccountData::Json(d) = &account.account.data {
                 if let Ok(parsed) = serde_json::from_value::<Parsed>(d.parsed.clone()) {
-                    if parsed
-                        .info
-                        .token_amount
-                        .amount
-                        .parse::<u32>()
-                        .unwrap_or_default()
-                        == 0_u32
-                        && parsed.info.mint != USDC
-                    {
-                        let account_pubkey = Pubkey::from_str_const(&account.pubkey);
+                    if parsed.info.token_amount.amount == "0" && parsed.info.mint != USDC {
+                        let Ok(account_pubkey) = Pubkey::from_str(&account.pubkey) else {
+                            continue;
+                        };
+                        // The Token-2022 builder accepts accounts of both token programs
                         if let Ok(instruct) = close_account(
                             &token_program,
                             &account_pubkey,
-                            &agent.wallet.pubkey,
-                            &agent.wallet.pubkey,
-                            &[&agent.wallet.pubkey],
+                            &wallet,
+                            &wallet,
+                            &[&wallet],
                         ) {
                             transaction.push(instruct);
+                            closed_size += 1;
                         }
                     }
                 }
@@ -121,21 +126,54 @@ pub async fn close_empty_token_accounts(
     }
 
     if transaction.is_empty() {
-        return Ok(CloseEmptyTokenAccountsData::default());
+        return Ok(CloseEmptyTokenAccountsData::new(
+            None,
+            0,
+            agent.network.clone(),
+        ));
     }
 
     // Create and send transaction
-    let recent_blockhash = agent.connection.get_latest_blockhash()?;
-    let transaction = Transaction::new_signed_with_payer(
-        &transaction,
-        Some(&agent.wallet.pubkey),
-        &[&agent.wallet.keypair],
-        recent_blockhash,
-    );
-
-    let signature = agent
-        .connection
-        .send_and_confirm_transaction(&transaction)?;
-    let data = CloseEmptyTokenAccountsData::new(signature.to_string(), closed_size);
+    let outcome = agent
+        .sender()
+        .send_instructions(
+            &TransactionIntent::new("close_empty_token_accounts"),
+            &transaction,
+            &[],
+        )
+        .await?;
+    let data = CloseEmptyTokenAccountsData::new(Some(outcome), closed_size, agent.network.clone());
     Ok(data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solagent_core::{solana_sdk::native_token::LAMPORTS_PER_SOL, Wallet};
+    use solagent_testkit::Svm;
+
+    #[tokio::test]
+    async fn test_close_empty_token_accounts() {
+        let svm = Svm::new();
+        let wallet = Wallet::new();
+        let address = wallet.pubkey;
+        svm.fund(&address, LAMPORTS_PER_SOL);
+        let empty = svm.mint_to(&svm.create_mint(&spl_token::ID, &address, 6), &address, 0);
+        let mint_2022 = svm.create_mint(&spl_token_2022::ID, &address, 0);
+        let empty_2022 = svm.mint_to(&mint_2022, &address, 0);
+        let held = svm.mint_to(&svm.create_mint(&spl_token::ID, &address, 6), &address, 42);
+        let kit = svm.kit(wallet);
+
+        let before = svm.balance(&address);
+        let data = close_empty_token_accounts(&kit, None).await.unwrap();
+        assert_eq!(data.closed_size, 2);
+        assert!(svm.account(&empty).is_none() && svm.account(&empty_2022).is_none());
+        assert_eq!(svm.token_balance(&held), 42);
+        // The rent of the closed accounts went back to the wallet
+        assert!(svm.balance(&address) > before);
+
+        let data = close_empty_token_accounts(&kit, None).await.unwrap();
+        assert_eq!(data.closed_size, 0);
+        assert!(data.transaction.is_none());
+    }
+}
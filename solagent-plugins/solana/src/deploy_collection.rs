@@ -20,16 +20,14 @@ use mpl_token_metadata::{
     types::DataV2,
 };
 use solagent_core::{
-    solana_client::client_error::ClientError,
     solana_program,
     solana_sdk::{
         program_pack::Pack,
         pubkey::Pubkey,
         signature::{Keypair, Signer},
         system_instruction, sysvar,
-        transaction::Transaction,
     },
-    SolanaAgentKit,
+    SolagentError, SolanaAgentKit, TransactionIntent,
 };
 use spl_associated_token_account::instruction::create_associated_token_account;
 
@@ -39,6 +37,7 @@ use spl_associated_token_account::instruction::create_associated_token_account;
 ///
 /// - `agent`: An instance of `SolanaAgentKit`.
 /// - `options`: Collection options including name, URI, royalties, and creators.
+/// - `wallet`: Label of the kit wallet that pays for and owns the collection; the default wallet when `None`.
 ///
 /// # Returns
 ///
@@ -46,7 +45,9 @@ use spl_associated_token_account::instruction::create_associated_token_account;
 pub async fn deploy_collection(
     agent: &SolanaAgentKit,
     options: &NFTMetadata,
-) -> Result<DeployedData, ClientError> {
+    wallet: Option<&str>,
+) -> Result<DeployedData, SolagentError> {
+    let wallet = agent.wallet(wallet)?.pubkey();
     // Create a new mint for the collection
     let collection_mint = Keypair::new();
     let collection_mint_pubkey = collection_mint.pubkey();
@@ -54,7 +55,8 @@ pub async fn deploy_collection(
     // Create token mint account
     let min_rent = agent
         .connection
-        .get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)?;
+        .get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)
+        .await?;
 
     // Create metadata account
     let metadata_seeds = &[
@@ -77,12 +79,12 @@ pub async fn deploy_collection(
 
     // Create associated token account for the mint
     let associated_token_account = spl_associated_token_account::get_associated_token_address(
-        &agent.wallet.pubkey,
+        &wallet,
         &collection_mint_pubkey,
     );
 
     // let create_mint_account_ix = system_instruction::create_account(
-    //     &agent.wallet.pubkey,
+    //     &wallet,
     //     &collection_mint.pubkey(),
     //     min_rent,
     //     spl_token::state::Mint::LEN as u64,
@@ -92,16 +94,16 @@ pub async fn deploy_collection(
     // let init_mint_ix = spl_token::instruction::initialize_mint(
     //     &spl_token::id(),
     //     &collection_mint_pubkey,
-    //     &agent.wallet.pubkey,
-    //     Some(&agent.wallet.pubkey),
+    //     &wallet,
+    //     Some(&wallet),
     //     0,
     // )
     // .unwrap();
 
     // Create associated token account
     let create_assoc_account_ix = create_associated_token_account(
-        &agent.wallet.pubkey,
-        &agent.wallet.pubkey,
+        &wallet,
+        &wallet,
         &collection_mint_pubkey,
         &spl_token::id(),
     );
@@ -111,19 +113,18 @@ pub async fn deploy_collection(
         &spl_token::id(),
         &collection_mint.pubkey(),
         &associated_token_account,
-        &agent.wallet.pubkey,
-        &[&agent.wallet.pubkey],
+        &wallet,
+        &[&wallet],
         1,
-    )
-    .expect("mint_to");
+    )?;
 
     // Create metadata
     let create_metadata_ix = CreateMetadataAccountV3 {
         metadata: metadata_account,
         mint: collection_mint.pubkey(),
-        mint_authority: agent.wallet.pubkey,
-        payer: agent.wallet.pubkey,
-        update_authority: (agent.wallet.pubkey, false),
+        mint_authority: wallet,
+        payer: wallet,
+        update_authority: (wallet, false),
         system_program: solana_program::system_program::id(),
         rent: Some(sysvar::rent::id()),
     }
@@ -145,9 +146,9 @@ pub async fn deploy_collection(
     let create_master_edition_ix = CreateMasterEditionV3 {
         edition: master_edition_account,
         mint: collection_mint.pubkey(),
-        update_authority: agent.wallet.pubkey,
-        mint_authority: agent.wallet.pubkey,
-        payer: agent.wallet.pubkey,
+        update_authority: wallet,
+        mint_authority: wallet,
+        payer: wallet,
         metadata: metadata_account,
         token_program: spl_token::id(),
         system_program: solana_program::system_program::id(),
@@ -161,7 +162,7 @@ pub async fn deploy_collection(
 
     // Create mint account
     let create_mint_account_ix = system_instruction::create_account(
-        &agent.wallet.pubkey,
+        &wallet,
         &collection_mint.pubkey(),
         min_rent,
         82,
@@ -172,34 +173,74 @@ pub async fn deploy_collection(
     let init_mint_ix = spl_token::instruction::initialize_mint(
         &spl_token::id(),
         &collection_mint.pubkey(),
-        &agent.wallet.pubkey,
-        Some(&agent.wallet.pubkey),
+        &wallet,
+        Some(&wallet),
         0,
-    )
-    .expect("initialize_mint");
+    )?;
 
     // Create and send transaction
-    let recent_blockhash = agent.connection.get_latest_blockhash()?;
-    let transaction = Transaction::new_signed_with_payer(
-        &[
-            create_mint_account_ix,
-            init_mint_ix,
-            create_assoc_account_ix,
-            mint_to_ix,
-            create_metadata_ix,
-            create_master_edition_ix,
-        ],
-        Some(&agent.wallet.pubkey),
-        &[&agent.wallet.keypair, &collection_mint],
-        recent_blockhash,
-    );
-
-    let signature = agent
-        .connection
-        .send_and_confirm_transaction(&transaction)?;
+    let transaction = agent
+        .sender()
+        .send_instructions(
+            &TransactionIntent::new("deploy_collection"),
+            &[
+                create_mint_account_ix,
+                init_mint_ix,
+                create_assoc_account_ix,
+                mint_to_ix,
+                create_metadata_ix,
+                create_master_edition_ix,
+            ],
+            &[&collection_mint],
+        )
+        .await?;
 
     Ok(DeployedData::new(
         collection_mint_pubkey.to_string(),
-        signature.to_string(),
+        transaction,
+        agent.network.clone(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mpl_token_metadata::accounts::{MasterEdition, Metadata};
+    use solagent_core::{
+        solana_sdk::{native_token::LAMPORTS_PER_SOL, program_option::COption},
+        Wallet,
+    };
+    use solagent_testkit::Svm;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_deploy_collection() {
+        let svm = Svm::new().with_token_metadata();
+        let wallet = Wallet::new();
+        let address = wallet.pubkey;
+        svm.fund(&address, LAMPORTS_PER_SOL);
+        let kit = svm.kit(wallet);
+
+        let options = NFTMetadata::new(
+            "Solagent",
+            "https://example.com/collection.json",
+            Some(500),
+            None,
+        );
+        let deployed = deploy_collection(&kit, &options, None).await.unwrap();
+        let mint = Pubkey::from_str(&deployed.mint).unwrap();
+        let account = spl_associated_token_account::get_associated_token_address(&address, &mint);
+        assert_eq!(svm.token_balance(&account), 1);
+
+        let (metadata, _) = Metadata::find_pda(&mint);
+        let metadata = Metadata::safe_deserialize(&svm.account(&metadata).unwrap().data).unwrap();
+        assert_eq!(metadata.update_authority, address);
+        assert_eq!(metadata.seller_fee_basis_points, 500);
+
+        // The master edition now holds the mint authority
+        let (edition, _) = MasterEdition::find_pda(&mint);
+        assert!(svm.account(&edition).is_some());
+        let state = spl_token::state::Mint::unpack(&svm.account(&mint).unwrap().data).unwrap();
+        assert_eq!(state.mint_authority, COption::Some(edition));
+    }
+}
@@ -19,15 +19,13 @@ use mpl_token_metadata::{
     types::{PrintSupply, TokenStandard},
 };
 use solagent_core::{
-    solana_client::{client_error::ClientError, rpc_config::RpcSendTransactionConfig},
     solana_program,
     solana_sdk::{
         program_pack::Pack,
         signature::{Keypair, Signer},
         system_instruction, system_program,
-        {commitment_config::CommitmentConfig, transaction::Transaction},
     },
-    SolanaAgentKit,
+    SolagentError, SolanaAgentKit, TransactionIntent,
 };
 use spl_associated_token_account::get_associated_token_address;
 use spl_token::instruction as spl_token_instruction;
@@ -42,10 +40,11 @@ use spl_token::instruction as spl_token_instruction;
 /// - `symbol`: Symbol of the token.
 /// - `decimals`: Number of decimals for the token (default: 9).
 /// - `initial_supply`: Initial supply to mint (optional).
+/// - `wallet`: Label of the kit wallet that pays for and owns the token; the default wallet when `None`.
 ///
 /// # Returns
 ///
-/// An object containing the token mint address.
+/// An object containing the token mint address and the transaction outcome.
 pub async fn deploy_token(
     agent: &SolanaAgentKit,
     name: String,
@@ -53,17 +52,20 @@ pub async fn deploy_token(
     symbol: String,
     decimals: u8,
     initial_supply: Option<u64>,
-) -> Result<DeployedData, ClientError> {
+    wallet: Option<&str>,
+) -> Result<DeployedData, SolagentError> {
+    let wallet = agent.wallet(wallet)?.pubkey();
     let mint = Keypair::new();
     let mint_pubkey = mint.pubkey();
 
     // Create token mint account
     let min_rent = agent
         .connection
-        .get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)?;
+        .get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)
+        .await?;
 
     let create_mint_account_ix = system_instruction::create_account(
-        &agent.wallet.pubkey,
+        &wallet,
         &mint_pubkey,
         min_rent,
         spl_token::state::Mint::LEN as u64,
@@ -73,11 +75,10 @@ pub async fn deploy_token(
     let initialize_mint_ix = spl_token_instruction::initialize_mint(
         &spl_token::id(),
         &mint_pubkey,
-        &agent.wallet.pubkey,
-        Some(&agent.wallet.pubkey),
+        &wallet,
+        Some(&wallet),
         decimals,
-    )
-    .expect("initialize_mint");
+    )?;
 
     // Create metadata account
     let (metadata, _x) = Metadata::find_pda(&mint_pubkey);
@@ -104,9 +105,9 @@ pub async fn deploy_token(
         metadata,
         master_edition: None,
         mint: (mint_pubkey, true),
-        authority: agent.wallet.pubkey,
-        payer: agent.wallet.pubkey,
-        update_authority: (agent.wallet.pubkey, true),
+        authority: wallet,
+        payer: wallet,
+        update_authority: (wallet, true),
         system_program: system_program::ID,
         sysvar_instructions: solana_program::sysvar::instructions::ID,
         spl_token_program: Some(spl_token::ID),
@@ -120,13 +121,12 @@ pub async fn deploy_token(
     ];
 
     if let Some(supply) = initial_supply {
-        let associated_token_account =
-            get_associated_token_address(&agent.wallet.pubkey, &mint_pubkey);
+        let associated_token_account = get_associated_token_address(&wallet, &mint_pubkey);
 
         let create_associated_token_account_ix =
             spl_associated_token_account::instruction::create_associated_token_account(
-                &agent.wallet.pubkey,
-                &agent.wallet.pubkey,
+                &wallet,
+                &wallet,
                 &mint_pubkey,
                 &spl_token::id(),
             );
@@ -135,37 +135,73 @@ pub async fn deploy_token(
             &spl_token::id(),
             &mint_pubkey,
             &associated_token_account,
-            &agent.wallet.pubkey,
-            &[&agent.wallet.pubkey],
+            &wallet,
+            &[&wallet],
             supply,
-        )
-        .expect("mint_to");
+        )?;
 
         instructions.push(create_associated_token_account_ix);
         instructions.push(mint_to_ix);
     }
 
-    let recent_blockhash = agent.connection.get_latest_blockhash()?;
-    let transaction = Transaction::new_signed_with_payer(
-        &instructions,
-        Some(&agent.wallet.pubkey),
-        &[&agent.wallet.keypair, &mint],
-        recent_blockhash,
-    );
-
-    let signature = agent
-        .connection
-        .send_and_confirm_transaction_with_spinner_and_config(
-            &transaction,
-            CommitmentConfig::finalized(),
-            RpcSendTransactionConfig {
-                skip_preflight: true,
-                ..Default::default()
-            },
-        )?;
+    let transaction = agent
+        .sender()
+        .send_instructions(
+            &TransactionIntent::new("deploy_token"),
+            &instructions,
+            &[&mint],
+        )
+        .await?;
 
     Ok(DeployedData::new(
         mint_pubkey.to_string(),
-        signature.to_string(),
+        transaction,
+        agent.network.clone(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solagent_core::{
+        solana_sdk::{native_token::LAMPORTS_PER_SOL, program_option::COption, pubkey::Pubkey},
+        Wallet,
+    };
+    use solagent_testkit::Svm;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_deploy_token() {
+        let svm = Svm::new().with_token_metadata();
+        let wallet = Wallet::new();
+        let address = wallet.pubkey;
+        svm.fund(&address, LAMPORTS_PER_SOL);
+        let kit = svm.kit(wallet);
+
+        let deployed = deploy_token(
+            &kit,
+            "Solagent".to_string(),
+            "https://example.com/token.json".to_string(),
+            "SA".to_string(),
+            6,
+            Some(1_000),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(deployed.transaction.signature().is_some());
+
+        let mint = Pubkey::from_str(&deployed.mint).unwrap();
+        let state = spl_token::state::Mint::unpack(&svm.account(&mint).unwrap().data).unwrap();
+        assert_eq!((state.decimals, state.supply), (6, 1_000));
+        assert_eq!(state.mint_authority, COption::Some(address));
+        let account = get_associated_token_address(&address, &mint);
+        assert_eq!(svm.token_balance(&account), 1_000);
+
+        let (metadata, _) = Metadata::find_pda(&mint);
+        let metadata = Metadata::safe_deserialize(&svm.account(&metadata).unwrap().data).unwrap();
+        assert_eq!(metadata.mint, mint);
+        assert_eq!(metadata.name.trim_end_matches('\0'), "Solagent");
+        assert_eq!(metadata.symbol.trim_end_matches('\0'), "SA");
+    }
+}
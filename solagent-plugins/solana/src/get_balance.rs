@@ -13,9 +13,8 @@
 // limitations under the License.
 
 use solagent_core::{
-    solana_client::client_error::ClientError,
     solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey},
-    SolanaAgentKit,
+    SolagentError, SolanaAgentKit,
 };
 use std::str::FromStr;
 
@@ -25,6 +24,7 @@ use std::str::FromStr;
 ///
 /// - `agent`: An instance of `SolanaAgentKit`.
 /// - `token_address`: An optional SPL token mint address. If not provided, returns the SOL balance.
+/// - `wallet`: Label of the kit wallet to read the SOL balance of; the default wallet when `None`.
 ///
 /// # Returns
 ///
@@ -32,17 +32,19 @@ use std::str::FromStr;
 pub async fn get_balance(
     agent: &SolanaAgentKit,
     token_address: Option<String>,
-) -> Result<f64, ClientError> {
+    wallet: Option<&str>,
+) -> Result<f64, SolagentError> {
+    let wallet = agent.wallet(wallet)?.pubkey();
     if let Some(token_address) = token_address {
         // Get SPL token account balance
         if let Ok(pubkey) = Pubkey::from_str(&token_address) {
-            let token_account = agent.connection.get_token_account_balance(&pubkey)?;
+            let token_account = agent.connection.get_token_account_balance(&pubkey).await?;
             let ui_amount = token_account.ui_amount.unwrap_or(0.0);
             return Ok(ui_amount);
         }
     }
 
     // Get SOL balance
-    let balance = agent.connection.get_balance(&agent.wallet.pubkey)?;
+    let balance = agent.connection.get_balance(&wallet).await?;
     Ok(balance as f64 / LAMPORTS_PER_SOL as f64)
 }
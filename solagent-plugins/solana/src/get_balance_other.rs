@@ -14,9 +14,9 @@
 
 use crate::get_balance;
 use solagent_core::{
-    solana_client::{client_error::ClientError, rpc_request::TokenAccountsFilter},
+    solana_client::rpc_request::TokenAccountsFilter,
     solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey},
-    SolanaAgentKit,
+    SolagentError, SolanaAgentKit,
 };
 
 /// Gets the balance of SOL or an SPL token for the specified wallet address.
@@ -34,13 +34,13 @@ pub async fn get_balance_other(
     agent: &SolanaAgentKit,
     wallet_address: Pubkey,
     token_address: Option<Pubkey>,
-) -> Result<f64, ClientError> {
+) -> Result<f64, SolagentError> {
     if let Some(token_address) = token_address {
         // Get token accounts by owner for the specified token mint address
         let token_accounts = agent
             .connection
             .get_token_accounts_by_owner(&wallet_address, TokenAccountsFilter::Mint(token_address))
-            .expect("get_token_accounts_by_owner");
+            .await?;
 
         if token_accounts.is_empty() {
             println!(
@@ -55,7 +55,7 @@ pub async fn get_balance_other(
         Ok(lamports as f64 / LAMPORTS_PER_SOL as f64)
     } else {
         // Get SOL balance if no token address is provided
-        let balance = get_balance(agent, Some(wallet_address.to_string())).await?;
+        let balance = get_balance(agent, Some(wallet_address.to_string()), None).await?;
         Ok(balance)
     }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use solagent_core::{solana_client::client_error::ClientError, SolanaAgentKit};
+use solagent_core::{SolagentError, SolanaAgentKit};
 
 /// Gets the transactions per second (TPS) from the Solana network.
 ///
@@ -23,12 +23,13 @@ use solagent_core::{solana_client::client_error::ClientError, SolanaAgentKit};
 /// # Returns
 ///
 /// A `Result` containing the TPS as a `f64`, or an error if fetching performance samples fails.
-pub async fn get_tps(agent: &SolanaAgentKit) -> Result<f64, ClientError> {
+pub async fn get_tps(agent: &SolanaAgentKit) -> Result<f64, SolagentError> {
     // Fetch recent performance samples
     let limit = 1;
     let perf_samples = agent
         .connection
-        .get_recent_performance_samples(Some(limit))?;
+        .get_recent_performance_samples(Some(limit))
+        .await?;
 
     // Check if there are any samples available
     if !perf_samples.is_empty() {
@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use solagent_core::SolanaAgentKit;
+use solagent_core::{SolagentError, SolanaAgentKit};
 
 /// Get the agent's wallet address.
 ///
 /// # Parameters
 /// - `agent`: A `SolanaAgentKit` instance.
+/// - `wallet`: Label of the kit wallet; the default wallet when `None`.
 ///
 /// # Returns
-/// A string representing the wallet address in base58 format.
-pub fn get_wallet_address(agent: &SolanaAgentKit) -> String {
-    agent.wallet.pubkey.to_string()
+/// A string representing the wallet address in base58 format, or an error if no wallet has that label.
+pub fn get_wallet_address(
+    agent: &SolanaAgentKit,
+    wallet: Option<&str>,
+) -> Result<String, SolagentError> {
+    Ok(agent.wallet(wallet)?.pubkey().to_string())
 }
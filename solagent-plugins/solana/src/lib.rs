@@ -42,19 +42,32 @@ pub use get_wallet_address::get_wallet_address;
 mod mint_nft;
 pub use mint_nft::mint_nft_to_collection;
 
+mod sign_message;
+pub use sign_message::{sign_message, MessageFormat, SignedMessage};
+
+mod verify_message;
+pub use verify_message::verify_message;
+
 use mpl_token_metadata::types::Creator;
 use serde::{Deserialize, Serialize};
-use solagent_core::solana_sdk::pubkey::Pubkey;
+use serde_json::{json, Value};
+use solagent_core::{solana_sdk::pubkey::Pubkey, Network, TransactionOutcome};
+use solagent_parameters::ToolParameters;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct DeployedData {
-    pub mint: String,      // mint address
-    pub signature: String, // Tx hash
+    pub mint: String,                    // mint address
+    pub transaction: TransactionOutcome, // landed or simulated tx
+    pub network: Network,                // cluster the tx landed on
 }
 
 impl DeployedData {
-    pub fn new(mint: String, signature: String) -> Self {
-        DeployedData { mint, signature }
+    pub fn new(mint: String, transaction: TransactionOutcome, network: Network) -> Self {
+        DeployedData {
+            mint,
+            transaction,
+            network,
+        }
     }
 }
 
@@ -102,3 +115,30 @@ impl NFTMetadata {
         }
     }
 }
+
+// `Creator` comes from mpl-token-metadata, so the schema is written out.
+impl ToolParameters for NFTMetadata {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": String::schema(),
+                "uri": { "type": "string", "description": "URI of the metadata JSON." },
+                "basis_points": { "type": "integer", "minimum": 0, "maximum": 10000, "description": "Royalties in basis points." },
+                "creators": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "address": { "type": "string" },
+                            "verified": { "type": "boolean" },
+                            "share": { "type": "integer", "minimum": 0, "maximum": 100 },
+                        },
+                        "required": ["address", "verified", "share"],
+                    },
+                },
+            },
+            "required": ["name", "uri"],
+        })
+    }
+}
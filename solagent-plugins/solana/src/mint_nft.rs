@@ -20,7 +20,6 @@ use mpl_token_metadata::{
     types::{Collection, DataV2},
 };
 use solagent_core::{
-    solana_client::client_error::ClientError,
     solana_program,
     solana_sdk::{
         self,
@@ -28,9 +27,8 @@ use solagent_core::{
         pubkey::Pubkey,
         signature::{Keypair, Signer},
         sysvar,
-        transaction::Transaction,
     },
-    SolanaAgentKit,
+    SolagentError, SolanaAgentKit, TransactionIntent,
 };
 
 /// Mints a new NFT
@@ -43,14 +41,17 @@ use solagent_core::{
 ///     * `uri`: A URI pointing to the NFT's assets (e.g., image, description) as a string.
 ///     * `seller_fee_basis_points`: An optional seller fee basis points as a number. This represents a percentage of the sale price (e.g., 500 means 5%).
 ///     * `creators`: An optional array of creator information. Each element contains the creator's address (as a string, to be converted to a `Pubkey` in practice) and their share (as a number, representing their contribution percentage).
+/// - `wallet`: Label of the kit wallet that pays for and owns the NFT; the default wallet when `None`.
 ///
 /// # Returns
-/// The transaction signature.
+/// The mint address and the transaction outcome.
 pub async fn mint_nft_to_collection(
     agent: &SolanaAgentKit,
     collection: Pubkey,
     metadata: NFTMetadata,
-) -> Result<DeployedData, ClientError> {
+    wallet: Option<&str>,
+) -> Result<DeployedData, SolagentError> {
+    let wallet = agent.wallet(wallet)?.pubkey();
     // Create a new keypair for the mint
     let mint_keypair = Keypair::new();
     let mint_pubkey = mint_keypair.pubkey();
@@ -58,7 +59,8 @@ pub async fn mint_nft_to_collection(
     // Create token mint account
     let min_rent = agent
         .connection
-        .get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)?;
+        .get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)
+        .await?;
 
     // Find the metadata account
     let metadata_seeds = &[
@@ -71,7 +73,7 @@ pub async fn mint_nft_to_collection(
 
     // Create the mint account
     let create_mint_account_ix = solana_sdk::system_instruction::create_account(
-        &agent.wallet.pubkey,
+        &wallet,
         &mint_pubkey,
         min_rent,
         82,
@@ -82,21 +84,18 @@ pub async fn mint_nft_to_collection(
     let init_mint_ix = spl_token::instruction::initialize_mint(
         &spl_token::id(),
         &mint_pubkey,
-        &agent.wallet.pubkey,
-        Some(&agent.wallet.pubkey),
+        &wallet,
+        Some(&wallet),
         0,
-    )
-    .expect("initialize_mint");
+    )?;
 
     // Create Associated Token Account
-    let associated_token_account = spl_associated_token_account::get_associated_token_address(
-        &agent.wallet.pubkey,
-        &mint_pubkey,
-    );
+    let associated_token_account =
+        spl_associated_token_account::get_associated_token_address(&wallet, &mint_pubkey);
     let create_assoc_account_ix =
         spl_associated_token_account::instruction::create_associated_token_account(
-            &agent.wallet.pubkey,
-            &agent.wallet.pubkey,
+            &wallet,
+            &wallet,
             &mint_pubkey,
             &spl_token::id(),
         );
@@ -106,19 +105,18 @@ pub async fn mint_nft_to_collection(
         &spl_token::id(),
         &mint_pubkey,
         &associated_token_account,
-        &agent.wallet.pubkey,
-        &[&agent.wallet.pubkey],
+        &wallet,
+        &[&wallet],
         1,
-    )
-    .expect("mint_to");
+    )?;
 
     // Create metadata account
     let create_metadata_ix = CreateMetadataAccountV3 {
         metadata: metadata_account,
         mint: mint_pubkey,
-        mint_authority: agent.wallet.pubkey,
-        payer: agent.wallet.pubkey,
-        update_authority: (agent.wallet.pubkey, false),
+        mint_authority: wallet,
+        payer: wallet,
+        update_authority: (wallet, false),
         system_program: solana_program::system_program::id(),
         rent: Some(sysvar::rent::id()),
     }
@@ -152,9 +150,9 @@ pub async fn mint_nft_to_collection(
     let create_master_edition_ix = CreateMasterEditionV3 {
         edition: master_edition_account,
         mint: mint_pubkey,
-        update_authority: agent.wallet.pubkey,
-        mint_authority: agent.wallet.pubkey,
-        payer: agent.wallet.pubkey,
+        update_authority: wallet,
+        mint_authority: wallet,
+        payer: wallet,
         metadata: metadata_account,
         token_program: spl_token::id(),
         system_program: solana_program::system_program::id(),
@@ -188,8 +186,8 @@ pub async fn mint_nft_to_collection(
 
     let verify_collection_ix = VerifyCollection {
         metadata: metadata_account,
-        collection_authority: agent.wallet.pubkey,
-        payer: agent.wallet.pubkey,
+        collection_authority: wallet,
+        payer: wallet,
         collection_mint: collection,
         collection: collection_metadata_account,
         collection_master_edition_account,
@@ -197,29 +195,68 @@ pub async fn mint_nft_to_collection(
     }
     .instruction();
 
-    // Create the transaction
-    let recent_blockhash = agent.connection.get_latest_blockhash()?;
-    let transaction = Transaction::new_signed_with_payer(
-        &[
-            create_mint_account_ix,
-            init_mint_ix,
-            create_assoc_account_ix,
-            mint_to_ix,
-            create_metadata_ix,
-            create_master_edition_ix,
-            verify_collection_ix,
-        ],
-        Some(&agent.wallet.pubkey),
-        &[&agent.wallet.keypair, &mint_keypair],
-        recent_blockhash,
-    );
-
     // Send and confirm the transaction
-    let signature = agent
-        .connection
-        .send_and_confirm_transaction(&transaction)?;
+    let transaction = agent
+        .sender()
+        .send_instructions(
+            &TransactionIntent::new("mint_nft"),
+            &[
+                create_mint_account_ix,
+                init_mint_ix,
+                create_assoc_account_ix,
+                mint_to_ix,
+                create_metadata_ix,
+                create_master_edition_ix,
+                verify_collection_ix,
+            ],
+            &[&mint_keypair],
+        )
+        .await?;
     Ok(DeployedData {
         mint: mint_pubkey.to_string(),
-        signature: signature.to_string(),
+        transaction,
+        network: agent.network.clone(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deploy_collection;
+    use mpl_token_metadata::accounts::Metadata;
+    use solagent_core::{solana_sdk::native_token::LAMPORTS_PER_SOL, Wallet};
+    use solagent_testkit::Svm;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_mint_nft_to_collection() {
+        let svm = Svm::new().with_token_metadata();
+        let wallet = Wallet::new();
+        let address = wallet.pubkey;
+        svm.fund(&address, LAMPORTS_PER_SOL);
+        let kit = svm.kit(wallet);
+
+        let options = NFTMetadata::new(
+            "Solagent",
+            "https://example.com/collection.json",
+            None,
+            None,
+        );
+        let collection = deploy_collection(&kit, &options, None).await.unwrap();
+        let collection = Pubkey::from_str(&collection.mint).unwrap();
+
+        let metadata = NFTMetadata::new("Solagent #1", "https://example.com/1.json", None, None);
+        let minted = mint_nft_to_collection(&kit, collection, metadata, None)
+            .await
+            .unwrap();
+        let mint = Pubkey::from_str(&minted.mint).unwrap();
+        let account = spl_associated_token_account::get_associated_token_address(&address, &mint);
+        assert_eq!(svm.token_balance(&account), 1);
+
+        let (metadata, _) = Metadata::find_pda(&mint);
+        let metadata = Metadata::safe_deserialize(&svm.account(&metadata).unwrap().data).unwrap();
+        let member = metadata.collection.unwrap();
+        assert_eq!(member.key, collection);
+        assert!(member.verified);
+    }
+}
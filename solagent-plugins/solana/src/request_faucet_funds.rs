@@ -12,16 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use solagent_core::{
-    solana_client::client_error::ClientError, solana_sdk::native_token::LAMPORTS_PER_SOL,
-    SolanaAgentKit,
-};
+use solagent_core::{solana_sdk::native_token::LAMPORTS_PER_SOL, SolagentError, SolanaAgentKit};
 
 /// Requests SOL from the Solana faucet (devnet/testnet only).
 ///
 /// # Parameters
 ///
 /// - `agent`: An instance of `SolanaAgentKit`.
+/// - `wallet`: Label of the kit wallet to fund; the default wallet when `None`.
 ///
 /// # Returns
 ///
@@ -29,15 +27,23 @@ use solagent_core::{
 ///
 /// # Errors
 ///
-/// Returns an error if the request fails or times out.
-pub async fn request_faucet_funds(agent: &SolanaAgentKit) -> Result<String, ClientError> {
+/// Returns an error if the request fails or times out, or if the agent is connected to mainnet.
+pub async fn request_faucet_funds(
+    agent: &SolanaAgentKit,
+    wallet: Option<&str>,
+) -> Result<String, SolagentError> {
+    agent.forbid_mainnet("request_faucet_funds")?;
+
+    let wallet = agent.wallet(wallet)?.pubkey();
+
     // Request airdrop of 5 SOL (5 * LAMPORTS_PER_SOL)
     let tx = agent
         .connection
-        .request_airdrop(&agent.wallet.pubkey, 5 * LAMPORTS_PER_SOL)?;
+        .request_airdrop(&wallet, 5 * LAMPORTS_PER_SOL)
+        .await?;
 
     // Confirm the transaction
-    agent.connection.confirm_transaction(&tx)?;
+    agent.connection.confirm_transaction(&tx).await?;
 
     Ok(tx.to_string())
 }
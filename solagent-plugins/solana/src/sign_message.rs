@@ -0,0 +1,161 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use solagent_core::{
+    solana_sdk::{message::VersionedMessage, offchain_message::OffchainMessage},
+    SolagentError, SolanaAgentKit,
+};
+use solagent_parameters::ToolParameters;
+
+/// How a message is turned into the bytes that get signed.
+#[derive(Serialize, Deserialize, ToolParameters, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageFormat {
+    /// The Solana off-chain message format, as used by `solana sign-offchain-message`: the
+    /// `\xffsolana offchain` signing domain, a header version (0), the message format and length,
+    /// then the message. The prefix keeps the signature from ever being valid for a transaction.
+    #[default]
+    Offchain,
+    /// The message bytes as they are, plain ed25519.
+    Raw,
+}
+
+/// A message signed by one of the agent's wallets.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignedMessage {
+    pub message: String,       // the message as given
+    pub signature: String,     // base58 ed25519 signature
+    pub signer: String,        // base58 public key of the wallet
+    pub format: MessageFormat, // how the message was encoded before signing
+}
+
+/// Signs a text message with one of the agent's wallets.
+///
+/// # Parameters
+///
+/// - `agent`: An instance of `SolanaAgentKit`.
+/// - `message`: The text to sign.
+/// - `format`: How the message is encoded before signing, see [`MessageFormat`].
+/// - `wallet`: Label of the kit wallet that signs; the default wallet when `None`.
+///
+/// # Returns
+///
+/// The signature and the signer's address. A [`MessageFormat::Raw`] message that decodes as a
+/// transaction message is refused, since its signature would authorize that transaction.
+pub async fn sign_message(
+    agent: &SolanaAgentKit,
+    message: &str,
+    format: MessageFormat,
+    wallet: Option<&str>,
+) -> Result<SignedMessage, SolagentError> {
+    let signer = agent.wallet(wallet)?;
+    let bytes = encode(message, format)?;
+    if format == MessageFormat::Raw && is_transaction_message(&bytes) {
+        return Err(SolagentError::InvalidInput(
+            "refusing to sign a raw message that is a valid transaction message".to_string(),
+        ));
+    }
+
+    let signature = signer.sign_message(&bytes).await?;
+    Ok(SignedMessage {
+        message: message.to_string(),
+        signature: signature.to_string(),
+        signer: signer.pubkey().to_string(),
+        format,
+    })
+}
+
+/// The bytes signed for `message` in `format`.
+pub(crate) fn encode(message: &str, format: MessageFormat) -> Result<Vec<u8>, SolagentError> {
+    match format {
+        MessageFormat::Offchain => OffchainMessage::new(0, message.as_bytes())
+            .and_then(|message| message.serialize())
+            .map_err(|e| {
+                SolagentError::InvalidInput(format!("cannot encode off-chain message: {}", e))
+            }),
+        MessageFormat::Raw => Ok(message.as_bytes().to_vec()),
+    }
+}
+
+fn is_transaction_message(bytes: &[u8]) -> bool {
+    bincode::deserialize::<VersionedMessage>(bytes).is_ok_and(|message| message.sanitize().is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solagent_core::{
+        solana_sdk::{
+            hash::Hash,
+            message::{Message, MessageHeader},
+            pubkey::Pubkey,
+        },
+        Config, Wallet,
+    };
+
+    fn agent() -> SolanaAgentKit {
+        SolanaAgentKit::new(
+            Wallet::new(),
+            "https://api.devnet.solana.com",
+            Config::default(),
+        )
+    }
+
+    #[test]
+    fn test_offchain_header() {
+        let bytes = encode("hello", MessageFormat::Offchain).unwrap();
+
+        assert_eq!(&bytes[..16], b"\xffsolana offchain"); // signing domain
+        assert_eq!(bytes[16], 0); // header version
+        assert_eq!(bytes[17], 0); // restricted ASCII
+        assert_eq!(&bytes[18..20], &5u16.to_le_bytes()); // message length
+        assert_eq!(&bytes[20..], b"hello");
+
+        // Non-ASCII text is limited UTF-8
+        let bytes = encode("héllo", MessageFormat::Offchain).unwrap();
+        assert_eq!(bytes[17], 1);
+        assert_eq!(&bytes[18..20], &6u16.to_le_bytes());
+
+        assert_eq!(encode("hello", MessageFormat::Raw).unwrap(), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_refuse_transaction_message() {
+        // A legacy message with one signer and no instructions, made of ASCII bytes only
+        let message = Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 0,
+            },
+            account_keys: vec![Pubkey::new_from_array([b'a'; 32])],
+            recent_blockhash: Hash::new_from_array([b'b'; 32]),
+            instructions: vec![],
+        };
+        let text = String::from_utf8(bincode::serialize(&message).unwrap()).unwrap();
+        let agent = agent();
+
+        let error = sign_message(&agent, &text, MessageFormat::Raw, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, SolagentError::InvalidInput(_)));
+
+        // The off-chain prefix makes the same text safe to sign
+        let signed = sign_message(&agent, &text, MessageFormat::Offchain, None)
+            .await
+            .unwrap();
+        assert_eq!(signed.message, text);
+    }
+}
@@ -13,90 +13,169 @@
 // limitations under the License.
 
 use solagent_core::{
-    solana_client::client_error::ClientError,
-    solana_sdk::{
-        program_pack::Pack, pubkey::Pubkey, system_instruction, transaction::Transaction,
-    },
-    SolanaAgentKit,
+    parse_pubkey,
+    solana_sdk::{program_pack::Pack, system_instruction},
+    SolagentError, SolanaAgentKit, TransactionIntent, TransactionOutcome,
 };
 use spl_associated_token_account::get_associated_token_address;
-use spl_token::{instruction::transfer as transfer_instruct, state::Mint};
+use spl_token::{
+    instruction::transfer as transfer_instruct,
+    state::{Account, Mint},
+};
 
 /// Transfer SOL or SPL tokens to a recipient
 ///
 /// `agent` - SolanaAgentKit instance
-/// `to` - Recipient's public key
+/// `to` - Recipient's public key, or the label of another wallet of the kit
 /// `amount` - Amount to transfer
 /// `mint` - Optional mint address for SPL tokens
+/// `wallet` - Label of the kit wallet to send from (default wallet when `None`)
 ///
-/// Returns the transaction signature.
+/// Returns the receipt of the landed transaction, or a simulation report in dry-run mode.
 pub async fn transfer(
     agent: &SolanaAgentKit,
     to: &str,
     amount: u64,
     mint: Option<String>,
-) -> Result<String, ClientError> {
-    match mint {
+    wallet: Option<&str>,
+) -> Result<TransactionOutcome, SolagentError> {
+    let wallet = agent.wallet(wallet)?.pubkey();
+    let to = agent.wallets.address("to", to)?;
+
+    let transfer_instruction = match mint {
         Some(mint) => {
             // Transfer SPL Token
-            let mint = Pubkey::from_str_const(&mint);
-            let to = Pubkey::from_str_const(to);
+            let mint = parse_pubkey("mint", &mint)?;
 
-            let from_ata = get_associated_token_address(&mint, &agent.wallet.pubkey);
-            let to_ata = get_associated_token_address(&mint, &to);
+            let from_ata = get_associated_token_address(&wallet, &mint);
+            let to_ata = get_associated_token_address(&to, &mint);
 
-            let account_info = &agent.connection.get_account(&mint).expect("get_account");
-            let mint_info = Mint::unpack_from_slice(&account_info.data).expect("unpack_from_slice");
+            let account_info = agent.connection.get_account(&mint).await?;
+            let mint_info = Mint::unpack_from_slice(&account_info.data)?;
 
-            let adjusted_amount = amount * 10u64.pow(mint_info.decimals as u32);
+            let adjusted_amount = 10u64
+                .checked_pow(mint_info.decimals as u32)
+                .and_then(|factor| amount.checked_mul(factor))
+                .ok_or_else(|| {
+                    SolagentError::InvalidInput(format!(
+                        "`amount` {} is too large for this token",
+                        amount
+                    ))
+                })?;
 
-            let transfer_instruction = transfer_instruct(
+            let available = agent
+                .connection
+                .get_account_with_commitment(&from_ata, agent.connection.commitment())
+                .await?
+                .value
+                .map(|account| Account::unpack_from_slice(&account.data))
+                .transpose()?
+                .map_or(0, |account| account.amount);
+            if available < adjusted_amount {
+                return Err(SolagentError::InsufficientFunds {
+                    required: adjusted_amount,
+                    available,
+                });
+            }
+
+            transfer_instruct(
                 &spl_token::id(),
                 &from_ata,
                 &to_ata,
-                &from_ata,
-                &[&agent.wallet.pubkey],
+                &wallet,
+                &[&wallet],
                 adjusted_amount,
-            )
-            .expect("transfer_instruct");
-
-            let transaction = Transaction::new_signed_with_payer(
-                &[transfer_instruction],
-                Some(&agent.wallet.pubkey),
-                &[&agent.wallet.keypair],
-                agent
-                    .connection
-                    .get_latest_blockhash()
-                    .expect("new_signed_with_payer"),
-            );
-
-            let signature = agent
-                .connection
-                .send_and_confirm_transaction(&transaction)
-                .expect("send_and_confirm_transaction");
-            Ok(signature.to_string())
+            )?
         }
         None => {
-            let transfer_instruction = system_instruction::transfer(
-                &agent.wallet.pubkey,
-                &Pubkey::from_str_const(to),
-                amount,
-            );
-            let transaction = Transaction::new_signed_with_payer(
-                &[transfer_instruction],
-                Some(&agent.wallet.pubkey),
-                &[&agent.wallet.keypair],
-                agent
-                    .connection
-                    .get_latest_blockhash()
-                    .expect("get_latest_blockhash"),
-            );
-
-            let signature = agent
-                .connection
-                .send_and_confirm_transaction(&transaction)
-                .expect("send_and_confirm_transaction");
-            Ok(signature.to_string())
+            let available = agent.connection.get_balance(&wallet).await?;
+            if available < amount {
+                return Err(SolagentError::InsufficientFunds {
+                    required: amount,
+                    available,
+                });
+            }
+
+            system_instruction::transfer(&wallet, &to, amount)
         }
+    };
+
+    agent
+        .sender()
+        .send_instructions(
+            &TransactionIntent::new("transfer").recipient(to),
+            &[transfer_instruction],
+            &[],
+        )
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solagent_core::{
+        solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey},
+        Wallet,
+    };
+    use solagent_testkit::Svm;
+
+    #[tokio::test]
+    async fn test_transfer_sol() {
+        let svm = Svm::new();
+        let wallet = Wallet::new();
+        let address = wallet.pubkey;
+        svm.fund(&address, LAMPORTS_PER_SOL);
+        let kit = svm.kit(wallet);
+        let to = Pubkey::new_unique();
+
+        let outcome = transfer(&kit, &to.to_string(), LAMPORTS_PER_SOL / 10, None, None)
+            .await
+            .unwrap();
+        assert!(outcome.signature().is_some());
+        assert_eq!(svm.balance(&to), LAMPORTS_PER_SOL / 10);
+
+        // More than the wallet holds is refused before anything is sent
+        let available = svm.balance(&address);
+        let error = transfer(&kit, &to.to_string(), LAMPORTS_PER_SOL, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            SolagentError::InsufficientFunds { required, available: held }
+                if required == LAMPORTS_PER_SOL && held == available
+        ));
+        assert_eq!(svm.balance(&to), LAMPORTS_PER_SOL / 10);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_token() {
+        let svm = Svm::new();
+        let wallet = Wallet::new();
+        let address = wallet.pubkey;
+        svm.fund(&address, LAMPORTS_PER_SOL);
+        let mint = svm.create_mint(&spl_token::ID, &address, 6);
+        let from = svm.mint_to(&mint, &address, 5_000_000);
+        let to = Pubkey::new_unique();
+        let to_account = svm.mint_to(&mint, &to, 0);
+        let kit = svm.kit(wallet);
+
+        // `amount` is in whole tokens
+        transfer(&kit, &to.to_string(), 2, Some(mint.to_string()), None)
+            .await
+            .unwrap();
+        assert_eq!(svm.token_balance(&to_account), 2_000_000);
+        assert_eq!(svm.token_balance(&from), 3_000_000);
+
+        let error = transfer(&kit, &to.to_string(), 4, Some(mint.to_string()), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            SolagentError::InsufficientFunds {
+                required: 4_000_000,
+                available: 3_000_000
+            }
+        ));
+        assert_eq!(svm.token_balance(&from), 3_000_000);
     }
 }
@@ -0,0 +1,106 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{sign_message::encode, MessageFormat};
+use solagent_core::{parse_pubkey, solana_sdk::signature::Signature, SolagentError};
+use std::str::FromStr;
+
+/// Verifies a signature over a text message.
+///
+/// # Parameters
+///
+/// - `message`: The text that was signed.
+/// - `signature`: The base58 ed25519 signature.
+/// - `signer`: The base58 public key of the expected signer.
+/// - `format`: How the message was encoded before signing, see [`MessageFormat`].
+///
+/// # Returns
+///
+/// Whether `signer` signed `message`, or an error if the signature or the address is malformed.
+pub fn verify_message(
+    message: &str,
+    signature: &str,
+    signer: &str,
+    format: MessageFormat,
+) -> Result<bool, SolagentError> {
+    let signer = parse_pubkey("signer", signer)?;
+    let signature = Signature::from_str(signature).map_err(|_| {
+        SolagentError::InvalidInput(format!(
+            "`signature` is not a valid signature: {}",
+            signature
+        ))
+    })?;
+    let bytes = encode(message, format)?;
+
+    Ok(signature.verify(signer.as_ref(), &bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sign_message;
+    use solagent_core::{Config, SolanaAgentKit, Wallet};
+
+    #[tokio::test]
+    async fn test_sign_and_verify() {
+        let agent = SolanaAgentKit::new(
+            Wallet::new(),
+            "https://api.devnet.solana.com",
+            Config::default(),
+        );
+        let other = Wallet::new().pubkey.to_string();
+
+        for format in [MessageFormat::Offchain, MessageFormat::Raw] {
+            let signed = sign_message(&agent, "hello solagent", format, None)
+                .await
+                .unwrap();
+            assert_eq!(signed.format, format);
+            assert_eq!(
+                signed.signer,
+                agent.wallet(None).unwrap().pubkey().to_string()
+            );
+
+            assert!(
+                verify_message("hello solagent", &signed.signature, &signed.signer, format)
+                    .unwrap()
+            );
+            // A tampered message or another signer does not verify
+            assert!(
+                !verify_message("hello solagent!", &signed.signature, &signed.signer, format)
+                    .unwrap()
+            );
+            assert!(!verify_message("hello solagent", &signed.signature, &other, format).unwrap());
+        }
+
+        // A signature is only valid in the format it was made for
+        let signed = sign_message(&agent, "hello solagent", MessageFormat::Offchain, None)
+            .await
+            .unwrap();
+        assert!(!verify_message(
+            "hello solagent",
+            &signed.signature,
+            &signed.signer,
+            MessageFormat::Raw
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let signer = Wallet::new().pubkey.to_string();
+        let error =
+            verify_message("hello", "not a signature", &signer, MessageFormat::Raw).unwrap_err();
+        assert!(matches!(error, SolagentError::InvalidInput(_)));
+    }
+}
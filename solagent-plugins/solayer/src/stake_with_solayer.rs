@@ -16,8 +16,8 @@ use base64::{engine::general_purpose, Engine};
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use serde::{Deserialize, Serialize};
 use solagent_core::{
-    solana_sdk::{commitment_config::CommitmentConfig, transaction::VersionedTransaction},
-    SolanaAgentKit,
+    solana_sdk::transaction::VersionedTransaction, Service, SolagentError, SolanaAgentKit,
+    TransactionIntent, TransactionOutcome,
 };
 
 #[derive(Serialize)]
@@ -37,65 +37,62 @@ struct StakeResponse {
 ///
 /// * `agent` - SolanaAgentKit instance
 /// * `amount` - Amount of SOL to stake
+/// * `wallet` - Label of the kit wallet to stake from (defaults to the default wallet)
 ///
 /// # Returns
 ///
-/// Transaction signature
+/// Receipt of the landed stake transaction, or a simulation report in dry-run mode
 pub async fn stake_with_solayer(
     agent: &SolanaAgentKit,
     amount: f64,
-) -> Result<String, Box<dyn std::error::Error>> {
-    let url = format!(
-        "https://app.solayer.org/api/action/restake/ssol?amount={}",
-        amount
-    );
+    wallet: Option<&str>,
+) -> Result<TransactionOutcome, SolagentError> {
+    agent.require_mainnet("stake_with_solayer")?;
+
+    let wallet = agent.wallet(wallet)?.pubkey();
+
+    let path = format!("action/restake/ssol?amount={}", amount);
     let mut headers = HeaderMap::new();
     headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
     let request = StakeRequest {
-        account: agent.wallet.pubkey.to_string(),
+        account: wallet.to_string(),
     };
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&url)
+    let response = agent
+        .http
+        .post(Service::Solayer, &path)
         .headers(headers)
         .json(&request)
         .send()
-        .await
-        .map_err(|e| format!("Failed to send request: {}", e))?;
+        .await?;
 
-    if !response.status().is_success() {
-        let error_data: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse error response: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let error_data: serde_json::Value = response.json().await?;
         let message = error_data
             .get("message")
             .and_then(|v| v.as_str())
             .unwrap_or("Staking request failed");
-        return Err(message.to_string().into());
+        return Err(SolagentError::Api {
+            status: status.as_u16(),
+            message: message.to_string(),
+        });
     }
 
-    let stake_response: StakeResponse = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse stake response: {}", e))?;
-
-    let transaction_data = general_purpose::STANDARD.decode(stake_response.transaction.as_str())?;
+    let stake_response: StakeResponse = response.json().await?;
 
-    let versioned_transaction: VersionedTransaction = bincode::deserialize(&transaction_data)?;
+    let transaction_data = general_purpose::STANDARD
+        .decode(stake_response.transaction.as_str())
+        .map_err(|e| SolagentError::Decode(format!("stake transaction: {}", e)))?;
 
-    let signed_transaction =
-        VersionedTransaction::try_new(versioned_transaction.message, &[&agent.wallet.keypair])?;
+    let versioned_transaction: VersionedTransaction = bincode::deserialize(&transaction_data)
+        .map_err(|e| SolagentError::Decode(format!("stake transaction: {}", e)))?;
 
-    let signature = agent.connection.send_transaction(&signed_transaction)?;
-
-    let latest_blockhash = agent.connection.get_latest_blockhash()?;
-
-    agent.connection.confirm_transaction_with_spinner(
-        &signature,
-        &latest_blockhash,
-        CommitmentConfig::confirmed(),
-    )?;
-
-    Ok(signature.to_string())
+    agent
+        .sender()
+        .send_transaction(
+            &TransactionIntent::new("stake_with_solayer"),
+            versioned_transaction,
+            &[],
+        )
+        .await
 }
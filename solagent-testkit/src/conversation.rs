@@ -0,0 +1,129 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{MemoryJournal, ScriptedModel};
+use solagent_core::{
+    rig::{
+        agent::{Agent, AgentBuilder},
+        completion::{Prompt, PromptError},
+    },
+    Config, SolanaAgentKit, ToolRegistry, Wallet,
+};
+use std::sync::Arc;
+
+/// A kit with a fresh wallet, pointed at a local validator url: tools that need a cluster fail
+/// unless one runs there.
+pub fn offline_kit() -> SolanaAgentKit {
+    SolanaAgentKit::new(Wallet::new(), "http://127.0.0.1:8899", Config::default())
+}
+
+/// A rig agent driven by a [`ScriptedModel`], its tools acting for a kit which journals into
+/// [`Conversation::journal`].
+pub struct Conversation {
+    pub kit: Arc<SolanaAgentKit>,
+    pub model: ScriptedModel,
+    pub journal: MemoryJournal,
+    agent: Agent<ScriptedModel>,
+}
+
+impl Conversation {
+    /// Gives the agent the tools `tools` builds for the kit, e.g. `solana_agent::tools`.
+    pub fn new(
+        kit: SolanaAgentKit,
+        model: ScriptedModel,
+        tools: impl FnOnce(&Arc<SolanaAgentKit>) -> ToolRegistry,
+    ) -> Self {
+        let journal = MemoryJournal::new();
+        let kit = Arc::new(kit.with_journal(journal.clone()));
+        let agent = tools(&kit).attach(AgentBuilder::new(model.clone())).build();
+        Self { kit, model, journal, agent }
+    }
+
+    /// Sends `prompt` to the agent. The answer is the reply of the model or, when the model calls a
+    /// tool, the output of the tool as JSON.
+    pub async fn prompt(&self, prompt: &str) -> Result<String, PromptError> {
+        self.agent.prompt(prompt).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Step;
+    use serde_json::{json, Value};
+    use solagent_core::ToolCategory;
+
+    fn solana_tools(kit: &Arc<SolanaAgentKit>) -> ToolRegistry {
+        solagent_rig_solana::tools(kit).into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn test_sign_and_verify() {
+        let model = ScriptedModel::new().call_tool("sign_message", json!({ "message": "gm" }));
+        let conversation = Conversation::new(offline_kit(), model, solana_tools);
+        let address = conversation.kit.wallet(None).unwrap().pubkey().to_string();
+
+        let output: Value = serde_json::from_str(&conversation.prompt("Sign gm").await.unwrap()).unwrap();
+        let signed = &output["signed"];
+        assert_eq!(signed["signer"], address);
+
+        // The next call takes the signature the model was just given
+        let args = json!({ "message": "gm", "signature": signed["signature"], "signer": address });
+        conversation.model.push(Step::CallTool { name: "verify_message".to_string(), args: args.clone() });
+        conversation.model.push(Step::Reply("The signature is valid.".to_string()));
+        let output: Value = serde_json::from_str(&conversation.prompt("Check it").await.unwrap()).unwrap();
+        assert_eq!(output, json!({ "valid": true }));
+        assert_eq!(conversation.prompt("Well?").await.unwrap(), "The signature is valid.");
+        assert_eq!(conversation.model.remaining(), 0);
+
+        let entries = conversation.journal.entries();
+        let tools: Vec<&str> = entries.iter().map(|entry| entry.tool.as_str()).collect();
+        assert_eq!(tools, ["sign_message", "verify_message"]);
+        assert_eq!(entries[0].arguments, json!({ "message": "gm" }));
+        assert_eq!(entries[1].arguments, args);
+        assert_eq!(entries[1].result, Some(json!({ "valid": true })));
+        assert!(entries.iter().all(|entry| entry.error.is_none() && entry.signatures.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_arguments() {
+        let args = json!({ "message": "gm", "signature": "1111", "signer": "not-an-address" });
+        let model = ScriptedModel::new().call_tool("verify_message", args.clone());
+        let conversation = Conversation::new(offline_kit(), model, solana_tools);
+
+        let error = conversation.prompt("Check it").await.unwrap_err();
+        assert!(matches!(error, PromptError::ToolError(_)));
+        assert!(error.to_string().contains("signer"));
+
+        let entries = conversation.journal.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].arguments, args);
+        assert!(entries[0].error.as_ref().unwrap().starts_with("Invalid arguments"));
+    }
+
+    #[tokio::test]
+    async fn test_read_only_agent() {
+        let args = json!({ "to": "So11111111111111111111111111111111111111112", "amount": 1 });
+        let model = ScriptedModel::new().call_tool("transfer", args);
+        let conversation =
+            Conversation::new(offline_kit(), model, |kit| solana_tools(kit).only(&[ToolCategory::ReadOnly]));
+
+        assert!(matches!(conversation.prompt("Send 1 lamport").await, Err(PromptError::CompletionError(_))));
+        let requests = conversation.model.requests();
+        let offered = requests[0].tool_names();
+        assert!(offered.contains(&"get_balance"));
+        assert!(!offered.contains(&"transfer") && !offered.contains(&"sign_message"));
+        assert!(conversation.journal.entries().is_empty());
+    }
+}
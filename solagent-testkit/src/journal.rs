@@ -0,0 +1,80 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use solagent_core::{Journal, JournalEntry, JournalError, JournalQuery};
+use std::sync::{Arc, Mutex};
+
+/// A journal kept in memory. Clones share their entries, so a test keeps one to read what the kit wrote.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryJournal {
+    entries: Arc<Mutex<Vec<JournalEntry>>>,
+}
+
+impl MemoryJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every entry, in the order they were written.
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl Journal for MemoryJournal {
+    fn append(&self, entry: &JournalEntry) -> Result<(), JournalError> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).push(entry.clone());
+        Ok(())
+    }
+
+    fn query(&self, query: &JournalQuery) -> Result<Vec<JournalEntry>, JournalError> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let matching = entries.iter().filter(|entry| query.matches(entry)).cloned();
+        Ok(matching.take(query.limit.unwrap_or(usize::MAX)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn entry(tool: &str) -> JournalEntry {
+        JournalEntry {
+            tool: tool.to_string(),
+            started_at: 1,
+            finished_at: 2,
+            arguments: Value::Null,
+            result: None,
+            error: None,
+            signatures: vec![],
+            fee: None,
+            mints: vec![],
+            dry_run: false,
+        }
+    }
+
+    #[test]
+    fn test_memory_journal() {
+        let journal = MemoryJournal::new();
+        let kit_journal: Box<dyn Journal> = Box::new(journal.clone());
+        for tool in ["transfer", "trade", "transfer"] {
+            kit_journal.append(&entry(tool)).unwrap();
+        }
+        assert_eq!(journal.entries().len(), 3);
+
+        let query = JournalQuery { tool: Some("transfer".to_string()), limit: Some(1), ..Default::default() };
+        assert_eq!(journal.query(&query).unwrap(), [entry("transfer")]);
+    }
+}
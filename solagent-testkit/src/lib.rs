@@ -0,0 +1,44 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test agents without a live model.
+//!
+//! [`ScriptedModel`] is a rig completion model that answers each request with the next step of its
+//! script: a reply, or a call to one of the tools the agent offers. [`Conversation`] runs such a
+//! model against real tools, acting for a kit that journals into a [`MemoryJournal`], so that a test
+//! can assert on what the model was offered, the arguments and outputs of each tool call, and the
+//! transactions it sent:
+//!
+//! ```ignore
+//! let model = ScriptedModel::new().call_tool("get_wallet_address", json!({}));
+//! let conversation = Conversation::new(offline_kit(), model, solana_agent::tools);
+//!
+//! let output = conversation.prompt("What is my address?").await?;
+//! assert_eq!(conversation.journal.entries()[0].tool, "get_wallet_address");
+//! ```
+//!
+//! With the `svm` feature, [`Svm`] runs the kit against an in-process Solana runtime instead of a
+//! cluster, so that tools sending transactions can be tested too.
+
+mod conversation;
+mod journal;
+mod model;
+#[cfg(feature = "svm")]
+mod svm;
+
+pub use conversation::{offline_kit, Conversation};
+pub use journal::MemoryJournal;
+pub use model::{RecordedRequest, ScriptedModel, Step};
+#[cfg(feature = "svm")]
+pub use svm::{Svm, TOKEN_METADATA_ID};
@@ -0,0 +1,171 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde_json::Value;
+use solagent_core::rig::completion::{
+    CompletionError, CompletionModel, CompletionRequest, CompletionResponse, ModelChoice, ToolDefinition,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+/// What the model answers to one completion request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    /// A text reply.
+    Reply(String),
+    /// A call to the tool named `name`, which the request must offer.
+    CallTool { name: String, args: Value },
+}
+
+/// A completion request the model received.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub prompt: String,
+    pub preamble: Option<String>,
+    /// The tools the agent offered with the request.
+    pub tools: Vec<ToolDefinition>,
+}
+
+impl RecordedRequest {
+    /// The names of the tools offered.
+    pub fn tool_names(&self) -> Vec<&str> {
+        self.tools.iter().map(|tool| tool.name.as_str()).collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Script {
+    steps: VecDeque<Step>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// A rig completion model answering with the steps of a script, in order.
+///
+/// Clones share the script, so a test can keep one to add steps while the conversation runs, e.g.
+/// arguments taken from an earlier tool output, and to read the requests the agent sent. A request
+/// past the end of the script, or a call to a tool the agent did not offer, fails with
+/// [`CompletionError::ProviderError`].
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedModel {
+    script: Arc<Mutex<Script>>,
+}
+
+impl ScriptedModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a step at the end of the script.
+    pub fn push(&self, step: Step) {
+        self.lock().steps.push_back(step);
+    }
+
+    /// Returns `self` with a text reply added to the script.
+    pub fn reply(self, text: impl Into<String>) -> Self {
+        self.push(Step::Reply(text.into()));
+        self
+    }
+
+    /// Returns `self` with a tool call added to the script.
+    pub fn call_tool(self, name: impl Into<String>, args: Value) -> Self {
+        self.push(Step::CallTool { name: name.into(), args });
+        self
+    }
+
+    /// Every request received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// The steps not played yet.
+    pub fn remaining(&self) -> usize {
+        self.lock().steps.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Script> {
+        self.script.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn next(&self, request: CompletionRequest) -> Result<ModelChoice, CompletionError> {
+        let mut script = self.lock();
+        let request = RecordedRequest { prompt: request.prompt, preamble: request.preamble, tools: request.tools };
+        let step = script.steps.pop_front();
+        let offered = |name: &str| request.tools.iter().any(|tool| tool.name == name);
+        let choice = match step {
+            Some(Step::Reply(text)) => Ok(ModelChoice::Message(text)),
+            Some(Step::CallTool { name, args }) if offered(&name) => Ok(ModelChoice::ToolCall(name, args)),
+            Some(Step::CallTool { name, .. }) => {
+                let offered = request.tool_names().join(", ");
+                Err(CompletionError::ProviderError(format!(
+                    "the script calls `{}`, not offered among [{}]",
+                    name, offered
+                )))
+            }
+            None => {
+                Err(CompletionError::ProviderError(format!("the script has no step left for `{}`", request.prompt)))
+            }
+        };
+        script.requests.push(request);
+        choice
+    }
+}
+
+impl CompletionModel for ScriptedModel {
+    type Response = ();
+
+    async fn completion(&self, request: CompletionRequest) -> Result<CompletionResponse<()>, CompletionError> {
+        let choice = self.next(request)?;
+        Ok(CompletionResponse { choice, raw_response: () })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(model: &ScriptedModel, prompt: &str, tools: &[&str]) -> CompletionRequest {
+        let tools = tools
+            .iter()
+            .map(|name| ToolDefinition { name: name.to_string(), description: String::new(), parameters: json!({}) })
+            .collect();
+        model.completion_request(prompt).tools(tools).build()
+    }
+
+    #[tokio::test]
+    async fn test_scripted_model() {
+        let model = ScriptedModel::new().call_tool("get_balance", json!({})).call_tool("transfer", json!({}));
+        model.push(Step::Reply("Done.".to_string()));
+
+        let response = model.completion(request(&model, "What is my balance?", &["get_balance"])).await.unwrap();
+        assert!(
+            matches!(response.choice, ModelChoice::ToolCall(name, args) if name == "get_balance" && args == json!({}))
+        );
+
+        let error = model.completion(request(&model, "Send 1 SOL", &["get_balance"])).await.unwrap_err();
+        assert!(error.to_string().contains("`transfer`, not offered among [get_balance]"));
+
+        let response = model.completion(request(&model, "Thanks", &[])).await.unwrap();
+        assert!(matches!(response.choice, ModelChoice::Message(text) if text == "Done."));
+        assert!(model.completion(request(&model, "Bye", &[])).await.is_err());
+
+        assert_eq!(model.remaining(), 0);
+        let requests = model.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[1].prompt, "Send 1 SOL");
+        assert_eq!(requests[1].tool_names(), ["get_balance"]);
+    }
+}
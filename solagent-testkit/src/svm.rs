@@ -0,0 +1,625 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use litesvm::LiteSVM;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use solagent_core::{
+    async_trait,
+    solana_client::{
+        client_error::{ClientErrorKind, Result as ClientResult},
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::RpcClientConfig,
+        rpc_request::{RpcError, RpcRequest},
+        rpc_sender::{RpcSender, RpcTransportStats},
+    },
+    solana_sdk::{
+        account::Account,
+        clock::Clock,
+        commitment_config::CommitmentConfig,
+        message::VersionedMessage,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey,
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::{TransactionError, VersionedTransaction},
+    },
+    AgentSigner, Config, Network, SendOptions, SolanaAgentKit,
+};
+use solana_account_decoder::{
+    encode_ui_account,
+    parse_account_data::{AccountAdditionalDataV2, SplTokenAdditionalData},
+    parse_token::real_number_string_trimmed,
+    UiAccount, UiAccountEncoding,
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    state::{Account as TokenAccount, AccountState, Mint},
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+/// The Token Metadata program, which LiteSVM does not ship: `scripts/dump-programs.sh` dumps it
+/// from mainnet, and [`Svm::with_token_metadata`] loads it.
+pub const TOKEN_METADATA_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const TOKEN_METADATA_PROGRAM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/programs/mpl_token_metadata.so");
+
+/// What LiteSVM charges per signature.
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+/// How many blocks a blockhash stays valid on a cluster.
+const MAX_PROCESSING_AGE: u64 = 150;
+
+/// An executed transaction, as `getSignatureStatuses` and `getTransaction` report it.
+struct Landed {
+    slot: u64,
+    /// The transaction as it was sent, base64 encoded; `None` for airdrops.
+    transaction: Option<String>,
+    result: Result<(), TransactionError>,
+    fee: u64,
+    pre_balances: Vec<u64>,
+    post_balances: Vec<u64>,
+    logs: Vec<String>,
+    compute_units: u64,
+}
+
+struct State {
+    svm: LiteSVM,
+    landed: HashMap<Signature, Landed>,
+    /// Every address a transaction or helper touched, since LiteSVM cannot list the token
+    /// accounts of an owner.
+    addresses: BTreeSet<Pubkey>,
+}
+
+/// An in-process Solana runtime for kits, with the SPL Token, Token-2022 and Associated Token
+/// Account programs loaded, and Token Metadata on demand.
+///
+/// [`Svm::kit`] builds a kit whose RPC requests are served by the runtime, so that the plugins run
+/// unchanged and every transaction lands at once, deterministically. Each landed transaction moves
+/// the runtime to the next slot with a fresh blockhash. Clones share the runtime, so a test can
+/// fund wallets, mint tokens and read balances around the calls of an agent:
+///
+/// ```ignore
+/// let svm = Svm::new();
+/// let wallet = Wallet::new();
+/// svm.fund(&wallet.pubkey, LAMPORTS_PER_SOL);
+/// let kit = svm.kit(wallet);
+///
+/// transfer(&kit, &recipient.to_string(), 1_000, None, None).await?;
+/// assert_eq!(svm.balance(&recipient), 1_000);
+/// ```
+///
+/// Signatures are not verified: the kit's sender simulates unsigned transactions, and refuses to
+/// send one it cannot sign completely.
+#[derive(Clone)]
+pub struct Svm {
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for Svm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Svm {
+    pub fn new() -> Self {
+        let svm = LiteSVM::new().with_sigverify(false);
+        let state = State { svm, landed: HashMap::new(), addresses: BTreeSet::new() };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    /// Loads the Token Metadata program, for the tools that create tokens, collections or NFTs.
+    ///
+    /// # Panics
+    ///
+    /// When `programs/mpl_token_metadata.so` is missing from this crate: run
+    /// `scripts/dump-programs.sh` once.
+    pub fn with_token_metadata(self) -> Self {
+        self.lock().svm.add_program_from_file(TOKEN_METADATA_ID, TOKEN_METADATA_PROGRAM).unwrap_or_else(|error| {
+            panic!("cannot load {}, run scripts/dump-programs.sh first: {}", TOKEN_METADATA_PROGRAM, error)
+        });
+        self
+    }
+
+    /// A localnet kit acting with `wallet`, connected to this runtime.
+    ///
+    /// Transactions are not rebroadcast after a delay, since they land when sent.
+    pub fn kit(&self, wallet: impl AgentSigner + 'static) -> SolanaAgentKit {
+        let network = Network::Localnet;
+        let mut kit = SolanaAgentKit::new_with_network(wallet, network.rpc_url(), network.clone(), Config::default())
+            .with_send_options(SendOptions { rebroadcast_interval: Duration::ZERO, ..Default::default() });
+        kit.connection = self.connection();
+        kit
+    }
+
+    /// A nonblocking RPC client served by this runtime.
+    pub fn connection(&self) -> RpcClient {
+        RpcClient::new_sender(SvmSender(self.clone()), RpcClientConfig::with_commitment(CommitmentConfig::confirmed()))
+    }
+
+    /// Airdrops `lamports` to `address`.
+    pub fn fund(&self, address: &Pubkey, lamports: u64) {
+        self.lock().airdrop(address, lamports).unwrap_or_else(|error| panic!("cannot fund {}: {}", address, error));
+    }
+
+    /// Creates a mint of `token_program`, SPL Token or Token-2022, without any supply.
+    pub fn create_mint(&self, token_program: &Pubkey, authority: &Pubkey, decimals: u8) -> Pubkey {
+        let mint = Pubkey::new_unique();
+        let state = Mint {
+            mint_authority: COption::Some(*authority),
+            supply: 0,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        self.lock().store(mint, token_program, state);
+        mint
+    }
+
+    /// Mints `amount` base units of `mint` to the associated token account of `owner`, creating it
+    /// when needed, and returns the address of that account. An `amount` of 0 only creates the
+    /// account.
+    ///
+    /// # Panics
+    ///
+    /// When `mint` is not a mint.
+    pub fn mint_to(&self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let mut state = self.lock();
+        let (token_program, mut mint_state) = state
+            .get(mint)
+            .and_then(|account| Some((account.owner, StateWithExtensions::<Mint>::unpack(&account.data).ok()?.base)))
+            .unwrap_or_else(|| panic!("{} is not a mint", mint));
+        mint_state.supply += amount;
+        state.store(*mint, &token_program, mint_state);
+
+        let address = get_associated_token_address_with_program_id(owner, mint, &token_program);
+        let mut account = state.get(&address).as_ref().and_then(token_account).unwrap_or(TokenAccount {
+            mint: *mint,
+            owner: *owner,
+            state: AccountState::Initialized,
+            ..Default::default()
+        });
+        account.amount += amount;
+        state.store(address, &token_program, account);
+        address
+    }
+
+    /// The account at `address`, if it exists.
+    pub fn account(&self, address: &Pubkey) -> Option<Account> {
+        self.lock().get(address)
+    }
+
+    /// The lamports of `address`.
+    pub fn balance(&self, address: &Pubkey) -> u64 {
+        self.account(address).map_or(0, |account| account.lamports)
+    }
+
+    /// The base units held by the token account at `address`; 0 when it does not exist.
+    pub fn token_balance(&self, address: &Pubkey) -> u64 {
+        self.account(address).as_ref().and_then(token_account).map_or(0, |account| account.amount)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl State {
+    fn slot(&self) -> u64 {
+        self.svm.get_sysvar::<Clock>().slot
+    }
+
+    /// The account at `address`; closed accounts do not exist, as on a cluster.
+    fn get(&self, address: &Pubkey) -> Option<Account> {
+        self.svm.get_account(address).filter(|account| account.lamports > 0)
+    }
+
+    fn balances(&self, addresses: &[Pubkey]) -> Vec<u64> {
+        addresses.iter().map(|address| self.get(address).map_or(0, |account| account.lamports)).collect()
+    }
+
+    fn decimals(&self, mint: &Pubkey) -> Option<u8> {
+        let account = self.get(mint)?;
+        Some(StateWithExtensions::<Mint>::unpack(&account.data).ok()?.base.decimals)
+    }
+
+    /// Writes the packed `state` at the start of the account at `address`, keeping the extensions
+    /// behind it, or creates a rent-exempt account owned by `owner` for it.
+    fn store<T: Pack>(&mut self, address: Pubkey, owner: &Pubkey, state: T) {
+        let mut account = self.get(&address).unwrap_or_else(|| Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(T::LEN),
+            data: vec![0; T::LEN],
+            owner: *owner,
+            executable: false,
+            rent_epoch: 0,
+        });
+        state.pack_into_slice(&mut account.data[..T::LEN]);
+        self.svm.set_account(address, account).unwrap_or_else(|error| panic!("cannot store {}: {}", address, error));
+        self.addresses.insert(address);
+    }
+
+    fn ui_account(&self, address: &Pubkey, account: &Account, encoding: UiAccountEncoding) -> UiAccount {
+        // `jsonParsed` token accounts need the decimals of their mint
+        let additional_data = token_account(account).and_then(|token| self.decimals(&token.mint)).map(|decimals| {
+            AccountAdditionalDataV2 { spl_token_additional_data: Some(SplTokenAdditionalData::with_decimals(decimals)) }
+        });
+        encode_ui_account(address, account, encoding, additional_data, None)
+    }
+
+    fn context(&self, value: impl Into<Value>) -> Value {
+        json!({ "context": { "slot": self.slot() }, "value": value.into() })
+    }
+
+    /// Records an executed transaction, then moves to the next slot with a fresh blockhash, so that
+    /// the same instructions can be sent again.
+    fn land(&mut self, signature: Signature, landed: Landed) {
+        self.landed.insert(signature, landed);
+        let slot = self.slot() + 1;
+        self.svm.warp_to_slot(slot);
+        self.svm.expire_blockhash();
+    }
+
+    fn airdrop(&mut self, address: &Pubkey, lamports: u64) -> Result<Signature, String> {
+        let pre_balances = self.balances(&[*address]);
+        let meta = self.svm.airdrop(address, lamports).map_err(|failed| failed.err.to_string())?;
+        self.addresses.insert(*address);
+        let landed = Landed {
+            slot: self.slot(),
+            transaction: None,
+            result: Ok(()),
+            fee: 0,
+            pre_balances,
+            post_balances: self.balances(&[*address]),
+            logs: meta.logs,
+            compute_units: meta.compute_units_consumed,
+        };
+        self.land(meta.signature, landed);
+        Ok(meta.signature)
+    }
+
+    /// Executes a transaction. A failed one lands too, with its error, so that the sender reports
+    /// it instead of waiting for the blockhash to expire.
+    fn send(&mut self, encoded: &str) -> Result<Signature, String> {
+        let transaction: VersionedTransaction = decode(encoded)?;
+        let signature = *transaction.signatures.first().ok_or("the transaction has no signature")?;
+        if self.landed.contains_key(&signature) {
+            return Ok(signature);
+        }
+
+        let keys = transaction.message.static_account_keys().to_vec();
+        let signatures = u64::from(transaction.message.header().num_required_signatures);
+        let pre_balances = self.balances(&keys);
+        let (result, meta) = match self.svm.send_transaction(transaction) {
+            Ok(meta) => (Ok(()), meta),
+            Err(failed) => (Err(failed.err), failed.meta),
+        };
+        self.addresses.extend(keys.iter().copied());
+
+        let landed = Landed {
+            slot: self.slot(),
+            transaction: Some(encoded.to_string()),
+            fee: if result.is_ok() { LAMPORTS_PER_SIGNATURE * signatures } else { 0 },
+            result,
+            pre_balances,
+            post_balances: self.balances(&keys),
+            logs: meta.logs,
+            compute_units: meta.compute_units_consumed,
+        };
+        self.land(signature, landed);
+        Ok(signature)
+    }
+
+    fn simulate(&self, encoded: &str, config: &Value) -> Result<Value, String> {
+        let mut transaction: VersionedTransaction = decode(encoded)?;
+        if config["replaceRecentBlockhash"] == true {
+            transaction.message.set_recent_blockhash(self.svm.latest_blockhash());
+        }
+        let addresses = match config["accounts"]["addresses"].as_array() {
+            Some(addresses) => Some(addresses.iter().map(address).collect::<Result<Vec<_>, _>>()?),
+            None => None,
+        };
+        let encoding = encoding(&config["accounts"]);
+
+        let value = match self.svm.simulate_transaction(transaction) {
+            Ok(info) => {
+                let accounts = addresses.map(|addresses| {
+                    let post = |address: &Pubkey| match info.post_accounts.iter().find(|(key, _)| key == address) {
+                        Some((_, account)) => {
+                            Some(Account::from(account.clone())).filter(|account| account.lamports > 0)
+                        }
+                        None => self.get(address),
+                    };
+                    let accounts: Vec<Option<UiAccount>> = addresses
+                        .iter()
+                        .map(|address| post(address).map(|account| self.ui_account(address, &account, encoding)))
+                        .collect();
+                    accounts
+                });
+                json!({
+                    "err": null,
+                    "logs": info.meta.logs,
+                    "accounts": accounts,
+                    "unitsConsumed": info.meta.compute_units_consumed,
+                })
+            }
+            Err(failed) => json!({
+                "err": failed.err,
+                "logs": failed.meta.logs,
+                "accounts": null,
+                "unitsConsumed": failed.meta.compute_units_consumed,
+            }),
+        };
+        Ok(self.context(value))
+    }
+
+    fn transaction(&self, signature: &Signature) -> Value {
+        let Some(landed) = self.landed.get(signature) else {
+            return Value::Null;
+        };
+        // Airdrops have no transaction to show
+        let Some(transaction) = &landed.transaction else {
+            return Value::Null;
+        };
+        json!({
+            "slot": landed.slot,
+            "blockTime": null,
+            "transaction": {
+                "transaction": [transaction, "base64"],
+                "meta": {
+                    "err": landed.result.as_ref().err(),
+                    "status": landed.result,
+                    "fee": landed.fee,
+                    "preBalances": landed.pre_balances,
+                    "postBalances": landed.post_balances,
+                    "logMessages": landed.logs,
+                    "computeUnitsConsumed": landed.compute_units,
+                },
+            },
+        })
+    }
+
+    fn token_accounts_by_owner(&self, params: &Value) -> Result<Value, String> {
+        let owner = address(&params[0])?;
+        let mint = params[1].get("mint").map(address).transpose()?;
+        let program = params[1].get("programId").map(address).transpose()?;
+        let encoding = encoding(&params[2]);
+
+        let accounts: Vec<Value> = self
+            .addresses
+            .iter()
+            .filter_map(|address| {
+                let account = self.get(address)?;
+                let token = token_account(&account)?;
+                let matches = token.owner == owner
+                    && (mint.is_none() || mint == Some(token.mint))
+                    && (program.is_none() || program == Some(account.owner));
+                matches.then(|| {
+                    json!({ "pubkey": address.to_string(), "account": self.ui_account(address, &account, encoding) })
+                })
+            })
+            .collect();
+        Ok(self.context(accounts))
+    }
+
+    fn token_account_balance(&self, address: &Pubkey) -> Result<Value, String> {
+        let token = self
+            .get(address)
+            .as_ref()
+            .and_then(token_account)
+            .ok_or_else(|| format!("could not find token account {}", address))?;
+        let decimals = self.decimals(&token.mint).ok_or_else(|| format!("could not find mint {}", token.mint))?;
+        Ok(self.context(json!({
+            "amount": token.amount.to_string(),
+            "decimals": decimals,
+            "uiAmount": token.amount as f64 / 10f64.powi(i32::from(decimals)),
+            "uiAmountString": real_number_string_trimmed(token.amount, decimals),
+        })))
+    }
+
+    /// Answers one JSON-RPC request, as a node would.
+    fn handle(&mut self, request: RpcRequest, params: &Value) -> Result<Value, String> {
+        let value = match request {
+            RpcRequest::GetAccountInfo => {
+                let address = address(&params[0])?;
+                let account =
+                    self.get(&address).map(|account| self.ui_account(&address, &account, encoding(&params[1])));
+                self.context(json!(account))
+            }
+            RpcRequest::GetMultipleAccounts => {
+                let addresses = params[0].as_array().ok_or("expected a list of addresses")?;
+                let mut accounts = vec![];
+                for value in addresses {
+                    let address = address(value)?;
+                    accounts.push(
+                        self.get(&address).map(|account| self.ui_account(&address, &account, encoding(&params[1]))),
+                    );
+                }
+                self.context(json!(accounts))
+            }
+            RpcRequest::GetBalance => self.context(self.balances(&[address(&params[0])?])[0]),
+            RpcRequest::GetTokenAccountBalance => self.token_account_balance(&address(&params[0])?)?,
+            RpcRequest::GetTokenAccountsByOwner => self.token_accounts_by_owner(params)?,
+            RpcRequest::GetLatestBlockhash => self.context(json!({
+                "blockhash": self.svm.latest_blockhash().to_string(),
+                "lastValidBlockHeight": self.slot() + MAX_PROCESSING_AGE,
+            })),
+            RpcRequest::GetBlockHeight | RpcRequest::GetSlot => json!(self.slot()),
+            RpcRequest::GetMinimumBalanceForRentExemption => {
+                let len = params[0].as_u64().ok_or("expected a data length")?;
+                json!(self.svm.minimum_balance_for_rent_exemption(len as usize))
+            }
+            RpcRequest::GetFeeForMessage => {
+                let message: VersionedMessage = decode(params[0].as_str().ok_or("expected a message")?)?;
+                self.context(LAMPORTS_PER_SIGNATURE * u64::from(message.header().num_required_signatures))
+            }
+            RpcRequest::GetRecentPrioritizationFees => json!([]),
+            RpcRequest::GetVersion => json!({ "solana-core": "2.1.7", "feature-set": 0 }),
+            RpcRequest::RequestAirdrop => {
+                let lamports = params[1].as_u64().ok_or("expected lamports")?;
+                json!(self.airdrop(&address(&params[0])?, lamports)?.to_string())
+            }
+            RpcRequest::SendTransaction => {
+                json!(self.send(params[0].as_str().ok_or("expected a transaction")?)?.to_string())
+            }
+            RpcRequest::SimulateTransaction => {
+                self.simulate(params[0].as_str().ok_or("expected a transaction")?, &params[1])?
+            }
+            RpcRequest::GetSignatureStatuses => {
+                let signatures = params[0].as_array().ok_or("expected a list of signatures")?;
+                let mut statuses = vec![];
+                for value in signatures {
+                    statuses.push(self.landed.get(&signature(value)?).map(|landed| {
+                        json!({
+                            "slot": landed.slot,
+                            "confirmations": null,
+                            "status": landed.result,
+                            "err": landed.result.as_ref().err(),
+                            "confirmationStatus": "finalized",
+                        })
+                    }));
+                }
+                self.context(json!(statuses))
+            }
+            RpcRequest::GetTransaction => self.transaction(&signature(&params[0])?),
+            request => return Err(format!("`{}` is not supported by the test SVM", request)),
+        };
+        Ok(value)
+    }
+}
+
+/// Serves the RPC requests of a kit from an [`Svm`].
+struct SvmSender(Svm);
+
+#[async_trait]
+impl RpcSender for SvmSender {
+    async fn send(&self, request: RpcRequest, params: Value) -> ClientResult<Value> {
+        let result = self.0.lock().handle(request, &params);
+        result.map_err(|message| ClientErrorKind::RpcError(RpcError::RpcRequestError(message)).into())
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        RpcTransportStats::default()
+    }
+
+    fn url(&self) -> String {
+        "svm".to_string()
+    }
+}
+
+/// The token account state of `account`, for both token programs.
+fn token_account(account: &Account) -> Option<TokenAccount> {
+    if account.owner != spl_token::ID && account.owner != spl_token_2022::ID {
+        return None;
+    }
+    Some(StateWithExtensions::<TokenAccount>::unpack(&account.data).ok()?.base)
+}
+
+fn address(value: &Value) -> Result<Pubkey, String> {
+    value.as_str().and_then(|value| Pubkey::from_str(value).ok()).ok_or_else(|| format!("invalid address {}", value))
+}
+
+fn signature(value: &Value) -> Result<Signature, String> {
+    value
+        .as_str()
+        .and_then(|value| Signature::from_str(value).ok())
+        .ok_or_else(|| format!("invalid signature {}", value))
+}
+
+/// The account encoding of a request config; base64 when it has none.
+fn encoding(config: &Value) -> UiAccountEncoding {
+    serde_json::from_value(config["encoding"].clone()).unwrap_or(UiAccountEncoding::Base64)
+}
+
+/// Decodes a base64 encoded transaction or message.
+fn decode<T: DeserializeOwned>(encoded: &str) -> Result<T, String> {
+    let bytes = STANDARD.decode(encoded).map_err(|error| error.to_string())?;
+    bincode::deserialize(&bytes).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Conversation, ScriptedModel};
+    use solagent_core::{
+        solana_client::rpc_request::TokenAccountsFilter, solana_sdk::native_token::LAMPORTS_PER_SOL, ToolRegistry,
+        Wallet,
+    };
+
+    fn solana_tools(kit: &Arc<SolanaAgentKit>) -> ToolRegistry {
+        solagent_rig_solana::tools(kit).into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn test_fund_and_mint() {
+        let svm = Svm::new();
+        let owner = Pubkey::new_unique();
+        svm.fund(&owner, LAMPORTS_PER_SOL);
+        svm.fund(&owner, LAMPORTS_PER_SOL);
+
+        let mint = svm.create_mint(&spl_token::ID, &owner, 6);
+        let mint_2022 = svm.create_mint(&spl_token_2022::ID, &owner, 0);
+        let account = svm.mint_to(&mint, &owner, 1_500_000);
+        svm.mint_to(&mint, &owner, 500_000);
+        let account_2022 = svm.mint_to(&mint_2022, &owner, 0);
+        assert_eq!(svm.token_balance(&account), 2_000_000);
+
+        let connection = svm.connection();
+        assert_eq!(connection.get_balance(&owner).await.unwrap(), 2 * LAMPORTS_PER_SOL);
+        let balance = connection.get_token_account_balance(&account).await.unwrap();
+        assert_eq!((balance.amount.as_str(), balance.ui_amount_string.as_str()), ("2000000", "2"));
+
+        let accounts = connection.get_token_accounts_by_owner(&owner, TokenAccountsFilter::Mint(mint)).await.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].pubkey, account.to_string());
+        let accounts = connection
+            .get_token_accounts_by_owner(&owner, TokenAccountsFilter::ProgramId(spl_token_2022::ID))
+            .await
+            .unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].pubkey, account_2022.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_transfer_conversation() {
+        let svm = Svm::new();
+        let wallet = Wallet::new();
+        let address = wallet.pubkey;
+        svm.fund(&address, LAMPORTS_PER_SOL);
+
+        let recipient = Pubkey::new_unique();
+        let args = json!({ "to": recipient.to_string(), "amount": 1_000_000 });
+        let model = ScriptedModel::new().call_tool("transfer", args.clone()).call_tool("transfer", args);
+        let conversation = Conversation::new(svm.kit(wallet), model, solana_tools);
+
+        let output: Value = serde_json::from_str(&conversation.prompt("Send 0.001 SOL").await.unwrap()).unwrap();
+        assert_eq!(output["transaction"]["status"], "confirmed");
+        // The same transfer again lands as a new transaction
+        conversation.prompt("Once more").await.unwrap();
+        assert_eq!(svm.balance(&recipient), 2_000_000);
+
+        let entries = conversation.journal.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].signatures, [output["transaction"]["signature"].as_str().unwrap()]);
+        assert_ne!(entries[0].signatures, entries[1].signatures);
+        let fees: u64 = entries.iter().map(|entry| entry.fee.unwrap()).sum();
+        assert_eq!(svm.balance(&address), LAMPORTS_PER_SOL - 2_000_000 - fees);
+    }
+}
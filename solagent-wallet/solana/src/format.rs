@@ -0,0 +1,161 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Keystore, Wallet, WalletError};
+use anyhow::{Context, Result};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::io::Write;
+use zeroize::Zeroizing;
+
+/// How a wallet is written to a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletFormat<'a> {
+    /// The base58 private key, as text.
+    Base58,
+    /// The 64 secret key bytes as a JSON array, the `id.json` format of the Solana CLI.
+    Json,
+    /// An encrypted [`Keystore`], sealed with `password`.
+    Keystore { password: &'a str },
+}
+
+impl Wallet {
+    /// Writes the wallet to `file_path` in `format`, readable by the owner only on Unix.
+    ///
+    /// [`WalletFormat::Json`] files work with the `solana` CLI as they are.
+    pub fn save_as(&self, file_path: &str, format: WalletFormat) -> Result<()> {
+        let contents = Zeroizing::new(match format {
+            WalletFormat::Base58 => self.to_base58(),
+            WalletFormat::Json => serde_json::to_string(&Zeroizing::new(self.keypair.to_bytes())[..])?,
+            WalletFormat::Keystore { password } => serde_json::to_string_pretty(&Keystore::encrypt(self, password)?)?,
+        });
+        write_private(file_path, contents.as_bytes())
+            .with_context(|| format!("Failed to save wallet to file: {}", file_path))?;
+        Ok(())
+    }
+
+    /// Loads a wallet file in any [`WalletFormat`], telling them apart by their contents.
+    ///
+    /// `password` is only used, and needed, for encrypted keystores.
+    pub fn load(file_path: &str, password: Option<&str>) -> Result<Self> {
+        let contents = Zeroizing::new(
+            std::fs::read_to_string(file_path).with_context(|| format!("Failed to read wallet file: {}", file_path))?,
+        );
+        let contents = contents.trim();
+
+        if contents.starts_with('{') {
+            let password = password.ok_or_else(|| WalletError::PasswordRequired(file_path.to_string()))?;
+            let keystore: Keystore =
+                serde_json::from_str(contents).map_err(|e| WalletError::Keystore(format!("{}: {}", file_path, e)))?;
+            Ok(keystore.decrypt(password)?)
+        } else if contents.starts_with('[') {
+            Self::from_json_bytes(contents)
+        } else {
+            Self::from_base58(contents)
+        }
+    }
+
+    /// Creates a wallet from the secret key bytes as a JSON array, e.g. the contents of a Solana CLI `id.json`.
+    pub fn from_json_bytes(json: &str) -> Result<Self> {
+        let secret_key: Zeroizing<Vec<u8>> =
+            Zeroizing::new(serde_json::from_str(json).map_err(|_| WalletError::InvalidPrivateKeyBytes)?);
+        let keypair = Keypair::from_bytes(&secret_key).map_err(|_| WalletError::InvalidPrivateKeyBytes)?;
+        let pubkey = keypair.pubkey();
+        Ok(Self { keypair, pubkey })
+    }
+}
+
+fn write_private(file_path: &str, contents: &[u8]) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(file_path)?;
+    // `mode` only applies to new files.
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    file.write_all(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("solagent-{}-{}", name, Keypair::new().pubkey()))
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_save_as_and_load() -> Result<()> {
+        let wallet = Wallet::new();
+        for format in [WalletFormat::Base58, WalletFormat::Json] {
+            let path = temp_path("wallet");
+            wallet.save_as(&path, format)?;
+            assert_eq!(Wallet::load(&path, None)?.pubkey, wallet.pubkey);
+            assert_eq!(Wallet::from_file(&path)?.pubkey, wallet.pubkey);
+            std::fs::remove_file(&path)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_matches_solana_cli() -> Result<()> {
+        let wallet = Wallet::new();
+        let path = temp_path("id.json");
+        wallet.save_as(&path, WalletFormat::Json)?;
+
+        // The Solana CLI writes the bytes as a compact JSON array.
+        let expected = format!(
+            "[{}]",
+            wallet.keypair.to_bytes().iter().map(|byte| byte.to_string()).collect::<Vec<_>>().join(",")
+        );
+        assert_eq!(std::fs::read_to_string(&path)?, expected);
+
+        // ... and may add a trailing newline when edited by hand.
+        std::fs::write(&path, format!("{}\n", expected))?;
+        assert_eq!(Wallet::load(&path, None)?.pubkey, wallet.pubkey);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            wallet.save_as(&path, WalletFormat::Json)?;
+            assert_eq!(std::fs::metadata(&path)?.permissions().mode() & 0o777, 0o600);
+        }
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_keystore() -> Result<()> {
+        let wallet = Wallet::new();
+        let path = temp_path("keystore.json");
+        std::fs::write(&path, serde_json::to_string(&Keystore::encrypt_with(&wallet, "password", 4)?)?)?;
+
+        assert_eq!(Wallet::load(&path, Some("password"))?.pubkey, wallet.pubkey);
+        let error = Wallet::from_file(&path).unwrap_err();
+        assert!(error.to_string().contains("is an encrypted keystore"));
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_json_bytes_invalid() {
+        assert!(Wallet::from_json_bytes("[1, 2, 3]").is_err());
+        assert!(Wallet::from_json_bytes("[1, 2, 300]").is_err());
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Wallet, WalletError};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, KeyInit, OsRng, Payload},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use std::str::FromStr;
+use zeroize::Zeroizing;
+
+/// Version of the keystore format written by [`Keystore::encrypt`].
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// scrypt cost used for new keystores: N = 2^15, r = 8, p = 1, about 32 MiB of memory per attempt.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// A wallet's secret key encrypted with a password, as stored on disk.
+///
+/// The key is derived from the password with scrypt and the secret key is sealed with XChaCha20-Poly1305.
+/// The public key is kept in the clear, so a keystore can be identified without its password, and is
+/// authenticated along with the ciphertext.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    pub pubkey: String,
+    pub kdf: Kdf,
+    pub cipher: Cipher,
+    /// Base64 of the sealed 64-byte secret key.
+    pub ciphertext: String,
+}
+
+/// How the encryption key is derived from the password.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "lowercase")]
+pub enum Kdf {
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+        /// Base64 of the random salt.
+        salt: String,
+    },
+}
+
+/// How the secret key is sealed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "name")]
+pub enum Cipher {
+    #[serde(rename = "xchacha20poly1305")]
+    XChaCha20Poly1305 {
+        /// Base64 of the random nonce.
+        nonce: String,
+    },
+}
+
+impl Keystore {
+    /// Encrypts the secret key of `wallet` with `password`.
+    pub fn encrypt(wallet: &Wallet, password: &str) -> Result<Self, WalletError> {
+        Self::encrypt_with(wallet, password, SCRYPT_LOG_N)
+    }
+
+    pub(crate) fn encrypt_with(wallet: &Wallet, password: &str, log_n: u8) -> Result<Self, WalletError> {
+        let mut salt = [0u8; 32];
+        let mut nonce = [0u8; 24];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let kdf = Kdf::Scrypt { log_n, r: SCRYPT_R, p: SCRYPT_P, salt: STANDARD.encode(salt) };
+        let key = kdf.derive(password)?;
+        let pubkey = wallet.pubkey.to_string();
+        let secret = Zeroizing::new(wallet.keypair.to_bytes());
+        let ciphertext = XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+            .encrypt(XNonce::from_slice(&nonce), Payload { msg: &secret[..], aad: pubkey.as_bytes() })
+            .map_err(|_| WalletError::Keystore("encryption failed".to_string()))?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            pubkey,
+            kdf,
+            cipher: Cipher::XChaCha20Poly1305 { nonce: STANDARD.encode(nonce) },
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+
+    /// Decrypts the wallet with `password`.
+    pub fn decrypt(&self, password: &str) -> Result<Wallet, WalletError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(WalletError::Keystore(format!("unsupported version {}", self.version)));
+        }
+        let pubkey = Pubkey::from_str(&self.pubkey).map_err(|_| WalletError::Keystore("invalid pubkey".to_string()))?;
+        let Cipher::XChaCha20Poly1305 { nonce } = &self.cipher;
+        let nonce = decode("nonce", nonce)?;
+        if nonce.len() != 24 {
+            return Err(WalletError::Keystore("invalid nonce".to_string()));
+        }
+
+        let key = self.kdf.derive(password)?;
+        let ciphertext = decode("ciphertext", &self.ciphertext)?;
+        let secret = XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+            .decrypt(XNonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: self.pubkey.as_bytes() })
+            .map(Zeroizing::new)
+            .map_err(|_| WalletError::InvalidPassword)?;
+
+        let keypair = Keypair::from_bytes(&secret).map_err(|_| WalletError::InvalidPrivateKeyBytes)?;
+        if keypair.pubkey() != pubkey {
+            return Err(WalletError::Keystore("secret key does not match pubkey".to_string()));
+        }
+        Ok(Wallet { keypair, pubkey })
+    }
+}
+
+impl Kdf {
+    fn derive(&self, password: &str) -> Result<Zeroizing<[u8; 32]>, WalletError> {
+        let Kdf::Scrypt { log_n, r, p, salt } = self;
+        let salt = decode("salt", salt)?;
+        let params = scrypt::Params::new(*log_n, *r, *p, 32)
+            .map_err(|e| WalletError::Keystore(format!("invalid scrypt parameters: {}", e)))?;
+
+        let mut key = Zeroizing::new([0u8; 32]);
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key[..])
+            .map_err(|e| WalletError::Keystore(format!("scrypt: {}", e)))?;
+        Ok(key)
+    }
+}
+
+fn decode(field: &str, value: &str) -> Result<Vec<u8>, WalletError> {
+    STANDARD.decode(value).map_err(|_| WalletError::Keystore(format!("invalid base64 in `{}`", field)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Cheap scrypt parameters, the default cost makes the tests slow.
+    const TEST_LOG_N: u8 = 4;
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let wallet = Wallet::new();
+        let keystore = Keystore::encrypt_with(&wallet, "correct horse", TEST_LOG_N).unwrap();
+        assert_eq!(keystore.version, KEYSTORE_VERSION);
+        assert_eq!(keystore.pubkey, wallet.pubkey.to_string());
+        assert!(!keystore.ciphertext.contains(&wallet.to_base58()));
+
+        let decrypted = keystore.decrypt("correct horse").unwrap();
+        assert_eq!(decrypted.pubkey, wallet.pubkey);
+        assert_eq!(decrypted.to_base58(), wallet.to_base58());
+
+        assert!(matches!(keystore.decrypt("wrong horse"), Err(WalletError::InvalidPassword)));
+    }
+
+    #[test]
+    fn test_keystore_rejects_tampering() {
+        let keystore = Keystore::encrypt_with(&Wallet::new(), "password", TEST_LOG_N).unwrap();
+
+        // The pubkey is authenticated with the ciphertext.
+        let swapped = Keystore { pubkey: Wallet::new().pubkey.to_string(), ..keystore.clone() };
+        assert!(matches!(swapped.decrypt("password"), Err(WalletError::InvalidPassword)));
+
+        let future = Keystore { version: 2, ..keystore };
+        assert!(
+            matches!(future.decrypt("password"), Err(WalletError::Keystore(message)) if message.contains("version"))
+        );
+    }
+
+    #[test]
+    fn test_keystore_format() {
+        let keystore = Keystore::encrypt_with(&Wallet::new(), "password", TEST_LOG_N).unwrap();
+        let json = serde_json::to_value(&keystore).unwrap();
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["kdf"]["name"], "scrypt");
+        assert_eq!(json["kdf"]["log_n"], TEST_LOG_N);
+        assert_eq!(json["cipher"]["name"], "xchacha20poly1305");
+
+        let parsed: Keystore = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, keystore);
+    }
+}
@@ -12,11 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod format;
+mod keystore;
+mod mnemonic;
+mod remote;
+mod signer;
+
+pub use format::WalletFormat;
+pub use keystore::{Cipher, Kdf, Keystore, KEYSTORE_VERSION};
+pub use mnemonic::{derivation_path, DEFAULT_DERIVATION_PATH};
+pub use remote::RemoteSigner;
+pub use signer::AgentSigner;
+
 use anyhow::{Context, Result};
 use dotenv::dotenv;
 use solana_sdk::{bs58, pubkey::Pubkey, signature::Keypair, signer::Signer};
-use std::env;
+use std::{env, fmt};
 use thiserror::Error;
+use zeroize::Zeroizing;
 
 #[derive(Error, Debug)]
 pub enum WalletError {
@@ -28,10 +41,21 @@ pub enum WalletError {
     InvalidPrivateKeyBytes,
     #[error("File operation failed: {0}")]
     FileError(String),
+    #[error("Wrong password or corrupted keystore")]
+    InvalidPassword,
+    #[error("Invalid keystore: {0}")]
+    Keystore(String),
+    #[error("Wallet file {0} is an encrypted keystore, a password is required")]
+    PasswordRequired(String),
+    #[error("Invalid mnemonic: {0}")]
+    InvalidMnemonic(String),
+    #[error("Invalid derivation path: {0}")]
+    InvalidDerivationPath(String),
 }
 
 /// Represents a wallet containing a keypair and its corresponding public key.
-#[derive(Debug)]
+///
+/// Its `Debug` output never shows the secret key.
 pub struct Wallet {
     /// The keypair associated with the wallet.  This contains the private key.
     pub keypair: Keypair,
@@ -39,6 +63,12 @@ pub struct Wallet {
     pub pubkey: Pubkey,
 }
 
+impl fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wallet").field("keypair", &"<redacted>").field("pubkey", &self.pubkey).finish()
+    }
+}
+
 impl Default for Wallet {
     /// Creates a new wallet with a randomly generated keypair.
     fn default() -> Self {
@@ -70,8 +100,9 @@ impl Wallet {
     pub fn from_env(variable_name: &str) -> Result<Self> {
         dotenv().ok(); // Load environment variables from .env file (if present)
 
-        let private_key =
-            env::var(variable_name).with_context(|| format!("Environment variable '{}' not found", variable_name))?;
+        let private_key = Zeroizing::new(
+            env::var(variable_name).with_context(|| format!("Environment variable '{}' not found", variable_name))?,
+        );
 
         Self::from_base58(&private_key)
     }
@@ -87,7 +118,8 @@ impl Wallet {
     /// * `Ok(Wallet)` - If the wallet was successfully created.
     /// * `Err(String)` - If the private key is invalid or not properly encoded.
     pub fn from_base58(private_key: &str) -> Result<Self> {
-        let secret_key = bs58::decode(private_key).into_vec().map_err(|_| WalletError::InvalidBase58Key)?;
+        let secret_key =
+            Zeroizing::new(bs58::decode(private_key).into_vec().map_err(|_| WalletError::InvalidBase58Key)?);
 
         let keypair = Keypair::from_bytes(&secret_key).map_err(|_| WalletError::InvalidPrivateKeyBytes)?;
 
@@ -100,19 +132,30 @@ impl Wallet {
         self.keypair.to_base58_string()
     }
 
-    /// Saves the wallet's private key to a file.
+    /// Saves the wallet's private key to a file, in plaintext base58.
+    ///
+    /// Prefer [`Wallet::save_encrypted`] for any key worth protecting; see [`Wallet::save_as`] for other formats.
     pub fn save_to_file(&self, file_path: &str) -> Result<()> {
-        let private_key = self.to_base58();
-        std::fs::write(file_path, private_key)
-            .with_context(|| format!("Failed to save wallet to file: {}", file_path))?;
-        Ok(())
+        self.save_as(file_path, WalletFormat::Base58)
     }
 
-    /// Loads a wallet from a private key file.
+    /// Loads a wallet from an unencrypted private key file, as base58 text or a Solana CLI JSON byte array.
     pub fn from_file(file_path: &str) -> Result<Self> {
-        let private_key =
+        Self::load(file_path, None)
+    }
+
+    /// Saves the wallet to a [`Keystore`] file, its secret key encrypted with `password`.
+    pub fn save_encrypted(&self, file_path: &str, password: &str) -> Result<()> {
+        self.save_as(file_path, WalletFormat::Keystore { password })
+    }
+
+    /// Loads a wallet from a [`Keystore`] file written by [`Wallet::save_encrypted`].
+    pub fn from_encrypted_file(file_path: &str, password: &str) -> Result<Self> {
+        let keystore =
             std::fs::read_to_string(file_path).with_context(|| format!("Failed to read wallet file: {}", file_path))?;
-        Self::from_base58(&private_key)
+        let keystore: Keystore =
+            serde_json::from_str(&keystore).map_err(|e| WalletError::Keystore(format!("{}: {}", file_path, e)))?;
+        Ok(keystore.decrypt(password)?)
     }
 }
 
@@ -171,6 +214,29 @@ mod tests {
         assert_eq!(result.unwrap_err().to_string(), "Invalid private key bytes");
     }
 
+    #[test]
+    fn test_wallet_debug_is_redacted() {
+        let wallet = Wallet::new();
+        let debug = format!("{:?}", wallet);
+        assert!(debug.contains(&wallet.pubkey.to_string()));
+        assert!(!debug.contains(&wallet.to_base58()));
+    }
+
+    #[test]
+    fn test_wallet_from_encrypted_file() -> Result<()> {
+        let wallet = Wallet::new();
+        let path = env::temp_dir().join(format!("solagent-keystore-{}.json", wallet.pubkey));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, serde_json::to_string(&Keystore::encrypt_with(&wallet, "password", 4)?)?)?;
+
+        assert_eq!(Wallet::from_encrypted_file(path, "password")?.pubkey, wallet.pubkey);
+        let error = Wallet::from_encrypted_file(path, "wrong").unwrap_err();
+        assert_eq!(error.to_string(), "Wrong password or corrupted keystore");
+
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
     #[test]
     fn test_wallet_to_base58() -> Result<()> {
         let wallet = Wallet::new();
@@ -0,0 +1,128 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Wallet, WalletError};
+use anyhow::Result;
+use bip39::Mnemonic;
+use solana_sdk::{
+    derivation_path::DerivationPath,
+    signer::{keypair::keypair_from_seed_and_derivation_path, Signer},
+};
+use std::ops::Range;
+use zeroize::Zeroizing;
+
+/// Derivation path of the first account, as used by the Solana CLI and most wallets.
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+/// The standard Solana derivation path of account `index`: `m/44'/501'/{index}'/0'`.
+pub fn derivation_path(index: u32) -> String {
+    format!("m/44'/501'/{}'/0'", index)
+}
+
+impl Wallet {
+    /// Generates a new random BIP39 mnemonic of `word_count` words (12, 15, 18, 21 or 24).
+    ///
+    /// Write it down: it is the backup of every account derived from it.
+    pub fn generate_mnemonic(word_count: usize) -> Result<Zeroizing<String>> {
+        let mnemonic = Mnemonic::generate(word_count).map_err(|e| WalletError::InvalidMnemonic(e.to_string()))?;
+        Ok(Zeroizing::new(mnemonic.to_string()))
+    }
+
+    /// Recovers the wallet derived from a BIP39 `phrase` and optional `passphrase` along `derivation_path`,
+    /// e.g. [`DEFAULT_DERIVATION_PATH`].
+    pub fn from_mnemonic(phrase: &str, passphrase: &str, derivation_path: &str) -> Result<Self> {
+        let seed = seed(phrase, passphrase)?;
+        derive(&seed[..], derivation_path)
+    }
+
+    /// Derives the accounts at the standard paths of `indexes` from one phrase, e.g. one sub-account per
+    /// strategy, all recoverable from the same backup.
+    pub fn derive_accounts(phrase: &str, passphrase: &str, indexes: Range<u32>) -> Result<Vec<Self>> {
+        let seed = seed(phrase, passphrase)?;
+        indexes.map(|index| derive(&seed[..], &derivation_path(index))).collect()
+    }
+}
+
+/// The BIP39 seed of `phrase` and `passphrase`, both NFKD-normalized first as the standard requires.
+fn seed(phrase: &str, passphrase: &str) -> Result<Zeroizing<[u8; 64]>> {
+    let mnemonic = Mnemonic::parse(phrase).map_err(|e| WalletError::InvalidMnemonic(e.to_string()))?;
+    Ok(Zeroizing::new(mnemonic.to_seed(passphrase)))
+}
+
+fn derive(seed: &[u8], path: &str) -> Result<Wallet> {
+    let path = DerivationPath::from_absolute_path_str(path)
+        .map_err(|e| WalletError::InvalidDerivationPath(format!("{}: {}", path, e)))?;
+    let keypair = keypair_from_seed_and_derivation_path(seed, Some(path))
+        .map_err(|e| WalletError::InvalidDerivationPath(e.to_string()))?;
+    let pubkey = keypair.pubkey();
+    Ok(Wallet { keypair, pubkey })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_from_mnemonic() -> Result<()> {
+        let wallet = Wallet::from_mnemonic(PHRASE, "", DEFAULT_DERIVATION_PATH)?;
+        assert_eq!(wallet.pubkey, Wallet::from_mnemonic(PHRASE, "", DEFAULT_DERIVATION_PATH)?.pubkey);
+        assert_ne!(wallet.pubkey, Wallet::from_mnemonic(PHRASE, "", &derivation_path(1))?.pubkey);
+        assert_ne!(wallet.pubkey, Wallet::from_mnemonic(PHRASE, "secret", DEFAULT_DERIVATION_PATH)?.pubkey);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_mnemonic_unicode_passphrase() -> Result<()> {
+        // "é" composed and decomposed normalize to the same passphrase
+        let composed = Wallet::from_mnemonic(PHRASE, "caf\u{e9}", DEFAULT_DERIVATION_PATH)?;
+        let decomposed = Wallet::from_mnemonic(PHRASE, "cafe\u{301}", DEFAULT_DERIVATION_PATH)?;
+        assert_eq!(composed.pubkey, decomposed.pubkey);
+        assert_ne!(composed.pubkey, Wallet::from_mnemonic(PHRASE, "cafe", DEFAULT_DERIVATION_PATH)?.pubkey);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_mnemonic_invalid() {
+        let error = Wallet::from_mnemonic("abandon abandon", "", DEFAULT_DERIVATION_PATH).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid mnemonic"));
+
+        let error = Wallet::from_mnemonic(PHRASE, "", "m/44'/501'/zero'").unwrap_err();
+        assert!(error.to_string().starts_with("Invalid derivation path"));
+    }
+
+    #[test]
+    fn test_derive_accounts() -> Result<()> {
+        let accounts = Wallet::derive_accounts(PHRASE, "", 0..3)?;
+        assert_eq!(accounts.len(), 3);
+        for (index, account) in accounts.iter().enumerate() {
+            let path = derivation_path(index as u32);
+            assert_eq!(account.pubkey, Wallet::from_mnemonic(PHRASE, "", &path)?.pubkey);
+        }
+        assert_eq!(Wallet::derive_accounts(PHRASE, "", 2..3)?[0].pubkey, accounts[2].pubkey);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_mnemonic() -> Result<()> {
+        let phrase = Wallet::generate_mnemonic(24)?;
+        assert_eq!(phrase.split_whitespace().count(), 24);
+        Wallet::from_mnemonic(&phrase, "", DEFAULT_DERIVATION_PATH)?;
+
+        assert!(Wallet::generate_mnemonic(13).is_err());
+        Ok(())
+    }
+}
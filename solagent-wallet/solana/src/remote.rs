@@ -0,0 +1,200 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AgentSigner;
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Signature, signer::SignerError};
+use std::str::FromStr;
+
+/// A signer whose key is held by an HTTP signing service.
+///
+/// The service exposes two endpoints:
+/// - `GET {url}/pubkey` answers `{"pubkey": "<base58>"}`;
+/// - `POST {url}/sign` takes `{"pubkey": "<base58>", "message": "<base64>"}` and answers
+///   `{"signature": "<base58>"}`.
+///
+/// Every signature is verified against the public key before it is used.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    url: String,
+    pubkey: Pubkey,
+    token: Option<String>,
+    client: reqwest::Client,
+}
+
+#[derive(Serialize)]
+struct SignRequest {
+    pubkey: String,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+#[derive(Deserialize)]
+struct PubkeyResponse {
+    pubkey: String,
+}
+
+impl RemoteSigner {
+    /// Creates a signer for `pubkey`, a key held by the service at `url`.
+    pub fn new(url: impl Into<String>, pubkey: Pubkey) -> Self {
+        let url = url.into().trim_end_matches('/').to_string();
+        Self { url, pubkey, token: None, client: reqwest::Client::new() }
+    }
+
+    /// Asks the service at `url` which key it signs with, sending `token` as a bearer token with this
+    /// and every later request.
+    pub async fn connect(url: impl Into<String>, token: Option<&str>) -> Result<Self, SignerError> {
+        let mut signer = Self::new(url, Pubkey::default());
+        signer.token = token.map(str::to_string);
+        let response: PubkeyResponse = signer.request(signer.client.get(format!("{}/pubkey", signer.url))).await?;
+        signer.pubkey = Pubkey::from_str(&response.pubkey)
+            .map_err(|e| SignerError::Protocol(format!("invalid pubkey `{}`: {}", response.pubkey, e)))?;
+        Ok(signer)
+    }
+
+    /// Sends `token` as a bearer token with every request.
+    pub fn bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    async fn request<T: for<'de> Deserialize<'de>>(&self, request: reqwest::RequestBuilder) -> Result<T, SignerError> {
+        let request = match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        let response = request.send().await.map_err(|e| SignerError::Connection(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(SignerError::Custom(format!("signing service answered {}: {}", status, message)));
+        }
+        response.json().await.map_err(|e| SignerError::Protocol(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl AgentSigner for RemoteSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let body = SignRequest { pubkey: self.pubkey.to_string(), message: STANDARD.encode(message) };
+        let response: SignResponse = self.request(self.client.post(format!("{}/sign", self.url)).json(&body)).await?;
+
+        let signature = Signature::from_str(&response.signature)
+            .map_err(|e| SignerError::Protocol(format!("invalid signature `{}`: {}", response.signature, e)))?;
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::Protocol(format!("signature does not match {}", self.pubkey)));
+        }
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::{Keypair, Signer};
+    use wiremock::{
+        matchers::{bearer_token, body_partial_json, method, path},
+        Mock, MockServer, Request, Respond, ResponseTemplate,
+    };
+
+    /// Stands in for the signing service, holding `keypair`.
+    struct Service {
+        keypair: Keypair,
+    }
+
+    impl Respond for Service {
+        fn respond(&self, request: &Request) -> ResponseTemplate {
+            let body: serde_json::Value = request.body_json().unwrap();
+            let message = STANDARD.decode(body["message"].as_str().unwrap()).unwrap();
+            let signature = self.keypair.sign_message(&message);
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "signature": signature.to_string() }))
+        }
+    }
+
+    async fn service(keypair: Keypair) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/pubkey"))
+            .and(bearer_token("secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "pubkey": keypair.pubkey().to_string()
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sign"))
+            .and(bearer_token("secret"))
+            .and(body_partial_json(serde_json::json!({ "pubkey": keypair.pubkey().to_string() })))
+            .respond_with(Service { keypair })
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer() {
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let server = service(keypair).await;
+
+        let signer = RemoteSigner::connect(server.uri(), Some("secret")).await.unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+
+        let signature = signer.sign_message(b"hello").await.unwrap();
+        assert!(signature.verify(pubkey.as_ref(), b"hello"));
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_rejects_foreign_signatures() {
+        let server = service(Keypair::new()).await;
+        let other = Pubkey::new_unique();
+        Mock::given(method("POST"))
+            .and(path("/sign"))
+            .and(body_partial_json(serde_json::json!({ "pubkey": other.to_string() })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "signature": Keypair::new().sign_message(b"hello").to_string()
+            })))
+            .mount(&server)
+            .await;
+
+        let error = RemoteSigner::new(server.uri(), other).sign_message(b"hello").await.unwrap_err();
+        assert!(matches!(error, SignerError::Protocol(message) if message.contains("does not match")));
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_service_error() {
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let server = service(keypair).await;
+
+        // Without the bearer token the service does not sign.
+        let error = RemoteSigner::new(server.uri(), pubkey).sign_message(b"hello").await.unwrap_err();
+        assert!(matches!(error, SignerError::Custom(message) if message.contains("404")));
+
+        // Nor does it tell its key.
+        let error = RemoteSigner::connect(server.uri(), None).await.unwrap_err();
+        assert!(matches!(error, SignerError::Custom(message) if message.contains("404")));
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Wallet;
+use async_trait::async_trait;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::{Signer, SignerError},
+    transaction::VersionedTransaction,
+};
+use std::sync::Arc;
+
+/// Something that can sign for an agent's account.
+///
+/// The private key does not have to live in the process: a signer may ask a remote service, a KMS or a
+/// threshold scheme for each signature, which is why signing is async.
+#[async_trait]
+pub trait AgentSigner: Send + Sync {
+    /// The public key this signer signs for.
+    fn pubkey(&self) -> Pubkey;
+
+    /// Signs `message`, e.g. a serialized transaction message.
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError>;
+
+    /// Adds this signer's signature to `transaction`, which must list it among its required signers.
+    async fn sign_transaction(&self, transaction: &mut VersionedTransaction) -> Result<(), SignerError> {
+        let pubkey = self.pubkey();
+        let message = &transaction.message;
+        let required = usize::from(message.header().num_required_signatures);
+        let position = message.static_account_keys()[..required]
+            .iter()
+            .position(|key| *key == pubkey)
+            .ok_or(SignerError::KeypairPubkeyMismatch)?;
+
+        let signature = self.sign_message(&message.serialize()).await?;
+        transaction.signatures.resize(required, Signature::default());
+        transaction.signatures[position] = signature;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AgentSigner for Wallet {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.keypair.try_sign_message(message)
+    }
+}
+
+#[async_trait]
+impl<S: AgentSigner + ?Sized> AgentSigner for Arc<S> {
+    fn pubkey(&self) -> Pubkey {
+        (**self).pubkey()
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        (**self).sign_message(message).await
+    }
+
+    async fn sign_transaction(&self, transaction: &mut VersionedTransaction) -> Result<(), SignerError> {
+        (**self).sign_transaction(transaction).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash,
+        message::{Message, VersionedMessage},
+        system_instruction,
+    };
+
+    fn transfer(from: &Pubkey, payer: &Pubkey) -> VersionedTransaction {
+        let instruction = system_instruction::transfer(from, &Pubkey::new_unique(), 1);
+        let message = Message::new_with_blockhash(&[instruction], Some(payer), &Hash::new_unique());
+        VersionedTransaction { signatures: vec![], message: VersionedMessage::Legacy(message) }
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction() {
+        let (payer, wallet) = (Wallet::new(), Wallet::new());
+        let mut transaction = transfer(&wallet.pubkey, &payer.pubkey);
+
+        wallet.sign_transaction(&mut transaction).await.unwrap();
+        assert_eq!(transaction.signatures.len(), 2);
+        assert_eq!(transaction.signatures[0], Signature::default());
+
+        payer.sign_transaction(&mut transaction).await.unwrap();
+        assert!(transaction.verify_with_results().iter().all(|verified| *verified));
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_not_a_signer() {
+        let wallet = Wallet::new();
+        let mut transaction = transfer(&Pubkey::new_unique(), &Pubkey::new_unique());
+
+        let error = wallet.sign_transaction(&mut transaction).await.unwrap_err();
+        assert_eq!(error, SignerError::KeypairPubkeyMismatch);
+        assert!(transaction.signatures.is_empty());
+    }
+}
@@ -1,39 +1,183 @@
-use std::{env, sync::Arc};
+// Copyright 2025 zTgx
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Every solagent tool in one crate, one cargo feature per plugin: `solana` (the default),
+//! `jupiter`, `birdeye`, `helius`, `pyth`, `dexscreener`, `gibwork`, `goplus`, `rugcheck`,
+//! `solayer`, `pumpfun`, `cookie`, or `full` for all of them.
+//!
+//! [`AgentTools`] gives a rig agent every enabled tool in one call, or only some categories of
+//! them:
+//!
+//! ```ignore
+//! use solana_agent::{AgentTools, ToolCategory};
+//!
+//! let kit = Arc::new(SolanaAgentKit::from_config(Config::from_env()?)?);
+//! let trader = kit.attach_tools(client.agent("llama3.2")).build();
+//!
+//! // An analytics agent which can never sign
+//! let read_only = kit.tool_registry().only(&[ToolCategory::ReadOnly]);
+//! let analyst = read_only.attach(client.agent("llama3.2")).build();
+//!
+//! // Only the 4 tools closest to each prompt, for small models
+//! let model = client.embedding_model("nomic-embed-text");
+//! let agent = kit.attach_dynamic_tools(client.agent("llama3.2"), model, 4).await?.build();
+//! ```
 
 use anyhow::Result;
 use solagent_core::{
     rig::{
-        agent::Agent,
-        providers::{self, openai::CompletionModel},
+        agent::{Agent, AgentBuilder},
+        completion::CompletionModel,
+        embeddings::EmbeddingModel,
+        providers::{self, openai},
+        tool::ToolSet,
     },
-    ConfigBuilder, SolanaAgentKit,
-};
-use solagent_rig_solana::{
-    close_empty_token_accounts::CloseEmptyTokenAccounts, get_balance::GetBalance, get_tps::GetTps,
+    Config, SolagentError,
 };
-use solagent_wallet_solana::Wallet;
+use std::{future::Future, sync::Arc};
+
+pub use solagent_core::{self, SolanaAgentKit, ToolCategory, ToolRegistry};
+#[cfg(feature = "birdeye")]
+pub use solagent_rig_birdeye as birdeye;
+#[cfg(feature = "cookie")]
+pub use solagent_rig_cookie as cookie;
+#[cfg(feature = "dexscreener")]
+pub use solagent_rig_dexscreener as dexscreener;
+#[cfg(feature = "gibwork")]
+pub use solagent_rig_gibwork as gibwork;
+#[cfg(feature = "goplus")]
+pub use solagent_rig_goplus as goplus;
+#[cfg(feature = "helius")]
+pub use solagent_rig_helius as helius;
+#[cfg(feature = "jupiter")]
+pub use solagent_rig_jupiter as jupiter;
+#[cfg(feature = "pumpfun")]
+pub use solagent_rig_pumpfun as pumpfun;
+#[cfg(feature = "pyth")]
+pub use solagent_rig_pyth as pyth;
+#[cfg(feature = "rugcheck")]
+pub use solagent_rig_rugcheck as rugcheck;
+#[cfg(feature = "solana")]
+pub use solagent_rig_solana as solana;
+#[cfg(feature = "solayer")]
+pub use solagent_rig_solayer as solayer;
+
+/// The tools of every plugin whose feature is enabled, all acting for `agent`.
+#[allow(unused_mut, unused_variables)]
+pub fn tools(agent: &Arc<SolanaAgentKit>) -> ToolRegistry {
+    let mut registry = ToolRegistry::new();
+    #[cfg(feature = "solana")]
+    registry.extend(solagent_rig_solana::tools(agent));
+    #[cfg(feature = "jupiter")]
+    registry.extend(solagent_rig_jupiter::tools(agent));
+    #[cfg(feature = "birdeye")]
+    registry.extend(solagent_rig_birdeye::tools(agent));
+    #[cfg(feature = "helius")]
+    registry.extend(solagent_rig_helius::tools(agent));
+    #[cfg(feature = "pyth")]
+    registry.extend(solagent_rig_pyth::tools(agent));
+    #[cfg(feature = "dexscreener")]
+    registry.extend(solagent_rig_dexscreener::tools(agent));
+    #[cfg(feature = "gibwork")]
+    registry.extend(solagent_rig_gibwork::tools(agent));
+    #[cfg(feature = "goplus")]
+    registry.extend(solagent_rig_goplus::tools(agent));
+    #[cfg(feature = "rugcheck")]
+    registry.extend(solagent_rig_rugcheck::tools(agent));
+    #[cfg(feature = "solayer")]
+    registry.extend(solagent_rig_solayer::tools(agent));
+    #[cfg(feature = "pumpfun")]
+    registry.extend(solagent_rig_pumpfun::tools(agent));
+    #[cfg(feature = "cookie")]
+    registry.extend(solagent_rig_cookie::tools(agent));
+    registry
+}
+
+/// The enabled tools of a kit, for rig agents. Filter them by category with
+/// [`ToolRegistry::only`] on [`AgentTools::tool_registry`].
+pub trait AgentTools {
+    /// Every enabled tool, see [`tools`].
+    fn tool_registry(&self) -> ToolRegistry;
+
+    /// Every enabled tool as a rig [`ToolSet`].
+    fn rig_toolset(&self) -> ToolSet {
+        self.tool_registry().rig_toolset()
+    }
 
-pub fn create_solana_agent() -> Result<Agent<CompletionModel>> {
-    let wallet = Wallet::from_env("SOLANA_PRIVATE_KEY").expect("SOLANA_PRIVATE_KEY");
-    let rpc_url = env::var("key").expect("SOLANA_RPC_URL");
-    let config = ConfigBuilder::default().build();
+    /// Adds every enabled tool to a rig agent.
+    fn attach_tools<M: CompletionModel>(&self, builder: AgentBuilder<M>) -> AgentBuilder<M> {
+        self.tool_registry().attach(builder)
+    }
 
-    let solana_agent_kit = SolanaAgentKit::new(wallet, &rpc_url, config);
-    let solana_agent_kit = Arc::new(solana_agent_kit);
+    /// Adds every enabled tool to a rig agent, sending the model only the `n` tools closest to each
+    /// prompt, see [`ToolRegistry::attach_dynamic`].
+    fn attach_dynamic_tools<M: CompletionModel, E: EmbeddingModel + 'static>(
+        &self,
+        builder: AgentBuilder<M>,
+        model: E,
+        n: usize,
+    ) -> impl Future<Output = Result<AgentBuilder<M>, SolagentError>> {
+        let registry = self.tool_registry();
+        async move { registry.attach_dynamic(builder, model, n).await }
+    }
+}
+
+impl AgentTools for Arc<SolanaAgentKit> {
+    fn tool_registry(&self) -> ToolRegistry {
+        tools(self)
+    }
+}
 
-    let close_acc = CloseEmptyTokenAccounts::new(solana_agent_kit.clone());
-    let balance = GetBalance::new(solana_agent_kit.clone());
-    let tps = GetTps::new(solana_agent_kit.clone());
+pub fn create_solana_agent() -> Result<Agent<openai::CompletionModel>> {
+    let config = Config::from_env()?;
+    let solana_agent_kit = Arc::new(SolanaAgentKit::from_config(config)?);
 
     let client = providers::openai::Client::from_url("ollama", "http://localhost:11434/v1");
     let agent = client
         .agent("llama3.2")
         .preamble("You are an solana trading agent here to help the user.")
-        .max_tokens(1024)
-        .tool(close_acc)
-        .tool(balance)
-        .tool(tps)
-        .build();
+        .max_tokens(1024);
+    let agent = solana_agent_kit.attach_tools(agent).build();
 
     Ok(agent)
 }
+
+#[cfg(all(test, feature = "solana"))]
+mod tests {
+    use super::*;
+    use solagent_core::Wallet;
+
+    #[test]
+    fn test_tools() {
+        let kit = Arc::new(SolanaAgentKit::new(
+            Wallet::new(),
+            "https://api.devnet.solana.com",
+            Config::default(),
+        ));
+        let registry = kit.tool_registry();
+        assert!(registry.get("transfer").is_some());
+
+        let read_only = registry.only(&[ToolCategory::ReadOnly]);
+        assert!(read_only.get("get_balance").is_some());
+        for name in [
+            "transfer",
+            "sign_message",
+            "deploy_token",
+            "close_empty_token_accounts",
+        ] {
+            assert!(read_only.get(name).is_none(), "{} is not read-only", name);
+        }
+        assert_eq!(registry.only(&ToolCategory::ALL).len(), registry.len());
+    }
+}